@@ -11,4 +11,9 @@ pub trait Builder: Sized {
     ) -> Result<Target<Self::Product, Self>, Error>;
 
     fn done(self) -> Option<Self::Product>;
+
+    // done, with the reason a builder gives for having no product
+    fn finish(self) -> Result<Self::Product, Error> {
+        self.done().ok_or(Error::End)
+    }
 }
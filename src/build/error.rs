@@ -3,6 +3,14 @@ use crate::text::Printable;
 #[derive(Debug, PartialEq)]
 pub enum Error {
     Character(Vec<Printable>),
+    // field ended before it was complete
+    End,
+    // list holding other than the declared number of items
+    Length(usize),
+    // value outside min..=max
+    Range(i32, i32),
+    // index past the count of items it refers to
+    Reference(u32, u32),
 }
 
 impl Error {
@@ -15,7 +23,11 @@ impl Error {
     }
 
     pub fn integer_leading() -> Self {
-        Self::Character(vec![])
+        let mut allowed = vec![Printable::Space, Printable::Minus];
+
+        allowed.extend(Printable::non_zero_digits());
+
+        Self::Character(allowed)
     }
 
     pub fn flag() -> Self {
@@ -1,5 +1,10 @@
 pub mod build;
 pub mod molfile;
 pub mod primitive;
+pub mod rdf;
 pub mod read;
+pub mod rgroup;
+pub mod rxn;
+pub mod sdf;
 pub mod text;
+pub mod write;
@@ -0,0 +1,19 @@
+use crate::primitive::{FixedCount, FixedInteger, FixedReal, Sequence};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Atom {
+    pub x: FixedReal<5, 4>,
+    pub y: FixedReal<5, 4>,
+    pub z: FixedReal<5, 4>,
+    pub symbol: Sequence<3>,
+    pub mass_difference: FixedInteger<2>,
+    pub charge: FixedCount<3>,
+    pub stereo_parity: FixedCount<3>,
+    pub hydrogen_count: FixedCount<3>,
+    pub stereo_care: FixedCount<3>,
+    pub valence: FixedCount<3>,
+    pub h0_designator: FixedCount<3>,
+    pub mapping: FixedCount<3>,
+    pub inversion: FixedCount<3>,
+    pub exact_change: FixedCount<3>,
+}
@@ -0,0 +1,10 @@
+use crate::primitive::Natural;
+
+use super::ListType;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AtomList {
+    pub atom: Natural,
+    pub list_type: ListType,
+    pub elements: Vec<Natural>,
+}
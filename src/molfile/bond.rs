@@ -0,0 +1,13 @@
+use crate::primitive::Natural;
+
+use super::{BondStereo, BondTopology, BondType, ReactingCenter};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bond {
+    pub first: Natural,
+    pub second: Natural,
+    pub bond_type: BondType,
+    pub stereo: BondStereo,
+    pub topology: BondTopology,
+    pub reacting_center: ReactingCenter,
+}
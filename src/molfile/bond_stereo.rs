@@ -0,0 +1,154 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BondStereo {
+    NotStereo,
+    Up,
+    Down,
+    Either,
+    CisTransEither,
+}
+
+impl BondStereo {
+    pub fn start() -> impl Builder<Product = BondStereo> {
+        BondStereoBuilder(0)
+    }
+}
+
+impl Emit for BondStereo {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.literal(match self {
+            Self::NotStereo => b"  0",
+            Self::Up => b"  1",
+            Self::CisTransEither => b"  3",
+            Self::Either => b"  4",
+            Self::Down => b"  6",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct BondStereoBuilder(usize);
+
+impl Builder for BondStereoBuilder {
+    type Product = BondStereo;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        if self.0 < 2 {
+            if printable == Printable::Space {
+                Ok(Target::Builder(Self(self.0 + 1)))
+            } else {
+                Err(Error::space())
+            }
+        } else {
+            match printable {
+                Printable::D0 => Ok(Target::Product(BondStereo::NotStereo)),
+                Printable::D1 => Ok(Target::Product(BondStereo::Up)),
+                Printable::D3 => {
+                    Ok(Target::Product(BondStereo::CisTransEither))
+                }
+                Printable::D4 => Ok(Target::Product(BondStereo::Either)),
+                Printable::D6 => Ok(Target::Product(BondStereo::Down)),
+                _ => Err(Error::Character(vec![
+                    Printable::D0,
+                    Printable::D1,
+                    Printable::D3,
+                    Printable::D4,
+                    Printable::D6,
+                ])),
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_limit_digit() {
+        let builder = BondStereoBuilder(0);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::space()))
+    }
+
+    #[test]
+    fn limit_two() {
+        let builder = BondStereoBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D2),
+            Err(Error::Character(vec![
+                Printable::D0,
+                Printable::D1,
+                Printable::D3,
+                Printable::D4,
+                Printable::D6,
+            ]))
+        )
+    }
+
+    #[test]
+    fn limit_one() {
+        let builder = BondStereoBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D1),
+            Ok(Target::Product(BondStereo::Up))
+        )
+    }
+
+    #[test]
+    fn limit_three() {
+        let builder = BondStereoBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D3),
+            Ok(Target::Product(BondStereo::CisTransEither))
+        )
+    }
+
+    #[test]
+    fn limit_six() {
+        let builder = BondStereoBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D6),
+            Ok(Target::Product(BondStereo::Down))
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_stereo() {
+        assert_eq!(emit(&BondStereo::NotStereo), Ok("  0".to_string()))
+    }
+
+    #[test]
+    fn cis_trans_either() {
+        assert_eq!(emit(&BondStereo::CisTransEither), Ok("  3".to_string()))
+    }
+
+    #[test]
+    fn down() {
+        assert_eq!(emit(&BondStereo::Down), Ok("  6".to_string()))
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BondTopology {
+    Either,
+    Ring,
+    Chain,
+}
+
+impl BondTopology {
+    pub fn start() -> impl Builder<Product = BondTopology> {
+        BondTopologyBuilder(0)
+    }
+}
+
+impl Emit for BondTopology {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.literal(match self {
+            Self::Either => b"  0",
+            Self::Ring => b"  1",
+            Self::Chain => b"  2",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct BondTopologyBuilder(usize);
+
+impl Builder for BondTopologyBuilder {
+    type Product = BondTopology;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        if self.0 < 2 {
+            if printable == Printable::Space {
+                Ok(Target::Builder(Self(self.0 + 1)))
+            } else {
+                Err(Error::space())
+            }
+        } else {
+            match printable {
+                Printable::D0 => Ok(Target::Product(BondTopology::Either)),
+                Printable::D1 => Ok(Target::Product(BondTopology::Ring)),
+                Printable::D2 => Ok(Target::Product(BondTopology::Chain)),
+                _ => Err(Error::Character(vec![
+                    Printable::D0,
+                    Printable::D1,
+                    Printable::D2,
+                ])),
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn limit_three() {
+        let builder = BondTopologyBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D3),
+            Err(Error::Character(vec![
+                Printable::D0,
+                Printable::D1,
+                Printable::D2
+            ]))
+        )
+    }
+
+    #[test]
+    fn limit_two() {
+        let builder = BondTopologyBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D2),
+            Ok(Target::Product(BondTopology::Chain))
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn either() {
+        assert_eq!(emit(&BondTopology::Either), Ok("  0".to_string()))
+    }
+
+    #[test]
+    fn chain() {
+        assert_eq!(emit(&BondTopology::Chain), Ok("  2".to_string()))
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BondType {
+    Single,
+    Double,
+    Triple,
+    Aromatic,
+    SingleOrDouble,
+    SingleOrAromatic,
+    DoubleOrAromatic,
+    Any,
+    Coordination,
+    Hydrogen,
+}
+
+impl BondType {
+    pub fn start() -> impl Builder<Product = BondType> {
+        BondTypeBuilder(0)
+    }
+}
+
+impl Emit for BondType {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::Single => writer.literal(b"  1"),
+            Self::Double => writer.literal(b"  2"),
+            Self::Triple => writer.literal(b"  3"),
+            Self::Aromatic => writer.literal(b"  4"),
+            Self::SingleOrDouble => writer.literal(b"  5"),
+            Self::SingleOrAromatic => writer.literal(b"  6"),
+            Self::DoubleOrAromatic => writer.literal(b"  7"),
+            Self::Any => writer.literal(b"  8"),
+            Self::Coordination | Self::Hydrogen => {
+                Err(write::Error::Unsupported(writer.row, writer.column))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct BondTypeBuilder(usize);
+
+impl Builder for BondTypeBuilder {
+    type Product = BondType;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        if self.0 < 2 {
+            if printable == Printable::Space {
+                Ok(Target::Builder(Self(self.0 + 1)))
+            } else {
+                Err(Error::space())
+            }
+        } else {
+            match printable {
+                Printable::D1 => Ok(Target::Product(BondType::Single)),
+                Printable::D2 => Ok(Target::Product(BondType::Double)),
+                Printable::D3 => Ok(Target::Product(BondType::Triple)),
+                Printable::D4 => Ok(Target::Product(BondType::Aromatic)),
+                Printable::D5 => Ok(Target::Product(BondType::SingleOrDouble)),
+                Printable::D6 => {
+                    Ok(Target::Product(BondType::SingleOrAromatic))
+                }
+                Printable::D7 => {
+                    Ok(Target::Product(BondType::DoubleOrAromatic))
+                }
+                Printable::D8 => Ok(Target::Product(BondType::Any)),
+                _ => Err(Error::Character(vec![
+                    Printable::D1,
+                    Printable::D2,
+                    Printable::D3,
+                    Printable::D4,
+                    Printable::D5,
+                    Printable::D6,
+                    Printable::D7,
+                    Printable::D8,
+                ])),
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_limit_digit() {
+        let builder = BondTypeBuilder(1);
+
+        assert_eq!(builder.push(Printable::D1), Err(Error::space()))
+    }
+
+    #[test]
+    fn not_limit_space() {
+        let builder = BondTypeBuilder(0);
+
+        assert_eq!(
+            builder.push(Printable::Space),
+            Ok(Target::Builder(BondTypeBuilder(1)))
+        )
+    }
+
+    #[test]
+    fn limit_zero() {
+        let builder = BondTypeBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D0),
+            Err(Error::Character(vec![
+                Printable::D1,
+                Printable::D2,
+                Printable::D3,
+                Printable::D4,
+                Printable::D5,
+                Printable::D6,
+                Printable::D7,
+                Printable::D8,
+            ]))
+        )
+    }
+
+    #[test]
+    fn limit_one() {
+        let builder = BondTypeBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D1),
+            Ok(Target::Product(BondType::Single))
+        )
+    }
+
+    #[test]
+    fn limit_eight() {
+        let builder = BondTypeBuilder(2);
+
+        assert_eq!(
+            builder.push(Printable::D8),
+            Ok(Target::Product(BondType::Any))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_done() {
+        let builder = BondTypeBuilder(2);
+
+        assert_eq!(builder.done(), None)
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn single() {
+        assert_eq!(emit(&BondType::Single), Ok("  1".to_string()))
+    }
+
+    #[test]
+    fn any() {
+        assert_eq!(emit(&BondType::Any), Ok("  8".to_string()))
+    }
+
+    #[test]
+    fn coordination() {
+        assert_eq!(
+            emit(&BondType::Coordination),
+            Err(write::Error::Unsupported(0, 0))
+        )
+    }
+}
@@ -0,0 +1,9 @@
+use crate::primitive::FixedReal;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Bracket {
+    pub x1: FixedReal<5, 4>,
+    pub y1: FixedReal<5, 4>,
+    pub x2: FixedReal<5, 4>,
+    pub y2: FixedReal<5, 4>,
+}
@@ -1,9 +1,10 @@
 use crate::{
     build::{Builder, Error, Target},
     text::Printable,
+    write::{self, Emit, Writer},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum ChiralFlag {
     Chiral,
     NotChiral,
@@ -15,6 +16,15 @@ impl ChiralFlag {
     }
 }
 
+impl Emit for ChiralFlag {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::Chiral => writer.literal(b"  1"),
+            Self::NotChiral => writer.literal(b"  0"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 struct ChiralFlagBuilder(usize);
 
@@ -107,3 +117,20 @@ mod buider_done {
         assert_eq!(builder.done(), None)
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn chiral() {
+        assert_eq!(emit(&ChiralFlag::Chiral), Ok("  1".to_string()))
+    }
+
+    #[test]
+    fn not_chiral() {
+        assert_eq!(emit(&ChiralFlag::NotChiral), Ok("  0".to_string()))
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Connectivity {
+    // HH
+    HeadToHead,
+    // HT
+    HeadToTail,
+    // EU
+    EitherUnknown,
+}
@@ -1,12 +1,99 @@
-use crate::primitive::FixedCount;
+use crate::{
+    primitive::FixedCount,
+    write::{self, Emit, Writer},
+};
 
 use super::{ChiralFlag, Version};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Counts {
     pub atoms: FixedCount<3>,
     pub bonds: FixedCount<3>,
     pub atom_lists: FixedCount<3>,
     pub chiral: ChiralFlag,
+    pub stext: Option<FixedCount<3>>,
     pub version: Version,
 }
+
+impl Emit for Counts {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.write(&self.atoms)?;
+        writer.write(&self.bonds)?;
+        writer.write(&self.atom_lists)?;
+
+        // fff
+        match self.version {
+            Version::V2 => writer.literal(b"  0")?,
+            Version::V3 => writer.pad(3)?,
+        }
+
+        writer.write(&self.chiral)?;
+
+        match &self.stext {
+            Some(stext) => writer.write(stext)?,
+            None => writer.pad(3)?,
+        }
+
+        // xxx, rrr, ppp, iii, mmm
+        writer.literal(b"            999")?;
+        writer.write(&self.version)
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn v2() {
+        let counts = Counts {
+            atoms: FixedCount::from_int(4).unwrap(),
+            bonds: FixedCount::from_int(3).unwrap(),
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::Chiral,
+            stext: Some(FixedCount::Zero),
+            version: Version::V2,
+        };
+
+        assert_eq!(
+            emit(&counts),
+            Ok("  4  3  0  0  1  0            999 V2000".to_string())
+        )
+    }
+
+    #[test]
+    fn v2_blank_stext() {
+        let counts = Counts {
+            atoms: FixedCount::from_int(4).unwrap(),
+            bonds: FixedCount::from_int(3).unwrap(),
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::NotChiral,
+            stext: None,
+            version: Version::V2,
+        };
+
+        assert_eq!(
+            emit(&counts),
+            Ok("  4  3  0  0  0               999 V2000".to_string())
+        )
+    }
+
+    #[test]
+    fn v3() {
+        let counts = Counts {
+            atoms: FixedCount::Zero,
+            bonds: FixedCount::Zero,
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::NotChiral,
+            stext: Some(FixedCount::Zero),
+            version: Version::V3,
+        };
+
+        assert_eq!(
+            emit(&counts),
+            Ok("  0  0  0     0  0            999 V3000".to_string())
+        )
+    }
+}
@@ -0,0 +1,8 @@
+use crate::primitive::{FixedReal, Natural};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CrossingVector {
+    pub bond: Natural,
+    pub x: FixedReal<5, 4>,
+    pub y: FixedReal<5, 4>,
+}
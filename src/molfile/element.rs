@@ -0,0 +1,179 @@
+// symbol and the mass a V2000 mass difference is relative to
+const ELEMENTS: [(&str, u32); 118] = [
+    ("H", 1),
+    ("He", 4),
+    ("Li", 7),
+    ("Be", 9),
+    ("B", 11),
+    ("C", 12),
+    ("N", 14),
+    ("O", 16),
+    ("F", 19),
+    ("Ne", 20),
+    ("Na", 23),
+    ("Mg", 24),
+    ("Al", 27),
+    ("Si", 28),
+    ("P", 31),
+    ("S", 32),
+    ("Cl", 35),
+    ("Ar", 40),
+    ("K", 39),
+    ("Ca", 40),
+    ("Sc", 45),
+    ("Ti", 48),
+    ("V", 51),
+    ("Cr", 52),
+    ("Mn", 55),
+    ("Fe", 56),
+    ("Co", 59),
+    ("Ni", 59),
+    ("Cu", 64),
+    ("Zn", 65),
+    ("Ga", 70),
+    ("Ge", 73),
+    ("As", 75),
+    ("Se", 79),
+    ("Br", 80),
+    ("Kr", 84),
+    ("Rb", 85),
+    ("Sr", 88),
+    ("Y", 89),
+    ("Zr", 91),
+    ("Nb", 93),
+    ("Mo", 96),
+    ("Tc", 98),
+    ("Ru", 101),
+    ("Rh", 103),
+    ("Pd", 106),
+    ("Ag", 108),
+    ("Cd", 112),
+    ("In", 115),
+    ("Sn", 119),
+    ("Sb", 122),
+    ("Te", 128),
+    ("I", 127),
+    ("Xe", 131),
+    ("Cs", 133),
+    ("Ba", 137),
+    ("La", 139),
+    ("Ce", 140),
+    ("Pr", 141),
+    ("Nd", 144),
+    ("Pm", 145),
+    ("Sm", 150),
+    ("Eu", 152),
+    ("Gd", 157),
+    ("Tb", 159),
+    ("Dy", 163),
+    ("Ho", 165),
+    ("Er", 167),
+    ("Tm", 169),
+    ("Yb", 173),
+    ("Lu", 175),
+    ("Hf", 178),
+    ("Ta", 181),
+    ("W", 184),
+    ("Re", 186),
+    ("Os", 190),
+    ("Ir", 192),
+    ("Pt", 195),
+    ("Au", 197),
+    ("Hg", 201),
+    ("Tl", 204),
+    ("Pb", 207),
+    ("Bi", 209),
+    ("Po", 209),
+    ("At", 210),
+    ("Rn", 222),
+    ("Fr", 223),
+    ("Ra", 226),
+    ("Ac", 227),
+    ("Th", 232),
+    ("Pa", 231),
+    ("U", 238),
+    ("Np", 237),
+    ("Pu", 244),
+    ("Am", 243),
+    ("Cm", 247),
+    ("Bk", 247),
+    ("Cf", 251),
+    ("Es", 252),
+    ("Fm", 257),
+    ("Md", 258),
+    ("No", 259),
+    ("Lr", 266),
+    ("Rf", 267),
+    ("Db", 268),
+    ("Sg", 269),
+    ("Bh", 270),
+    ("Hs", 277),
+    ("Mt", 278),
+    ("Ds", 281),
+    ("Rg", 282),
+    ("Cn", 285),
+    ("Nh", 286),
+    ("Fl", 289),
+    ("Mc", 290),
+    ("Lv", 293),
+    ("Ts", 294),
+    ("Og", 294),
+];
+
+pub fn symbol(number: u32) -> Option<&'static str> {
+    ELEMENTS
+        .get((number as usize).checked_sub(1)?)
+        .map(|(symbol, _)| *symbol)
+}
+
+pub fn mass(symbol: &str) -> Option<u32> {
+    match symbol {
+        "D" => Some(2),
+        "T" => Some(3),
+        _ => ELEMENTS
+            .iter()
+            .find(|(element, _)| *element == symbol)
+            .map(|(_, mass)| *mass),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn symbol_zero() {
+        assert_eq!(symbol(0), None)
+    }
+
+    #[test]
+    fn symbol_carbon() {
+        assert_eq!(symbol(6), Some("C"))
+    }
+
+    #[test]
+    fn symbol_last() {
+        assert_eq!(symbol(118), Some("Og"))
+    }
+
+    #[test]
+    fn symbol_unknown() {
+        assert_eq!(symbol(119), None)
+    }
+
+    #[test]
+    fn mass_chlorine() {
+        assert_eq!(mass("Cl"), Some(35))
+    }
+
+    #[test]
+    fn mass_deuterium() {
+        assert_eq!(mass("D"), Some(2))
+    }
+
+    #[test]
+    fn mass_pseudo_atom() {
+        assert_eq!(mass("R#"), None)
+    }
+}
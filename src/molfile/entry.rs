@@ -0,0 +1,7 @@
+use crate::primitive::Natural;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Entry<V> {
+    pub atom: Natural,
+    pub value: V,
+}
@@ -1,11 +1,75 @@
-use crate::primitive::Line;
+use crate::{
+    primitive::Line,
+    write::{self, Emit, Writer},
+};
 
 use super::{Counts, MoleculeName, Parameters};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Header {
     pub molecule_name: MoleculeName,
     pub parameters: Option<Parameters>,
     pub comment: Line<80>,
     pub counts: Counts,
 }
+
+impl Emit for Header {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.write_line(&self.molecule_name)?;
+
+        if let Some(parameters) = &self.parameters {
+            writer.write(parameters)?;
+        }
+
+        writer.end_line()?;
+        writer.write_line(&self.comment)?;
+        writer.write_line(&self.counts)
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use crate::{
+        read::{header, Reader},
+        write::emit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn round_trip(lines: &[&str]) {
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+        let header = header(&mut Reader::new(&mut bytes)).unwrap();
+
+        assert_eq!(emit(&header), Ok(text))
+    }
+
+    #[test]
+    fn blank_parameters() {
+        round_trip(&[
+            "Acetate",
+            "",
+            "",
+            "  4  3  0  0  0  0            999 V2000",
+        ])
+    }
+
+    #[test]
+    fn parameters() {
+        round_trip(&[
+            "Acetate",
+            "  CTCORE  01012300002D 1   1.00000     0.00000     0",
+            "a comment",
+            "  4  3  0  0  1               999 V2000",
+        ])
+    }
+
+    #[test]
+    fn v3() {
+        round_trip(&[
+            "",
+            "  CTCORE  01012300002D 1  -0.50000    -0.00100 12345",
+            "",
+            "  0  0  0     0  0            999 V3000",
+        ])
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ListType {
+    List,
+    NotList,
+}
+
+impl ListType {
+    pub fn start() -> impl Builder<Product = ListType> {
+        ListTypeBuilder
+    }
+}
+
+impl Emit for ListType {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::List => writer.push(Printable::F),
+            Self::NotList => writer.push(Printable::T),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ListTypeBuilder;
+
+impl Builder for ListTypeBuilder {
+    type Product = ListType;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match printable {
+            Printable::F => Ok(Target::Product(ListType::List)),
+            Printable::T => Ok(Target::Product(ListType::NotList)),
+            _ => Err(Error::Character(vec![Printable::F, Printable::T])),
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn f() {
+        assert_eq!(
+            ListTypeBuilder.push(Printable::F),
+            Ok(Target::Product(ListType::List))
+        )
+    }
+
+    #[test]
+    fn t() {
+        assert_eq!(
+            ListTypeBuilder.push(Printable::T),
+            Ok(Target::Product(ListType::NotList))
+        )
+    }
+
+    #[test]
+    fn space() {
+        assert_eq!(
+            ListTypeBuilder.push(Printable::Space),
+            Err(Error::Character(vec![Printable::F, Printable::T]))
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn list() {
+        assert_eq!(emit(&ListType::List), Ok("F".to_string()))
+    }
+
+    #[test]
+    fn not_list() {
+        assert_eq!(emit(&ListType::NotList), Ok("T".to_string()))
+    }
+}
@@ -1,13 +1,61 @@
+mod atom;
+mod atom_list;
+mod bond;
+mod bond_stereo;
+mod bond_topology;
+mod bond_type;
+mod bracket;
+mod bracket_style;
 mod chiral_flag;
+mod connectivity;
 mod counts;
+mod crossing_vector;
+mod element;
+mod entry;
 mod header;
+mod list_type;
 mod molecule_name;
+#[allow(clippy::module_inception)]
+mod molfile;
 mod parameters;
+mod property;
+mod reacting_center;
+mod sgroup;
+mod sgroup_attachment;
+mod sgroup_subtype;
+mod sgroup_type;
+mod stereo_group;
+mod stereo_group_kind;
+mod stext;
+mod unsupported;
 mod version;
 
+pub use atom::Atom;
+pub use atom_list::AtomList;
+pub use bond::Bond;
+pub use bond_stereo::BondStereo;
+pub use bond_topology::BondTopology;
+pub use bond_type::BondType;
+pub use bracket::Bracket;
+pub use bracket_style::BracketStyle;
 pub use chiral_flag::ChiralFlag;
+pub use connectivity::Connectivity;
 pub use counts::Counts;
+pub use crossing_vector::CrossingVector;
+pub use entry::Entry;
 pub use header::Header;
+pub use list_type::ListType;
 pub use molecule_name::MoleculeName;
+pub use molfile::Molfile;
 pub use parameters::Parameters;
+pub use property::{LinkAtom, Property};
+pub use reacting_center::ReactingCenter;
+pub use sgroup::Sgroup;
+pub use sgroup_attachment::SgroupAttachment;
+pub use sgroup_subtype::SgroupSubtype;
+pub use sgroup_type::SgroupType;
+pub use stereo_group::StereoGroup;
+pub use stereo_group_kind::StereoGroupKind;
+pub use stext::Stext;
+pub use unsupported::Unsupported;
 pub use version::Version;
@@ -1,9 +1,10 @@
 use crate::{
     build::{Builder, Error, Target},
     text::Printable,
+    write::{self, Emit, Writer},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct MoleculeName(Vec<Printable>);
 
 impl MoleculeName {
@@ -11,6 +12,7 @@ impl MoleculeName {
         MoleculeNameBuilder(Vec::new())
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(str: &str) -> Option<Self> {
         let mut builder = Self::start();
 
@@ -29,11 +31,21 @@ impl MoleculeName {
         self.0.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
     pub fn push(&mut self, printable: Printable) {
         self.0.push(printable)
     }
 }
 
+impl Emit for MoleculeName {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push_all(&self.0)
+    }
+}
+
 pub struct MoleculeNameBuilder(pub Vec<Printable>);
 
 impl Builder for MoleculeNameBuilder {
@@ -56,3 +68,24 @@ impl Builder for MoleculeNameBuilder {
         Some(MoleculeName(self.0))
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        let name = MoleculeName::from_str("").unwrap();
+
+        assert_eq!(emit(&name), Ok("".to_string()))
+    }
+
+    #[test]
+    fn name() {
+        let name = MoleculeName::from_str("Acetate").unwrap();
+
+        assert_eq!(emit(&name), Ok("Acetate".to_string()))
+    }
+}
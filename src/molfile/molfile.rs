@@ -0,0 +1,775 @@
+use crate::primitive::{FixedCount, FixedInteger, Natural, Sequence};
+
+use super::{
+    element, Atom, AtomList, Bond, BondType, Counts, Entry, Header, Property,
+    Sgroup, SgroupType, StereoGroup, Stext, Unsupported, Version,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Molfile {
+    pub header: Header,
+    pub atoms: Vec<Atom>,
+    pub bonds: Vec<Bond>,
+    pub atom_lists: Vec<AtomList>,
+    pub stext: Vec<Stext>,
+    pub sgroups: Vec<Sgroup>,
+    pub stereo_groups: Vec<StereoGroup>,
+    pub properties: Vec<Property>,
+}
+
+impl Molfile {
+    pub fn parent(&self, sgroup: &Sgroup) -> Option<&Sgroup> {
+        let parent = sgroup.parent.as_ref()?;
+
+        self.sgroups.iter().find(|sgroup| &sgroup.index == parent)
+    }
+
+    pub fn to_v3000(&self) -> Result<Molfile, Vec<Unsupported>> {
+        let mut unsupported = Vec::new();
+        let mut properties = Vec::new();
+
+        // M  CHG and M  RAD supersede atom block charges
+        if !self.properties.iter().any(|property| {
+            matches!(property, Property::Charge(_) | Property::Radical(_))
+        }) {
+            let mut charges = Vec::new();
+            let mut radicals = Vec::new();
+
+            for (index, atom) in self.atoms.iter().enumerate() {
+                match atom.charge.to_int() {
+                    code @ (1..=3 | 5..=7) => charges.push(Entry {
+                        atom: natural(index),
+                        value: FixedInteger::from_int(4 - code as i32)
+                            .expect("charge"),
+                    }),
+                    4 => radicals.push(Entry {
+                        atom: natural(index),
+                        value: FixedCount::from_int(2).expect("doublet"),
+                    }),
+                    0 => (),
+                    _ => unsupported.push(Unsupported::Charge(natural(index))),
+                }
+            }
+
+            if !charges.is_empty() {
+                properties.push(Property::Charge(charges))
+            }
+
+            if !radicals.is_empty() {
+                properties.push(Property::Radical(radicals))
+            }
+        }
+
+        // M  ISO supersedes atom block mass differences
+        if !self
+            .properties
+            .iter()
+            .any(|property| matches!(property, Property::Isotope(_)))
+        {
+            let mut isotopes = Vec::new();
+
+            for (index, atom) in self.atoms.iter().enumerate() {
+                let difference = atom.mass_difference.to_int();
+
+                if difference == 0 {
+                    continue;
+                }
+
+                if let Some(value) = element::mass(&symbol(&atom.symbol))
+                    .and_then(|mass| {
+                        u32::try_from(mass as i32 + difference).ok()
+                    })
+                    .and_then(FixedCount::from_int)
+                {
+                    isotopes.push(Entry {
+                        atom: natural(index),
+                        value,
+                    })
+                } else {
+                    unsupported
+                        .push(Unsupported::MassDifference(natural(index)))
+                }
+            }
+
+            if !isotopes.is_empty() {
+                properties.push(Property::Isotope(isotopes))
+            }
+        }
+
+        for atom_list in &self.atom_lists {
+            let elements = atom_list
+                .elements
+                .iter()
+                .map(|element| {
+                    let symbol = element::symbol(element.to_int())?;
+
+                    Sequence::from_str(&format!("{:<4}", symbol))
+                })
+                .collect::<Option<Vec<_>>>();
+
+            match elements {
+                Some(elements) => properties.push(Property::AtomList {
+                    atom: atom_list.atom.clone(),
+                    list_type: atom_list.list_type.clone(),
+                    elements,
+                }),
+                None => unsupported
+                    .push(Unsupported::AtomList(atom_list.atom.clone())),
+            }
+        }
+
+        if !unsupported.is_empty() {
+            return Err(unsupported);
+        }
+
+        properties.extend(self.properties.iter().cloned());
+
+        Ok(Molfile {
+            header: Header {
+                counts: Counts {
+                    atoms: FixedCount::Zero,
+                    bonds: FixedCount::Zero,
+                    atom_lists: FixedCount::Zero,
+                    chiral: self.header.counts.chiral.clone(),
+                    stext: Some(FixedCount::Zero),
+                    version: Version::V3,
+                },
+                ..self.header.clone()
+            },
+            atoms: self
+                .atoms
+                .iter()
+                .map(|atom| Atom {
+                    mass_difference: FixedInteger::Zero,
+                    charge: FixedCount::Zero,
+                    h0_designator: FixedCount::Zero,
+                    ..atom.clone()
+                })
+                .collect(),
+            atom_lists: vec![],
+            stext: vec![],
+            sgroups: self
+                .sgroups
+                .iter()
+                .map(|sgroup| Sgroup {
+                    field_type: None,
+                    ..sgroup.clone()
+                })
+                .collect(),
+            properties,
+            ..self.clone()
+        })
+    }
+
+    // V2000 features with no V3000 form, which to_v3000 drops
+    pub fn lost_in_v3000(&self) -> Vec<Unsupported> {
+        let mut result = Vec::new();
+
+        if !self.stext.is_empty() {
+            result.push(Unsupported::Stext)
+        }
+
+        for (index, atom) in self.atoms.iter().enumerate() {
+            if atom.h0_designator != FixedCount::Zero {
+                result.push(Unsupported::H0Designator(natural(index)))
+            }
+        }
+
+        for sgroup in &self.sgroups {
+            if sgroup.field_type.is_some() {
+                result.push(Unsupported::FieldType(sgroup.index.clone()))
+            }
+        }
+
+        result
+    }
+
+    pub fn to_v2000(&self) -> Result<Molfile, Vec<Unsupported>> {
+        let mut unsupported = Vec::new();
+
+        if self.atoms.len() > 999 {
+            unsupported.push(Unsupported::Atoms(self.atoms.len()))
+        }
+
+        if self.bonds.len() > 999 {
+            unsupported.push(Unsupported::Bonds(self.bonds.len()))
+        }
+
+        if self.atom_lists.len() > 999 {
+            unsupported.push(Unsupported::AtomLists(self.atom_lists.len()))
+        }
+
+        if self.stext.len() > 999 {
+            unsupported.push(Unsupported::StextEntries(self.stext.len()))
+        }
+
+        if !self.stereo_groups.is_empty() {
+            unsupported.push(Unsupported::StereoGroups)
+        }
+
+        for (index, bond) in self.bonds.iter().enumerate() {
+            if matches!(
+                bond.bond_type,
+                BondType::Coordination | BondType::Hydrogen
+            ) {
+                unsupported.push(Unsupported::BondType(natural(index)))
+            }
+        }
+
+        for sgroup in &self.sgroups {
+            let index = &sgroup.index;
+
+            if index.to_int() > 999 {
+                unsupported.push(Unsupported::SgroupIndex(index.clone()))
+            }
+
+            if !sgroup.containment_bonds.is_empty()
+                || !sgroup.head_bonds.is_empty()
+                || !sgroup.bond_correspondence.is_empty()
+            {
+                unsupported.push(Unsupported::SgroupBonds(index.clone()))
+            }
+
+            if sgroup.bracket_style.is_some() {
+                unsupported.push(Unsupported::BracketStyle(index.clone()))
+            }
+
+            match (&sgroup.sgroup_type, &sgroup.multiplier, &sgroup.label) {
+                (SgroupType::Multiple, _, Some(_)) => {
+                    unsupported.push(Unsupported::SgroupLabel(index.clone()))
+                }
+                (SgroupType::Multiple, _, None) => (),
+                (_, Some(_), _) => {
+                    unsupported.push(Unsupported::Multiplier(index.clone()))
+                }
+                (_, None, Some(label)) if label.printables().len() > 69 => {
+                    unsupported.push(Unsupported::SgroupLabel(index.clone()))
+                }
+                (_, None, _) => (),
+            }
+
+            if let Some(class) = &sgroup.class {
+                if class.printables().len() > 69 {
+                    unsupported.push(Unsupported::SgroupClass(index.clone()))
+                }
+            }
+
+            // M  SDT columns
+            if [
+                (&sgroup.field_name, 30),
+                (&sgroup.field_type, 2),
+                (&sgroup.field_info, 20),
+                (&sgroup.query_type, 2),
+                (&sgroup.query_operator, 15),
+            ]
+            .iter()
+            .any(|(field, width)| {
+                field
+                    .as_ref()
+                    .is_some_and(|field| field.printables().len() > *width)
+            }) {
+                unsupported.push(Unsupported::SgroupField(index.clone()))
+            }
+        }
+
+        for property in &self.properties {
+            match property {
+                Property::AtomKeyword { atom, .. } => {
+                    unsupported.push(Unsupported::AtomKeyword(atom.clone()))
+                }
+                Property::BondKeyword { bond, .. } => {
+                    unsupported.push(Unsupported::BondKeyword(bond.clone()))
+                }
+                Property::Collection { name, .. } => {
+                    unsupported.push(Unsupported::Collection(name.clone()))
+                }
+                _ => (),
+            }
+        }
+
+        if !unsupported.is_empty() {
+            return Err(unsupported);
+        }
+
+        Ok(Molfile {
+            header: Header {
+                counts: Counts {
+                    atoms: count(self.atoms.len()),
+                    bonds: count(self.bonds.len()),
+                    atom_lists: count(self.atom_lists.len()),
+                    chiral: self.header.counts.chiral.clone(),
+                    stext: if self.stext.is_empty()
+                        && self.header.counts.stext.is_none()
+                    {
+                        None
+                    } else {
+                        Some(count(self.stext.len()))
+                    },
+                    version: Version::V2,
+                },
+                ..self.header.clone()
+            },
+            ..self.clone()
+        })
+    }
+}
+
+fn natural(index: usize) -> Natural {
+    Natural::from_int(index as u32 + 1).expect("index")
+}
+
+fn count(count: usize) -> FixedCount<3> {
+    FixedCount::from_int(count as u32).expect("count")
+}
+
+fn symbol(symbol: &Sequence<3>) -> String {
+    symbol
+        .printables()
+        .iter()
+        .map(|printable| printable.to_byte() as char)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+#[cfg(test)]
+mod to_v3000 {
+    use super::*;
+    use crate::{
+        molfile::ListType,
+        read::{molfile, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Molfile {
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        molfile(&mut reader).unwrap()
+    }
+
+    fn v2000(atoms: &[&str], properties: &[&str]) -> Molfile {
+        let counts =
+            format!("{:>3}  0  0  0  0  0            999 V2000", atoms.len());
+        let mut lines = vec!["", "", "", &counts];
+
+        lines.extend(atoms);
+        lines.extend(properties);
+        lines.push("M  END");
+
+        read(&lines)
+    }
+
+    fn atom(symbol: &str, mass_difference: i32, charge: u32) -> String {
+        format!(
+            "    0.0000    0.0000    0.0000 {:<3}{:>2}{:>3}  0  0  0  0  0  0  0  0  0  0",
+            symbol, mass_difference, charge
+        )
+    }
+
+    fn entry<V>(atom: u32, value: V) -> Entry<V> {
+        Entry {
+            atom: Natural::from_int(atom).unwrap(),
+            value,
+        }
+    }
+
+    #[test]
+    fn counts() {
+        let molfile = v2000(&[&atom("C", 0, 0)], &[]).to_v3000().unwrap();
+
+        assert_eq!(
+            molfile.header.counts,
+            Counts {
+                atoms: FixedCount::Zero,
+                bonds: FixedCount::Zero,
+                atom_lists: FixedCount::Zero,
+                chiral: molfile.header.counts.chiral.clone(),
+                stext: Some(FixedCount::Zero),
+                version: Version::V3
+            }
+        )
+    }
+
+    #[test]
+    fn atom_block_charges() {
+        let molfile =
+            v2000(&[&atom("N", 0, 3), &atom("O", 0, 5), &atom("C", 0, 4)], &[])
+                .to_v3000()
+                .unwrap();
+
+        assert_eq!(
+            molfile.properties,
+            vec![
+                Property::Charge(vec![
+                    entry(1, FixedInteger::from_int(1).unwrap()),
+                    entry(2, FixedInteger::from_int(-1).unwrap())
+                ]),
+                Property::Radical(vec![entry(
+                    3,
+                    FixedCount::from_int(2).unwrap()
+                )]),
+                Property::End
+            ]
+        );
+        assert!(molfile
+            .atoms
+            .iter()
+            .all(|atom| atom.charge == FixedCount::Zero))
+    }
+
+    #[test]
+    fn superseded_charges() {
+        let molfile = v2000(&[&atom("N", 0, 3)], &["M  CHG  1   1   2"])
+            .to_v3000()
+            .unwrap();
+
+        assert_eq!(
+            molfile.properties,
+            vec![
+                Property::Charge(vec![entry(
+                    1,
+                    FixedInteger::from_int(2).unwrap()
+                )]),
+                Property::End
+            ]
+        );
+        assert_eq!(molfile.atoms[0].charge, FixedCount::Zero)
+    }
+
+    #[test]
+    fn mass_differences() {
+        let molfile = v2000(
+            &[&atom("C", 1, 0), &atom("Cl", 2, 0), &atom("D", 1, 0)],
+            &[],
+        )
+        .to_v3000()
+        .unwrap();
+
+        assert_eq!(
+            molfile.properties,
+            vec![
+                Property::Isotope(vec![
+                    entry(1, FixedCount::from_int(13).unwrap()),
+                    entry(2, FixedCount::from_int(37).unwrap()),
+                    entry(3, FixedCount::from_int(3).unwrap())
+                ]),
+                Property::End
+            ]
+        );
+        assert!(molfile
+            .atoms
+            .iter()
+            .all(|atom| atom.mass_difference == FixedInteger::Zero))
+    }
+
+    #[test]
+    fn pseudo_atom_mass_difference() {
+        let molfile = v2000(&[&atom("C", 1, 0), &atom("R#", 1, 0)], &[]);
+
+        assert_eq!(
+            molfile.to_v3000(),
+            Err(vec![Unsupported::MassDifference(
+                Natural::from_int(2).unwrap()
+            )])
+        )
+    }
+
+    #[test]
+    fn unknown_charge_code() {
+        let molfile = v2000(&[&atom("C", 0, 8)], &[]);
+
+        assert_eq!(
+            molfile.to_v3000(),
+            Err(vec![Unsupported::Charge(Natural::from_int(1).unwrap())])
+        )
+    }
+
+    #[test]
+    fn h0_designator() {
+        let mut molfile = v2000(&[&atom("C", 0, 0)], &[]);
+
+        molfile.atoms[0].h0_designator = FixedCount::from_int(1).unwrap();
+
+        assert_eq!(
+            molfile.lost_in_v3000(),
+            vec![Unsupported::H0Designator(Natural::from_int(1).unwrap())]
+        );
+        assert_eq!(
+            molfile.to_v3000().unwrap().atoms[0].h0_designator,
+            FixedCount::Zero
+        )
+    }
+
+    #[test]
+    fn field_type() {
+        let molfile = v2000(
+            &[&atom("C", 0, 0)],
+            &[
+                "M  STY  1   1 DAT",
+                "M  SDT   1 PKA                           N",
+            ],
+        );
+
+        assert_eq!(
+            molfile.lost_in_v3000(),
+            vec![Unsupported::FieldType(Natural::from_int(1).unwrap())]
+        );
+        assert_eq!(molfile.to_v3000().unwrap().sgroups[0].field_type, None)
+    }
+
+    #[test]
+    fn atom_list_block() {
+        let mut molfile = v2000(&[&atom("L", 0, 0)], &[]);
+
+        molfile.atom_lists.push(AtomList {
+            atom: Natural::from_int(1).unwrap(),
+            list_type: ListType::NotList,
+            elements: vec![
+                Natural::from_int(7).unwrap(),
+                Natural::from_int(17).unwrap(),
+            ],
+        });
+
+        let molfile = molfile.to_v3000().unwrap();
+
+        assert_eq!(molfile.atom_lists, vec![]);
+        assert_eq!(
+            molfile.properties,
+            vec![
+                Property::AtomList {
+                    atom: Natural::from_int(1).unwrap(),
+                    list_type: ListType::NotList,
+                    elements: vec![
+                        Sequence::from_str("N   ").unwrap(),
+                        Sequence::from_str("Cl  ").unwrap()
+                    ]
+                },
+                Property::End
+            ]
+        )
+    }
+
+    #[test]
+    fn unknown_atom_list_element() {
+        let mut molfile = v2000(&[&atom("L", 0, 0)], &[]);
+        let atom_list = AtomList {
+            atom: Natural::from_int(1).unwrap(),
+            list_type: ListType::List,
+            elements: vec![Natural::from_int(200).unwrap()],
+        };
+
+        molfile.atom_lists.push(atom_list);
+
+        assert_eq!(
+            molfile.to_v3000(),
+            Err(vec![Unsupported::AtomList(Natural::from_int(1).unwrap())])
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let molfile = v2000(
+            &[&atom("R#", 0, 0), &atom("C", 0, 0)],
+            &[
+                "M  CHG  1   2  -1",
+                "M  ISO  1   2  13",
+                "M  RGP  1   1   2",
+                "M  ALS   2  2 F N   O   ",
+                "M  SUB  1   2  -2",
+                "M  STY  1   1 SUP",
+                "M  SAL   1  1   2",
+                "M  SMT   1 Boc",
+            ],
+        );
+
+        assert_eq!(molfile.to_v3000().unwrap().to_v2000(), Ok(molfile))
+    }
+}
+
+#[cfg(test)]
+mod to_v2000 {
+    use super::*;
+    use crate::{
+        molfile::{BracketStyle, StereoGroupKind},
+        primitive::Text,
+        read::{molfile, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn v3000(atoms: usize, bonds: &[&str]) -> Molfile {
+        let mut lines = vec![
+            String::new(),
+            String::new(),
+            String::new(),
+            "  0  0  0     0  0            999 V3000".to_string(),
+            "M  V30 BEGIN CTAB".to_string(),
+            format!("M  V30 COUNTS {} {} 0 0 0", atoms, bonds.len()),
+            "M  V30 BEGIN ATOM".to_string(),
+        ];
+
+        for index in 1..=atoms {
+            lines.push(format!("M  V30 {} C 0 0 0 0", index))
+        }
+
+        lines.push("M  V30 END ATOM".to_string());
+
+        if !bonds.is_empty() {
+            lines.push("M  V30 BEGIN BOND".to_string());
+            lines.extend(bonds.iter().map(|bond| bond.to_string()));
+            lines.push("M  V30 END BOND".to_string());
+        }
+
+        lines.push("M  V30 END CTAB".to_string());
+        lines.push("M  END".to_string());
+
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        molfile(&mut reader).unwrap()
+    }
+
+    fn natural(value: u32) -> Natural {
+        Natural::from_int(value).unwrap()
+    }
+
+    #[test]
+    fn counts() {
+        let molfile = v3000(2, &["M  V30 1 1 1 2"]).to_v2000().unwrap();
+
+        assert_eq!(
+            molfile.header.counts,
+            Counts {
+                atoms: FixedCount::from_int(2).unwrap(),
+                bonds: FixedCount::from_int(1).unwrap(),
+                atom_lists: FixedCount::Zero,
+                chiral: molfile.header.counts.chiral.clone(),
+                stext: Some(FixedCount::Zero),
+                version: Version::V2
+            }
+        )
+    }
+
+    #[test]
+    fn too_many_atoms() {
+        assert_eq!(
+            v3000(1000, &[]).to_v2000(),
+            Err(vec![Unsupported::Atoms(1000)])
+        )
+    }
+
+    #[test]
+    fn stereo_groups() {
+        let mut molfile = v3000(1, &[]);
+
+        molfile.atoms[0].stereo_parity = FixedCount::from_int(1).unwrap();
+        molfile.stereo_groups.push(StereoGroup {
+            kind: StereoGroupKind::Absolute,
+            atoms: vec![natural(1)],
+        });
+
+        assert_eq!(molfile.to_v2000(), Err(vec![Unsupported::StereoGroups]))
+    }
+
+    #[test]
+    fn bond_types() {
+        assert_eq!(
+            v3000(3, &["M  V30 1 1 1 2", "M  V30 2 9 2 3"]).to_v2000(),
+            Err(vec![Unsupported::BondType(natural(2))])
+        )
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(
+            v3000(2, &["M  V30 1 1 1 2 ATTACH=ALL"]).to_v2000(),
+            Err(vec![Unsupported::BondKeyword(natural(1))])
+        )
+    }
+
+    #[test]
+    fn collections() {
+        let mut molfile = v3000(1, &[]);
+        let name = Text::from_str("MDLV30/HILITE").unwrap();
+
+        molfile.properties.push(Property::Collection {
+            name: name.clone(),
+            keywords: vec![],
+        });
+
+        assert_eq!(molfile.to_v2000(), Err(vec![Unsupported::Collection(name)]))
+    }
+
+    #[test]
+    fn long_sgroup_label() {
+        let mut molfile = v3000(1, &[]);
+        let mut sgroup = Sgroup::new(natural(1), SgroupType::Superatom);
+
+        sgroup.label = Text::from_str(&"x".repeat(70));
+        molfile.sgroups.push(sgroup);
+
+        assert_eq!(
+            molfile.to_v2000(),
+            Err(vec![Unsupported::SgroupLabel(natural(1))])
+        )
+    }
+
+    #[test]
+    fn multiple_with_label() {
+        let mut molfile = v3000(1, &[]);
+        let mut sgroup = Sgroup::new(natural(1), SgroupType::Multiple);
+
+        sgroup.multiplier = Some(natural(2));
+        sgroup.label = Text::from_str("2");
+        molfile.sgroups.push(sgroup);
+
+        assert_eq!(
+            molfile.to_v2000(),
+            Err(vec![Unsupported::SgroupLabel(natural(1))])
+        )
+    }
+
+    #[test]
+    fn every_feature() {
+        let mut molfile = v3000(1, &[]);
+        let mut sgroup = Sgroup::new(natural(1000), SgroupType::Data);
+
+        sgroup.head_bonds.push(natural(1));
+        sgroup.bracket_style = Some(BracketStyle::Parenthesis);
+        sgroup.multiplier = Some(natural(2));
+        sgroup.class = Text::from_str(&"x".repeat(70));
+        sgroup.field_name = Text::from_str(&"x".repeat(31));
+        molfile.sgroups.push(sgroup);
+
+        assert_eq!(
+            molfile.to_v2000(),
+            Err(vec![
+                Unsupported::SgroupIndex(natural(1000)),
+                Unsupported::SgroupBonds(natural(1000)),
+                Unsupported::BracketStyle(natural(1000)),
+                Unsupported::Multiplier(natural(1000)),
+                Unsupported::SgroupClass(natural(1000)),
+                Unsupported::SgroupField(natural(1000))
+            ])
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let molfile = v3000(2, &["M  V30 1 1 1 2 CFG=1"]);
+        let mut query = molfile.clone();
+
+        query.atoms[1].valence = FixedCount::from_int(15).unwrap();
+        query.properties.insert(
+            0,
+            Property::Charge(vec![Entry {
+                atom: natural(1),
+                value: FixedInteger::from_int(-1).unwrap(),
+            }]),
+        );
+
+        assert_eq!(query.to_v2000().unwrap().to_v3000(), Ok(query))
+    }
+}
@@ -1,6 +1,9 @@
-use crate::primitive::{FixedInteger, FixedReal, Sequence};
+use crate::{
+    primitive::{FixedInteger, FixedReal, Sequence},
+    write::{self, Emit, Writer},
+};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Parameters {
     pub user_initials: Sequence<2>,
     pub program_name: Sequence<8>,
@@ -11,3 +14,49 @@ pub struct Parameters {
     pub energy: FixedReal<6, 5>,
     pub registry_number: FixedInteger<6>,
 }
+
+impl Emit for Parameters {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.write(&self.user_initials)?;
+        writer.write(&self.program_name)?;
+        writer.write(&self.timestamp)?;
+        writer.write(&self.dimensional_codes)?;
+        writer.write(&self.major_scaling)?;
+        writer.write(&self.minor_scaling)?;
+        writer.write(&self.energy)?;
+        writer.write(&self.registry_number)
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::{text::Digit, write::emit};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parameters() {
+        let parameters = Parameters {
+            user_initials: Sequence::from_str("AB").unwrap(),
+            program_name: Sequence::from_str("CTCORE  ").unwrap(),
+            timestamp: Sequence::from_str("0101230000").unwrap(),
+            dimensional_codes: Sequence::from_str("2D").unwrap(),
+            major_scaling: FixedInteger::from_int(1).unwrap(),
+            minor_scaling: FixedReal::Integer(
+                FixedInteger::from_int(1).unwrap(),
+                vec![Digit::d0(); 5],
+            ),
+            energy: FixedReal::Integer(
+                FixedInteger::Zero,
+                vec![Digit::d0(); 5],
+            ),
+            registry_number: FixedInteger::from_int(42).unwrap(),
+        };
+
+        assert_eq!(
+            emit(&parameters),
+            Ok("ABCTCORE  01012300002D 1   1.00000     0.00000    42"
+                .to_string())
+        )
+    }
+}
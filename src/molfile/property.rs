@@ -0,0 +1,84 @@
+use crate::primitive::{
+    FixedCount, FixedInteger, Line, Natural, Sequence, Text,
+};
+
+use super::{Entry, ListType};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Property {
+    Charge(Vec<Entry<FixedInteger<3>>>),
+    Radical(Vec<Entry<FixedCount<3>>>),
+    Isotope(Vec<Entry<FixedCount<3>>>),
+    RingBondCount(Vec<Entry<FixedInteger<3>>>),
+    SubstitutionCount(Vec<Entry<FixedInteger<3>>>),
+    Unsaturated(Vec<Entry<FixedCount<3>>>),
+    AtomList {
+        atom: Natural,
+        list_type: ListType,
+        elements: Vec<Sequence<4>>,
+    },
+    AttachmentPoint(Vec<Entry<FixedCount<3>>>),
+    AttachmentOrder {
+        atom: Natural,
+        entries: Vec<Entry<FixedCount<3>>>,
+    },
+    RgroupLabel(Vec<Entry<Natural>>),
+    RgroupLogic {
+        rgroup: Natural,
+        if_then: FixedCount<3>,
+        rest_h: FixedCount<3>,
+        occurrence: Line<80>,
+    },
+    Link(Vec<LinkAtom>),
+    // M  ZCH
+    ZeroOrderCharge(Vec<Entry<FixedInteger<3>>>),
+    // M  ZZC
+    AtomLabel {
+        atom: Natural,
+        label: Line<80>,
+    },
+    Registry(Natural),
+    // A  aaa, with the alias on the following line
+    AtomAlias {
+        atom: Natural,
+        alias: Line<80>,
+    },
+    // V  aaa
+    AtomValue {
+        atom: Natural,
+        value: Line<80>,
+    },
+    // G  aaappp, with the abbreviation on the following line
+    GroupAbbreviation {
+        atom: Natural,
+        matching: Natural,
+        label: Line<80>,
+    },
+    // V3000 atom and bond keywords without a model field, kept verbatim
+    AtomKeyword {
+        atom: Natural,
+        keyword: Text,
+        value: Text,
+    },
+    BondKeyword {
+        bond: Natural,
+        keyword: Text,
+        value: Text,
+    },
+    // V3000 collections other than enhanced stereo, such as MDLV30/HILITE
+    Collection {
+        name: Text,
+        keywords: Vec<(Text, Text)>,
+    },
+    // M  lines with an unrecognized keyword
+    Unknown(Line<80>),
+    End,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct LinkAtom {
+    pub atom: Natural,
+    pub repetitions: FixedCount<3>,
+    pub first: Natural,
+    pub second: Natural,
+}
@@ -0,0 +1,255 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReactingCenter {
+    NotCenter,
+    Unmarked,
+    Center,
+    NoChange,
+    MakeOrBreak,
+    CenterMakeOrBreak,
+    OrderChange,
+    CenterOrderChange,
+    MakeOrBreakAndOrderChange,
+    CenterMakeOrBreakAndOrderChange,
+}
+
+impl ReactingCenter {
+    pub fn start() -> impl Builder<Product = ReactingCenter> {
+        ReactingCenterBuilder::Pad
+    }
+}
+
+impl Emit for ReactingCenter {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.literal(match self {
+            Self::NotCenter => b" -1",
+            Self::Unmarked => b"  0",
+            Self::Center => b"  1",
+            Self::NoChange => b"  2",
+            Self::MakeOrBreak => b"  4",
+            Self::CenterMakeOrBreak => b"  5",
+            Self::OrderChange => b"  8",
+            Self::CenterOrderChange => b"  9",
+            Self::MakeOrBreakAndOrderChange => b" 12",
+            Self::CenterMakeOrBreakAndOrderChange => b" 13",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ReactingCenterBuilder {
+    Pad,
+    Sign,
+    Minus,
+    Units,
+    Teens,
+}
+
+impl Builder for ReactingCenterBuilder {
+    type Product = ReactingCenter;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self {
+            Self::Pad => {
+                if printable == Printable::Space {
+                    Ok(Target::Builder(Self::Sign))
+                } else {
+                    Err(Error::space())
+                }
+            }
+            Self::Sign => match printable {
+                Printable::Space => Ok(Target::Builder(Self::Units)),
+                Printable::Minus => Ok(Target::Builder(Self::Minus)),
+                Printable::D1 => Ok(Target::Builder(Self::Teens)),
+                _ => Err(Error::Character(vec![
+                    Printable::Space,
+                    Printable::Minus,
+                    Printable::D1,
+                ])),
+            },
+            Self::Minus => match printable {
+                Printable::D1 => Ok(Target::Product(ReactingCenter::NotCenter)),
+                _ => Err(Error::Character(vec![Printable::D1])),
+            },
+            Self::Units => match printable {
+                Printable::D0 => Ok(Target::Product(ReactingCenter::Unmarked)),
+                Printable::D1 => Ok(Target::Product(ReactingCenter::Center)),
+                Printable::D2 => Ok(Target::Product(ReactingCenter::NoChange)),
+                Printable::D4 => {
+                    Ok(Target::Product(ReactingCenter::MakeOrBreak))
+                }
+                Printable::D5 => {
+                    Ok(Target::Product(ReactingCenter::CenterMakeOrBreak))
+                }
+                Printable::D8 => {
+                    Ok(Target::Product(ReactingCenter::OrderChange))
+                }
+                Printable::D9 => {
+                    Ok(Target::Product(ReactingCenter::CenterOrderChange))
+                }
+                _ => Err(Error::Character(vec![
+                    Printable::D0,
+                    Printable::D1,
+                    Printable::D2,
+                    Printable::D4,
+                    Printable::D5,
+                    Printable::D8,
+                    Printable::D9,
+                ])),
+            },
+            Self::Teens => match printable {
+                Printable::D2 => Ok(Target::Product(
+                    ReactingCenter::MakeOrBreakAndOrderChange,
+                )),
+                Printable::D3 => Ok(Target::Product(
+                    ReactingCenter::CenterMakeOrBreakAndOrderChange,
+                )),
+                _ => Err(Error::Character(vec![Printable::D2, Printable::D3])),
+            },
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn pad_minus() {
+        let builder = ReactingCenterBuilder::Pad;
+
+        assert_eq!(builder.push(Printable::Minus), Err(Error::space()))
+    }
+
+    #[test]
+    fn sign_two() {
+        let builder = ReactingCenterBuilder::Sign;
+
+        assert_eq!(
+            builder.push(Printable::D2),
+            Err(Error::Character(vec![
+                Printable::Space,
+                Printable::Minus,
+                Printable::D1
+            ]))
+        )
+    }
+
+    #[test]
+    fn sign_minus() {
+        let builder = ReactingCenterBuilder::Sign;
+
+        assert_eq!(
+            builder.push(Printable::Minus),
+            Ok(Target::Builder(ReactingCenterBuilder::Minus))
+        )
+    }
+
+    #[test]
+    fn minus_one() {
+        let builder = ReactingCenterBuilder::Minus;
+
+        assert_eq!(
+            builder.push(Printable::D1),
+            Ok(Target::Product(ReactingCenter::NotCenter))
+        )
+    }
+
+    #[test]
+    fn minus_two() {
+        let builder = ReactingCenterBuilder::Minus;
+
+        assert_eq!(
+            builder.push(Printable::D2),
+            Err(Error::Character(vec![Printable::D1]))
+        )
+    }
+
+    #[test]
+    fn units_three() {
+        let builder = ReactingCenterBuilder::Units;
+
+        assert_eq!(
+            builder.push(Printable::D3),
+            Err(Error::Character(vec![
+                Printable::D0,
+                Printable::D1,
+                Printable::D2,
+                Printable::D4,
+                Printable::D5,
+                Printable::D8,
+                Printable::D9,
+            ]))
+        )
+    }
+
+    #[test]
+    fn units_nine() {
+        let builder = ReactingCenterBuilder::Units;
+
+        assert_eq!(
+            builder.push(Printable::D9),
+            Ok(Target::Product(ReactingCenter::CenterOrderChange))
+        )
+    }
+
+    #[test]
+    fn teens_three() {
+        let builder = ReactingCenterBuilder::Teens;
+
+        assert_eq!(
+            builder.push(Printable::D3),
+            Ok(Target::Product(
+                ReactingCenter::CenterMakeOrBreakAndOrderChange
+            ))
+        )
+    }
+
+    #[test]
+    fn teens_four() {
+        let builder = ReactingCenterBuilder::Teens;
+
+        assert_eq!(
+            builder.push(Printable::D4),
+            Err(Error::Character(vec![Printable::D2, Printable::D3]))
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_center() {
+        assert_eq!(emit(&ReactingCenter::NotCenter), Ok(" -1".to_string()))
+    }
+
+    #[test]
+    fn unmarked() {
+        assert_eq!(emit(&ReactingCenter::Unmarked), Ok("  0".to_string()))
+    }
+
+    #[test]
+    fn center_make_or_break_and_order_change() {
+        assert_eq!(
+            emit(&ReactingCenter::CenterMakeOrBreakAndOrderChange),
+            Ok(" 13".to_string())
+        )
+    }
+}
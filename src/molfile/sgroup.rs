@@ -0,0 +1,75 @@
+use crate::primitive::{Natural, Text};
+
+use super::{
+    Bracket, BracketStyle, Connectivity, CrossingVector, SgroupAttachment,
+    SgroupSubtype, SgroupType,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Sgroup {
+    pub index: Natural,
+    pub sgroup_type: SgroupType,
+    pub external_index: Option<Natural>,
+    pub atoms: Vec<Natural>,
+    pub crossing_bonds: Vec<Natural>,
+    pub containment_bonds: Vec<Natural>,
+    pub paradigmatic_atoms: Vec<Natural>,
+    // XBHEAD
+    pub head_bonds: Vec<Natural>,
+    // XBCORR
+    pub bond_correspondence: Vec<Natural>,
+    pub subtype: Option<SgroupSubtype>,
+    pub connectivity: Option<Connectivity>,
+    pub parent: Option<Natural>,
+    pub component: Option<Natural>,
+    pub label: Option<Text>,
+    pub multiplier: Option<Natural>,
+    pub brackets: Vec<Bracket>,
+    pub bracket_style: Option<BracketStyle>,
+    pub expanded: bool,
+    pub crossing_vectors: Vec<CrossingVector>,
+    pub attachments: Vec<SgroupAttachment>,
+    pub class: Option<Text>,
+    pub field_name: Option<Text>,
+    pub field_type: Option<Text>,
+    pub field_info: Option<Text>,
+    pub field_display: Option<Text>,
+    pub query_type: Option<Text>,
+    pub query_operator: Option<Text>,
+    pub field_data: Vec<Text>,
+}
+
+impl Sgroup {
+    pub fn new(index: Natural, sgroup_type: SgroupType) -> Self {
+        Self {
+            index,
+            sgroup_type,
+            external_index: None,
+            atoms: vec![],
+            crossing_bonds: vec![],
+            containment_bonds: vec![],
+            paradigmatic_atoms: vec![],
+            head_bonds: vec![],
+            bond_correspondence: vec![],
+            subtype: None,
+            connectivity: None,
+            parent: None,
+            component: None,
+            label: None,
+            multiplier: None,
+            brackets: vec![],
+            bracket_style: None,
+            expanded: false,
+            crossing_vectors: vec![],
+            attachments: vec![],
+            class: None,
+            field_name: None,
+            field_type: None,
+            field_info: None,
+            field_display: None,
+            query_type: None,
+            query_operator: None,
+            field_data: vec![],
+        }
+    }
+}
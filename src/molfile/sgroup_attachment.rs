@@ -0,0 +1,8 @@
+use crate::primitive::{Natural, Sequence};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SgroupAttachment {
+    pub atom: Natural,
+    pub leaving_atom: Option<Natural>,
+    pub id: Sequence<2>,
+}
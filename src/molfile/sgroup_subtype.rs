@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SgroupSubtype {
+    // ALT
+    Alternating,
+    // RAN
+    Random,
+    // BLO
+    Block,
+}
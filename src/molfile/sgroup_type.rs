@@ -0,0 +1,25 @@
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SgroupType {
+    // SUP
+    Superatom,
+    // MUL
+    Multiple,
+    // SRU
+    StructureRepeatingUnit,
+    // MON
+    Monomer,
+    // COP
+    Copolymer,
+    // DAT
+    Data,
+    // GEN
+    Generic,
+    // FOR
+    Formulation,
+    // ANY
+    AnyPolymer,
+    // COM
+    Component,
+    // MIX
+    Mixture,
+}
@@ -0,0 +1,9 @@
+use crate::primitive::Natural;
+
+use super::StereoGroupKind;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StereoGroup {
+    pub kind: StereoGroupKind,
+    pub atoms: Vec<Natural>,
+}
@@ -0,0 +1,11 @@
+use crate::primitive::Natural;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum StereoGroupKind {
+    // STEABS
+    Absolute,
+    // STERACn
+    Racemic(Natural),
+    // STERELn
+    Relative(Natural),
+}
@@ -0,0 +1,8 @@
+use crate::primitive::{FixedReal, Line};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stext {
+    pub x: FixedReal<5, 4>,
+    pub y: FixedReal<5, 4>,
+    pub text: Line<80>,
+}
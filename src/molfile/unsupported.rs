@@ -0,0 +1,36 @@
+use crate::primitive::{Natural, Text};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Unsupported {
+    Atoms(usize),
+    Bonds(usize),
+    AtomLists(usize),
+    StextEntries(usize),
+    SgroupIndex(Natural),
+    StereoGroups,
+    // coordination and hydrogen bonds
+    BondType(Natural),
+    // CBONDS, XBHEAD, XBCORR
+    SgroupBonds(Natural),
+    BracketStyle(Natural),
+    Multiplier(Natural),
+    SgroupLabel(Natural),
+    SgroupClass(Natural),
+    SgroupField(Natural),
+    // V3000 keywords kept without a model field
+    AtomKeyword(Natural),
+    BondKeyword(Natural),
+    // V3000 collections other than enhanced stereo, by name
+    Collection(Text),
+    // V2000 features with no V3000 form
+    Stext,
+    // atom block charge codes other than 0 to 7
+    Charge(Natural),
+    // atom block mass difference on an atom without a standard mass
+    MassDifference(Natural),
+    H0Designator(Natural),
+    // atom list element without a symbol
+    AtomList(Natural),
+    // M  SDT field type
+    FieldType(Natural),
+}
@@ -1,9 +1,10 @@
 use crate::{
     build::{Builder, Error, Target},
     text::Printable,
+    write::{self, Emit, Writer},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Version {
     V2,
     V3,
@@ -15,6 +16,15 @@ impl Version {
     }
 }
 
+impl Emit for Version {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::V2 => writer.literal(b" V2000"),
+            Self::V3 => writer.literal(b" V3000"),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum VersionBuilder {
     Pad,
@@ -222,3 +232,20 @@ mod builder_done {
         assert_eq!(builder.done(), None)
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn v2() {
+        assert_eq!(emit(&Version::V2), Ok(" V2000".to_string()))
+    }
+
+    #[test]
+    fn v3() {
+        assert_eq!(emit(&Version::V3), Ok(" V3000".to_string()))
+    }
+}
@@ -1,9 +1,10 @@
 use crate::build::{Builder, Error, Target};
 use crate::text::{Digit, Printable};
+use crate::write::{self, Emit, Writer};
 
 use super::Natural;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum FixedCount<const I: usize> {
     Zero,
     Natural(Natural),
@@ -14,11 +15,15 @@ impl<const I: usize> FixedCount<I> {
         FixedCountBuilder::<I>::Pad(0)
     }
 
+    pub fn start_optional() -> impl Builder<Product = Option<FixedCount<I>>> {
+        OptionalFixedCountBuilder::<I>::Blank(0)
+    }
+
     pub fn from_int(int: u32) -> Option<Self> {
         if int == 0 {
             Some(Self::Zero)
         } else {
-            let natural = Natural::from_int(int as u32)?;
+            let natural = Natural::from_int(int)?;
 
             if natural.len() > I {
                 None
@@ -27,6 +32,22 @@ impl<const I: usize> FixedCount<I> {
             }
         }
     }
+
+    pub fn to_int(&self) -> u32 {
+        match self {
+            Self::Zero => 0,
+            Self::Natural(natural) => natural.to_int(),
+        }
+    }
+}
+
+impl<const I: usize> Emit for FixedCount<I> {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::Zero => writer.right(&[Printable::D0], I),
+            Self::Natural(natural) => writer.right(&natural.printables(), I),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -107,6 +128,48 @@ impl<const I: usize> Builder for FixedCountBuilder<I> {
     }
 }
 
+#[derive(Debug, PartialEq)]
+enum OptionalFixedCountBuilder<const I: usize> {
+    Blank(usize),
+    Count(FixedCountBuilder<I>),
+}
+
+impl<const I: usize> Builder for OptionalFixedCountBuilder<I> {
+    type Product = Option<FixedCount<I>>;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        let builder = match self {
+            Self::Blank(padding) => {
+                if printable == Printable::Space {
+                    return Ok(if padding + 1 == I {
+                        Target::Product(None)
+                    } else {
+                        Target::Builder(Self::Blank(padding + 1))
+                    });
+                }
+
+                FixedCountBuilder::Pad(padding)
+            }
+            Self::Count(builder) => builder,
+        };
+
+        Ok(match builder.push(printable)? {
+            Target::Builder(builder) => Target::Builder(Self::Count(builder)),
+            Target::Product(product) => Target::Product(Some(product)),
+        })
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        match self {
+            Self::Blank(_) => None,
+            Self::Count(builder) => builder.done().map(Some),
+        }
+    }
+}
+
 #[cfg(test)]
 mod from_int {
     use super::*;
@@ -131,6 +194,22 @@ mod from_int {
     }
 }
 
+#[cfg(test)]
+mod to_int {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        assert_eq!(FixedCount::<3>::Zero.to_int(), 0)
+    }
+
+    #[test]
+    fn natural() {
+        assert_eq!(FixedCount::<3>::from_int(42).unwrap().to_int(), 42)
+    }
+}
+
 #[cfg(test)]
 mod builder_push {
     use pretty_assertions::assert_eq;
@@ -230,6 +309,55 @@ mod builder_push {
     }
 }
 
+#[cfg(test)]
+mod optional_builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn blank_not_limit_space() {
+        let builder = OptionalFixedCountBuilder::<3>::Blank(1);
+
+        assert_eq!(
+            builder.push(Printable::Space),
+            Ok(Target::Builder(OptionalFixedCountBuilder::Blank(2)))
+        )
+    }
+
+    #[test]
+    fn blank_limit_space() {
+        let builder = OptionalFixedCountBuilder::<3>::Blank(2);
+
+        assert_eq!(builder.push(Printable::Space), Ok(Target::Product(None)))
+    }
+
+    #[test]
+    fn blank_limit_zero() {
+        let builder = OptionalFixedCountBuilder::<3>::Blank(2);
+
+        assert_eq!(
+            builder.push(Printable::D0),
+            Ok(Target::Product(Some(FixedCount::Zero)))
+        )
+    }
+
+    #[test]
+    fn blank_not_limit_zero() {
+        let builder = OptionalFixedCountBuilder::<3>::Blank(1);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::non_zero_digit()))
+    }
+
+    #[test]
+    fn count_space() {
+        let builder = OptionalFixedCountBuilder::<3>::Count(
+            FixedCountBuilder::Count(1, Natural::from_int(4).unwrap()),
+        );
+
+        assert_eq!(builder.push(Printable::Space), Err(Error::digit()))
+    }
+}
+
 #[cfg(test)]
 mod builder_done {
     use super::*;
@@ -258,3 +386,38 @@ mod builder_done {
         assert_eq!(builder.done(), Some(FixedCount::from_int(42).unwrap()))
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        let count = FixedCount::<3>::Zero;
+
+        assert_eq!(emit(&count), Ok("  0".to_string()))
+    }
+
+    #[test]
+    fn natural() {
+        let count = FixedCount::<3>::from_int(42).unwrap();
+
+        assert_eq!(emit(&count), Ok(" 42".to_string()))
+    }
+
+    #[test]
+    fn full() {
+        let count = FixedCount::<3>::from_int(999).unwrap();
+
+        assert_eq!(emit(&count), Ok("999".to_string()))
+    }
+
+    #[test]
+    fn overflow() {
+        let count = FixedCount::<3>::Natural(Natural::from_int(1000).unwrap());
+
+        assert_eq!(emit(&count), Err(write::Error::Overflow(0, 0)))
+    }
+}
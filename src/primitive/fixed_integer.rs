@@ -1,11 +1,12 @@
 use crate::{
     build::{Builder, Error, Target},
     text::{Digit, NonZeroDigit, Printable},
+    write::{self, Emit, Writer},
 };
 
 use super::Natural;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum FixedInteger<const I: usize> {
     Zero,
     Positive(Natural),
@@ -17,9 +18,20 @@ impl<const I: usize> FixedInteger<I> {
         FixedIntegerBuilder::<I>::Pad(0)
     }
 
+    pub fn start_range(
+        min: i32,
+        max: i32,
+    ) -> impl Builder<Product = FixedInteger<I>> {
+        RangeBuilder {
+            builder: FixedIntegerBuilder::<I>::Pad(0),
+            min,
+            max,
+        }
+    }
+
     pub fn from_int(int: i32) -> Option<Self> {
         if int < 0 {
-            let natural = Natural::from_int(int.abs() as u32)?;
+            let natural = Natural::from_int(int.unsigned_abs())?;
 
             if 1 + natural.len() > I {
                 None
@@ -38,6 +50,30 @@ impl<const I: usize> FixedInteger<I> {
             }
         }
     }
+
+    pub fn to_int(&self) -> i32 {
+        match self {
+            Self::Zero => 0,
+            Self::Positive(natural) => natural.to_int() as i32,
+            Self::Negative(natural) => -(natural.to_int() as i32),
+        }
+    }
+}
+
+impl<const I: usize> Emit for FixedInteger<I> {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        match self {
+            Self::Zero => writer.right(&[Printable::D0], I),
+            Self::Positive(natural) => writer.right(&natural.printables(), I),
+            Self::Negative(natural) => {
+                let mut printables = vec![Printable::Minus];
+
+                printables.extend(natural.printables());
+
+                writer.right(&printables, I)
+            }
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -172,6 +208,54 @@ impl<const I: usize> Builder for FixedIntegerBuilder<I> {
     }
 }
 
+// rejects values outside min..=max once the field is complete
+struct RangeBuilder<const I: usize> {
+    builder: FixedIntegerBuilder<I>,
+    min: i32,
+    max: i32,
+}
+
+impl<const I: usize> Builder for RangeBuilder<I> {
+    type Product = FixedInteger<I>;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self.builder.push(printable)? {
+            Target::Builder(builder) => Ok(Target::Builder(Self {
+                builder,
+                min: self.min,
+                max: self.max,
+            })),
+            Target::Product(product) => {
+                if (self.min..=self.max).contains(&product.to_int()) {
+                    Ok(Target::Product(product))
+                } else {
+                    Err(Error::Range(self.min, self.max))
+                }
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        self.builder
+            .done()
+            .filter(|product| (self.min..=self.max).contains(&product.to_int()))
+    }
+
+    fn finish(self) -> Result<Self::Product, Error> {
+        let (min, max) = (self.min, self.max);
+        let product = self.builder.finish()?;
+
+        if (min..=max).contains(&product.to_int()) {
+            Ok(product)
+        } else {
+            Err(Error::Range(min, max))
+        }
+    }
+}
+
 #[cfg(test)]
 mod from_int {
     use super::*;
@@ -209,6 +293,27 @@ mod from_int {
     }
 }
 
+#[cfg(test)]
+mod to_int {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        assert_eq!(FixedInteger::<2>::Zero.to_int(), 0)
+    }
+
+    #[test]
+    fn negative() {
+        assert_eq!(FixedInteger::<3>::from_int(-42).unwrap().to_int(), -42)
+    }
+
+    #[test]
+    fn positive() {
+        assert_eq!(FixedInteger::<3>::from_int(42).unwrap().to_int(), 42)
+    }
+}
+
 #[cfg(test)]
 mod builder_push {
     use super::*;
@@ -466,3 +571,90 @@ mod builder_done {
         assert_eq!(builder.done(), None)
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        let integer = FixedInteger::<3>::Zero;
+
+        assert_eq!(emit(&integer), Ok("  0".to_string()))
+    }
+
+    #[test]
+    fn positive() {
+        let integer = FixedInteger::<3>::from_int(12).unwrap();
+
+        assert_eq!(emit(&integer), Ok(" 12".to_string()))
+    }
+
+    #[test]
+    fn negative() {
+        let integer = FixedInteger::<3>::from_int(-12).unwrap();
+
+        assert_eq!(emit(&integer), Ok("-12".to_string()))
+    }
+
+    #[test]
+    fn negative_overflow() {
+        let integer =
+            FixedInteger::<3>::Negative(Natural::from_int(123).unwrap());
+
+        assert_eq!(emit(&integer), Err(write::Error::Overflow(0, 0)))
+    }
+}
+
+#[cfg(test)]
+mod range_builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn push(str: &str) -> Result<FixedInteger<3>, Error> {
+        let mut builder = FixedInteger::<3>::start_range(-15, 15);
+
+        for byte in str.bytes() {
+            builder = match builder.push(Printable::from_byte(byte).unwrap())? {
+                Target::Builder(builder) => builder,
+                Target::Product(product) => return Ok(product),
+            }
+        }
+
+        unreachable!("fixed width")
+    }
+
+    #[test]
+    fn within() {
+        assert_eq!(push("-15"), Ok(FixedInteger::from_int(-15).unwrap()))
+    }
+
+    #[test]
+    fn above() {
+        assert_eq!(push(" 16"), Err(Error::Range(-15, 15)))
+    }
+
+    #[test]
+    fn below() {
+        assert_eq!(push("-16"), Err(Error::Range(-15, 15)))
+    }
+}
+
+#[cfg(test)]
+mod range_builder_finish {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn partial() {
+        let builder = FixedInteger::<3>::start_range(-15, 15);
+        let builder = match builder.push(Printable::Minus) {
+            Ok(Target::Builder(builder)) => builder,
+            _ => unreachable!("partial field"),
+        };
+
+        assert_eq!(builder.finish(), Err(Error::End))
+    }
+}
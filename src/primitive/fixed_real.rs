@@ -1,15 +1,16 @@
 use crate::{
     build::{Builder, Error, Target},
     text::{Digit, Printable},
+    write::{self, Emit, Writer},
 };
 
 use super::{fixed_integer::FixedIntegerBuilder, FixedInteger};
 
-#[derive(Debug, PartialEq)]
-pub struct FixedReal<const I: usize, const F: usize>(
-    FixedInteger<I>,
-    Vec<Digit>,
-);
+#[derive(Debug, PartialEq, Clone)]
+pub enum FixedReal<const I: usize, const F: usize> {
+    Integer(FixedInteger<I>, Vec<Digit>),
+    NegativeZero(Vec<Digit>),
+}
 
 impl<const I: usize, const F: usize> FixedReal<I, F> {
     pub fn start() -> impl Builder<Product = FixedReal<I, F>> {
@@ -17,11 +18,50 @@ impl<const I: usize, const F: usize> FixedReal<I, F> {
     }
 }
 
+impl<const I: usize, const F: usize> Emit for FixedReal<I, F> {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        let digits = match self {
+            Self::Integer(fixed_integer, digits) => {
+                writer.write(fixed_integer)?;
+
+                digits
+            }
+            Self::NegativeZero(digits) => {
+                writer.right(&[Printable::Minus, Printable::D0], I)?;
+
+                digits
+            }
+        };
+
+        writer.push(Printable::Dot)?;
+
+        if digits.len() != F {
+            return Err(write::Error::Overflow(writer.row, writer.column));
+        }
+
+        writer.push_all(
+            &digits.iter().map(Digit::to_printable).collect::<Vec<_>>(),
+        )
+    }
+}
+
 #[derive(Debug, PartialEq)]
 enum FixedRealBuilder<const I: usize, const F: usize> {
     IntegerPart(FixedIntegerBuilder<I>),
-    Decimal(FixedInteger<I>),
-    FractionalPart(FixedInteger<I>, Vec<Digit>),
+    Decimal(Option<FixedInteger<I>>),
+    FractionalPart(Option<FixedInteger<I>>, Vec<Digit>),
+}
+
+impl<const I: usize, const F: usize> FixedRealBuilder<I, F> {
+    fn product(
+        fixed_integer: Option<FixedInteger<I>>,
+        digits: Vec<Digit>,
+    ) -> FixedReal<I, F> {
+        match fixed_integer {
+            Some(fixed_integer) => FixedReal::Integer(fixed_integer, digits),
+            None => FixedReal::NegativeZero(digits),
+        }
+    }
 }
 
 impl<const I: usize, const F: usize> Builder for FixedRealBuilder<I, F> {
@@ -32,12 +72,18 @@ impl<const I: usize, const F: usize> Builder for FixedRealBuilder<I, F> {
         printable: Printable,
     ) -> Result<Target<Self::Product, Self>, Error> {
         match self {
+            // "-0" is not an integer, but it is the integer part of a real
+            Self::IntegerPart(FixedIntegerBuilder::Minus(padding))
+                if padding + 2 == I && printable == Printable::D0 =>
+            {
+                Ok(Target::Builder(Self::Decimal(None)))
+            }
             Self::IntegerPart(builder) => match builder.push(printable)? {
                 Target::Builder(builder) => {
                     Ok(Target::Builder(Self::IntegerPart(builder)))
                 }
                 Target::Product(fixed_integer) => {
-                    Ok(Target::Builder(Self::Decimal(fixed_integer)))
+                    Ok(Target::Builder(Self::Decimal(Some(fixed_integer))))
                 }
             },
             Self::Decimal(fixed_integer) => {
@@ -56,7 +102,7 @@ impl<const I: usize, const F: usize> Builder for FixedRealBuilder<I, F> {
                         digits.push(digit);
 
                         if digits.len() == F {
-                            Ok(Target::Product(FixedReal(
+                            Ok(Target::Product(Self::product(
                                 fixed_integer,
                                 digits,
                             )))
@@ -77,7 +123,7 @@ impl<const I: usize, const F: usize> Builder for FixedRealBuilder<I, F> {
         match self {
             Self::FractionalPart(fixed_integer, digits) => {
                 if digits.len() == F {
-                    Some(FixedReal(fixed_integer, digits))
+                    Some(Self::product(fixed_integer, digits))
                 } else {
                     None
                 }
@@ -116,17 +162,38 @@ mod builder_push {
 
         assert_eq!(
             builder.push(Printable::D4),
-            Ok(Target::Builder(FixedRealBuilder::Decimal(
+            Ok(Target::Builder(FixedRealBuilder::Decimal(Some(
                 FixedInteger::from_int(4).unwrap()
-            )))
+            ))))
         )
     }
 
+    #[test]
+    fn integer_part_limit_negative_zero() {
+        let builder = FixedRealBuilder::<3, 2>::IntegerPart(
+            FixedIntegerBuilder::<3>::Minus(1),
+        );
+
+        assert_eq!(
+            builder.push(Printable::D0),
+            Ok(Target::Builder(FixedRealBuilder::Decimal(None)))
+        )
+    }
+
+    #[test]
+    fn integer_part_not_limit_negative_zero() {
+        let builder = FixedRealBuilder::<3, 2>::IntegerPart(
+            FixedIntegerBuilder::<3>::Minus(0),
+        );
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::non_zero_digit()))
+    }
+
     #[test]
     fn decimal_non_dot() {
-        let builder = FixedRealBuilder::<1, 3>::Decimal(
+        let builder = FixedRealBuilder::<1, 3>::Decimal(Some(
             FixedInteger::from_int(2).unwrap(),
-        );
+        ));
 
         assert_eq!(
             builder.push(Printable::Space),
@@ -136,14 +203,14 @@ mod builder_push {
 
     #[test]
     fn decimal_dot() {
-        let builder = FixedRealBuilder::<1, 3>::Decimal(
+        let builder = FixedRealBuilder::<1, 3>::Decimal(Some(
             FixedInteger::from_int(2).unwrap(),
-        );
+        ));
 
         assert_eq!(
             builder.push(Printable::Dot),
             Ok(Target::Builder(FixedRealBuilder::FractionalPart(
-                FixedInteger::from_int(2).unwrap(),
+                Some(FixedInteger::from_int(2).unwrap()),
                 vec![]
             )))
         )
@@ -152,7 +219,7 @@ mod builder_push {
     #[test]
     fn fractional_non_digit() {
         let builder = FixedRealBuilder::<1, 3>::FractionalPart(
-            FixedInteger::from_int(2).unwrap(),
+            Some(FixedInteger::from_int(2).unwrap()),
             vec![],
         );
 
@@ -162,13 +229,13 @@ mod builder_push {
     #[test]
     fn fractional_limit_digit() {
         let builder = FixedRealBuilder::<1, 1>::FractionalPart(
-            FixedInteger::from_int(4).unwrap(),
+            Some(FixedInteger::from_int(4).unwrap()),
             vec![],
         );
 
         assert_eq!(
             builder.push(Printable::D2),
-            Ok(Target::Product(FixedReal(
+            Ok(Target::Product(FixedReal::Integer(
                 FixedInteger::from_int(4).unwrap(),
                 vec![Digit::d2()]
             )))
@@ -178,14 +245,14 @@ mod builder_push {
     #[test]
     fn fractional_not_limit_digit() {
         let builder = FixedRealBuilder::<1, 2>::FractionalPart(
-            FixedInteger::from_int(4).unwrap(),
+            Some(FixedInteger::from_int(4).unwrap()),
             vec![],
         );
 
         assert_eq!(
             builder.push(Printable::D2),
             Ok(Target::Builder(FixedRealBuilder::FractionalPart(
-                FixedInteger::from_int(4).unwrap(),
+                Some(FixedInteger::from_int(4).unwrap()),
                 vec![Digit::d2()]
             )))
         )
@@ -200,7 +267,7 @@ mod builder_done {
     #[test]
     fn fractional_not_done() {
         let builder = FixedRealBuilder::<1, 2>::FractionalPart(
-            FixedInteger::from_int(4).unwrap(),
+            Some(FixedInteger::from_int(4).unwrap()),
             vec![],
         );
 
@@ -210,19 +277,30 @@ mod builder_done {
     #[test]
     fn fractional_done() {
         let builder = FixedRealBuilder::<1, 1>::FractionalPart(
-            FixedInteger::from_int(4).unwrap(),
+            Some(FixedInteger::from_int(4).unwrap()),
             vec![Digit::d2()],
         );
 
         assert_eq!(
             builder.done(),
-            Some(FixedReal(
+            Some(FixedReal::Integer(
                 FixedInteger::from_int(4).unwrap(),
                 vec![Digit::d2()]
             ))
         )
     }
 
+    #[test]
+    fn negative_zero_done() {
+        let builder =
+            FixedRealBuilder::<2, 1>::FractionalPart(None, vec![Digit::d5()]);
+
+        assert_eq!(
+            builder.done(),
+            Some(FixedReal::NegativeZero(vec![Digit::d5()]))
+        )
+    }
+
     #[test]
     fn non_fractional() {
         let builder = FixedRealBuilder::<2, 3>::IntegerPart(
@@ -232,3 +310,40 @@ mod builder_done {
         assert_eq!(builder.done(), None)
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn integer() {
+        let real = FixedReal::<5, 4>::Integer(
+            FixedInteger::from_int(-12).unwrap(),
+            vec![Digit::d2(), Digit::d5(), Digit::d0(), Digit::d0()],
+        );
+
+        assert_eq!(emit(&real), Ok("  -12.2500".to_string()))
+    }
+
+    #[test]
+    fn negative_zero() {
+        let real = FixedReal::<5, 4>::NegativeZero(vec![
+            Digit::d5(),
+            Digit::d0(),
+            Digit::d0(),
+            Digit::d0(),
+        ]);
+
+        assert_eq!(emit(&real), Ok("   -0.5000".to_string()))
+    }
+
+    #[test]
+    fn short_fraction() {
+        let real =
+            FixedReal::<5, 4>::Integer(FixedInteger::Zero, vec![Digit::d5()]);
+
+        assert_eq!(emit(&real), Err(write::Error::Overflow(0, 6)))
+    }
+}
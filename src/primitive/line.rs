@@ -1,9 +1,10 @@
 use crate::{
     build::{Builder, Target},
     text::Printable,
+    write::{self, Emit, Writer},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Line<const L: usize>(Vec<Printable>);
 
 impl<const L: usize> Line<L> {
@@ -11,6 +12,7 @@ impl<const L: usize> Line<L> {
         LineBuilder::<L>(Vec::new())
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(str: &str) -> Option<Self> {
         let mut builder = Self::start();
 
@@ -24,6 +26,16 @@ impl<const L: usize> Line<L> {
 
         builder.done()
     }
+
+    pub fn printables(&self) -> &[Printable] {
+        &self.0
+    }
+}
+
+impl<const L: usize> Emit for Line<L> {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push_all(&self.0)
+    }
 }
 
 struct LineBuilder<const L: usize>(Vec<Printable>);
@@ -49,3 +61,17 @@ impl<const L: usize> Builder for LineBuilder<L> {
         Some(Line(self.0))
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn printables() {
+        let item = Line::<80>::from_str("comment").unwrap();
+
+        assert_eq!(emit(&item), Ok("comment".to_string()))
+    }
+}
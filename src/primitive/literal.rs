@@ -0,0 +1,94 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Literal;
+
+impl Literal {
+    pub fn start(bytes: &'static [u8]) -> impl Builder<Product = Literal> {
+        LiteralBuilder(bytes)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct LiteralBuilder(&'static [u8]);
+
+impl Builder for LiteralBuilder {
+    type Product = Literal;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self.0.split_first() {
+            Some((byte, rest)) => {
+                if printable.to_byte() == *byte {
+                    if rest.is_empty() {
+                        Ok(Target::Product(Literal))
+                    } else {
+                        Ok(Target::Builder(Self(rest)))
+                    }
+                } else {
+                    Err(Error::Character(
+                        Printable::from_byte(*byte).into_iter().collect(),
+                    ))
+                }
+            }
+            None => Err(Error::Character(vec![])),
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        if self.0.is_empty() {
+            Some(Literal)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn mismatch() {
+        let builder = LiteralBuilder(b"M  ");
+
+        assert_eq!(
+            builder.push(Printable::A),
+            Err(Error::Character(vec![Printable::M]))
+        )
+    }
+
+    #[test]
+    fn not_limit_match() {
+        let builder = LiteralBuilder(b"M  ");
+
+        assert_eq!(
+            builder.push(Printable::M),
+            Ok(Target::Builder(LiteralBuilder(b"  ")))
+        )
+    }
+
+    #[test]
+    fn limit_match() {
+        let builder = LiteralBuilder(b" ");
+
+        assert_eq!(builder.push(Printable::Space), Ok(Target::Product(Literal)))
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn incomplete() {
+        assert_eq!(LiteralBuilder(b"M").done(), None)
+    }
+}
@@ -2,12 +2,18 @@ mod fixed_count;
 mod fixed_integer;
 mod fixed_real;
 mod line;
+mod literal;
 mod natural;
+mod padding;
 mod sequence;
+mod text;
 
 pub use fixed_count::FixedCount;
 pub use fixed_integer::FixedInteger;
 pub use fixed_real::FixedReal;
 pub use line::Line;
+pub use literal::Literal;
 pub use natural::Natural;
+pub use padding::Padding;
 pub use sequence::Sequence;
+pub use text::Text;
@@ -1,11 +1,18 @@
-use crate::text::{Digit, NonZeroDigit};
+use crate::{
+    build::{Builder, Error, Target},
+    text::{Digit, NonZeroDigit, Printable},
+    write::{self, Emit, Writer},
+};
 
-#[derive(Debug, PartialEq)]
+use super::FixedCount;
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Natural {
     head: NonZeroDigit,
     tail: Vec<Digit>,
 }
 
+#[allow(clippy::len_without_is_empty)]
 impl Natural {
     pub fn new(head: NonZeroDigit) -> Self {
         Self {
@@ -14,6 +21,31 @@ impl Natural {
         }
     }
 
+    pub fn start() -> impl Builder<Product = Natural> {
+        NaturalBuilder(None)
+    }
+
+    pub fn start_fixed<const I: usize>() -> impl Builder<Product = Natural> {
+        FixedNaturalBuilder(FixedCount::<I>::start())
+    }
+
+    // an index into count items
+    pub fn start_reference(count: u32) -> impl Builder<Product = Natural> {
+        ReferenceBuilder {
+            builder: NaturalBuilder(None),
+            count,
+        }
+    }
+
+    pub fn start_fixed_reference<const I: usize>(
+        count: u32,
+    ) -> impl Builder<Product = Natural> {
+        ReferenceBuilder {
+            builder: FixedNaturalBuilder(FixedCount::<I>::start()),
+            count,
+        }
+    }
+
     pub fn from_int(int: u32) -> Option<Self> {
         Self::from_digits(Digit::split(int))
     }
@@ -39,6 +71,142 @@ impl Natural {
     pub fn len(&self) -> usize {
         self.tail.len() + 1
     }
+
+    pub fn to_int(&self) -> u32 {
+        self.tail
+            .iter()
+            .fold(self.head.to_int(), |int, digit| int * 10 + digit.to_int())
+    }
+
+    pub fn printables(&self) -> Vec<Printable> {
+        let mut result = vec![self.head.to_printable()];
+
+        result.extend(self.tail.iter().map(Digit::to_printable));
+
+        result
+    }
+}
+
+impl Emit for Natural {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push_all(&self.printables())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct NaturalBuilder(Option<Natural>);
+
+impl Builder for NaturalBuilder {
+    type Product = Natural;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self.0 {
+            Some(mut natural) => match Digit::from_printable(printable) {
+                Some(digit) => {
+                    natural.push(digit);
+
+                    Ok(Target::Builder(Self(Some(natural))))
+                }
+                None => Err(Error::digit()),
+            },
+            None => match NonZeroDigit::from_printable(printable) {
+                Some(non_zero) => {
+                    Ok(Target::Builder(Self(Some(Natural::new(non_zero)))))
+                }
+                None => Err(Error::non_zero_digit()),
+            },
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        self.0
+    }
+}
+
+struct FixedNaturalBuilder<B>(B);
+
+impl<const I: usize, B: Builder<Product = FixedCount<I>>> Builder
+    for FixedNaturalBuilder<B>
+{
+    type Product = Natural;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self.0.push(printable)? {
+            Target::Builder(builder) => Ok(Target::Builder(Self(builder))),
+            Target::Product(FixedCount::Natural(natural)) => {
+                Ok(Target::Product(natural))
+            }
+            Target::Product(FixedCount::Zero) => Err(Error::non_zero_digit()),
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        match self.0.done()? {
+            FixedCount::Natural(natural) => Some(natural),
+            FixedCount::Zero => None,
+        }
+    }
+}
+
+// the value pushed so far, where the builder can tell
+trait Partial {
+    fn partial(&self) -> Option<u32>;
+}
+
+impl Partial for NaturalBuilder {
+    fn partial(&self) -> Option<u32> {
+        self.0.as_ref().map(Natural::to_int)
+    }
+}
+
+impl<B> Partial for FixedNaturalBuilder<B> {
+    fn partial(&self) -> Option<u32> {
+        None
+    }
+}
+
+// rejects an index past count once its digits exceed it, since more digits
+// only make it larger
+struct ReferenceBuilder<B> {
+    builder: B,
+    count: u32,
+}
+
+impl<B: Builder<Product = Natural> + Partial> Builder for ReferenceBuilder<B> {
+    type Product = Natural;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self.builder.push(printable)? {
+            Target::Builder(builder) => match builder.partial() {
+                Some(index) if index > self.count => {
+                    Err(Error::Reference(index, self.count))
+                }
+                _ => Ok(Target::Builder(Self {
+                    builder,
+                    count: self.count,
+                })),
+            },
+            Target::Product(natural) if natural.to_int() > self.count => {
+                Err(Error::Reference(natural.to_int(), self.count))
+            }
+            Target::Product(natural) => Ok(Target::Product(natural)),
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        self.builder
+            .done()
+            .filter(|natural| natural.to_int() <= self.count)
+    }
 }
 
 #[cfg(test)]
@@ -100,3 +268,193 @@ mod from_int {
         )
     }
 }
+
+#[cfg(test)]
+mod to_int {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn one_digit() {
+        assert_eq!(Natural::new(NonZeroDigit::D4).to_int(), 4)
+    }
+
+    #[test]
+    fn three_digits() {
+        assert_eq!(Natural::from_int(402).unwrap().to_int(), 402)
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty_zero() {
+        let builder = NaturalBuilder(None);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::non_zero_digit()))
+    }
+
+    #[test]
+    fn empty_non_zero() {
+        let builder = NaturalBuilder(None);
+
+        assert_eq!(
+            builder.push(Printable::D4),
+            Ok(Target::Builder(NaturalBuilder(Natural::from_int(4))))
+        )
+    }
+
+    #[test]
+    fn natural_space() {
+        let builder = NaturalBuilder(Natural::from_int(4));
+
+        assert_eq!(builder.push(Printable::Space), Err(Error::digit()))
+    }
+
+    #[test]
+    fn natural_zero() {
+        let builder = NaturalBuilder(Natural::from_int(4));
+
+        assert_eq!(
+            builder.push(Printable::D0),
+            Ok(Target::Builder(NaturalBuilder(Natural::from_int(40))))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(NaturalBuilder(None).done(), None)
+    }
+
+    #[test]
+    fn natural() {
+        assert_eq!(
+            NaturalBuilder(Natural::from_int(4)).done(),
+            Natural::from_int(4)
+        )
+    }
+}
+
+#[cfg(test)]
+mod start_fixed {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn build(str: &str) -> Result<Option<Natural>, Error> {
+        let mut builder = Natural::start_fixed::<3>();
+
+        for byte in str.bytes() {
+            builder = match builder.push(Printable::from_byte(byte).unwrap())? {
+                Target::Builder(builder) => builder,
+                Target::Product(product) => return Ok(Some(product)),
+            }
+        }
+
+        Ok(builder.done())
+    }
+
+    #[test]
+    fn zero() {
+        assert_eq!(build("  0"), Err(Error::non_zero_digit()))
+    }
+
+    #[test]
+    fn padded() {
+        assert_eq!(build("  7"), Ok(Natural::from_int(7)))
+    }
+
+    #[test]
+    fn unpadded() {
+        assert_eq!(build("123"), Ok(Natural::from_int(123)))
+    }
+}
+
+#[cfg(test)]
+mod start_reference {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn build<B: Builder<Product = Natural>>(
+        mut builder: B,
+        str: &str,
+    ) -> Result<Option<Natural>, Error> {
+        for byte in str.bytes() {
+            builder = match builder.push(Printable::from_byte(byte).unwrap())? {
+                Target::Builder(builder) => builder,
+                Target::Product(product) => return Ok(Some(product)),
+            }
+        }
+
+        Ok(builder.done())
+    }
+
+    #[test]
+    fn within_count() {
+        assert_eq!(
+            build(Natural::start_reference(40), "40"),
+            Ok(Natural::from_int(40))
+        )
+    }
+
+    #[test]
+    fn past_count() {
+        assert_eq!(
+            build(Natural::start_reference(40), "57"),
+            Err(Error::Reference(57, 40))
+        )
+    }
+
+    #[test]
+    fn past_count_before_last_digit() {
+        assert_eq!(
+            build(Natural::start_reference(4), "5000000000000"),
+            Err(Error::Reference(5, 4))
+        )
+    }
+
+    #[test]
+    fn fixed_within_count() {
+        assert_eq!(
+            build(Natural::start_fixed_reference::<3>(3), "  3"),
+            Ok(Natural::from_int(3))
+        )
+    }
+
+    #[test]
+    fn fixed_past_count() {
+        assert_eq!(
+            build(Natural::start_fixed_reference::<3>(3), " 12"),
+            Err(Error::Reference(12, 3))
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn single_digit() {
+        let natural = Natural::from_int(7).unwrap();
+
+        assert_eq!(emit(&natural), Ok("7".to_string()))
+    }
+
+    #[test]
+    fn multiple_digits() {
+        let natural = Natural::from_int(1020).unwrap();
+
+        assert_eq!(emit(&natural), Ok("1020".to_string()))
+    }
+}
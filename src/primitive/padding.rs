@@ -0,0 +1,82 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+};
+
+#[derive(Debug, PartialEq)]
+pub struct Padding<const P: usize>;
+
+impl<const P: usize> Padding<P> {
+    pub fn start() -> impl Builder<Product = Padding<P>> {
+        PaddingBuilder::<P>(0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct PaddingBuilder<const P: usize>(usize);
+
+impl<const P: usize> Builder for PaddingBuilder<P> {
+    type Product = Padding<P>;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        if printable == Printable::Space {
+            if self.0 + 1 == P {
+                Ok(Target::Product(Padding))
+            } else {
+                Ok(Target::Builder(Self(self.0 + 1)))
+            }
+        } else {
+            Err(Error::space())
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn non_space() {
+        let builder = PaddingBuilder::<2>(0);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::space()))
+    }
+
+    #[test]
+    fn not_limit_space() {
+        let builder = PaddingBuilder::<2>(0);
+
+        assert_eq!(
+            builder.push(Printable::Space),
+            Ok(Target::Builder(PaddingBuilder(1)))
+        )
+    }
+
+    #[test]
+    fn limit_space() {
+        let builder = PaddingBuilder::<2>(1);
+
+        assert_eq!(builder.push(Printable::Space), Ok(Target::Product(Padding)))
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn not_done() {
+        let builder = PaddingBuilder::<2>(1);
+
+        assert_eq!(builder.done(), None)
+    }
+}
@@ -1,15 +1,41 @@
 use crate::{
     build::{Builder, Target},
     text::Printable,
+    write::{self, Emit, Writer},
 };
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Sequence<const A: usize>(Vec<Printable>);
 
 impl<const A: usize> Sequence<A> {
     pub fn start() -> impl Builder<Product = Sequence<A>> {
         SequenceBuilder::<A>(Vec::new())
     }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(str: &str) -> Option<Self> {
+        let mut builder = Self::start();
+
+        for byte in str.bytes() {
+            builder = match builder.push(Printable::from_byte(byte)?) {
+                Ok(Target::Builder(builder)) => builder,
+                Ok(Target::Product(product)) => return Some(product),
+                Err(_) => return None,
+            }
+        }
+
+        builder.done()
+    }
+
+    pub fn printables(&self) -> &[Printable] {
+        &self.0
+    }
+}
+
+impl<const A: usize> Emit for Sequence<A> {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push_all(&self.0)
+    }
 }
 
 struct SequenceBuilder<const A: usize>(Vec<Printable>);
@@ -38,3 +64,17 @@ impl<const A: usize> Builder for SequenceBuilder<A> {
         }
     }
 }
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn printables() {
+        let item = Sequence::<3>::from_str("N  ").unwrap();
+
+        assert_eq!(emit(&item), Ok("N  ".to_string()))
+    }
+}
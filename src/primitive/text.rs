@@ -0,0 +1,101 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::Printable,
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Text(Vec<Printable>);
+
+impl Text {
+    pub fn start() -> impl Builder<Product = Text> {
+        TextBuilder(Vec::new())
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(str: &str) -> Option<Self> {
+        Some(Text(
+            str.bytes()
+                .map(Printable::from_byte)
+                .collect::<Option<Vec<_>>>()?,
+        ))
+    }
+
+    pub fn from_printables(printables: Vec<Printable>) -> Self {
+        Text(printables)
+    }
+
+    pub fn printables(&self) -> &[Printable] {
+        &self.0
+    }
+}
+
+impl Emit for Text {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push_all(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct TextBuilder(Vec<Printable>);
+
+impl Builder for TextBuilder {
+    type Product = Text;
+
+    fn push(
+        mut self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        self.0.push(printable);
+
+        Ok(Target::Builder(self))
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        Some(Text(self.0))
+    }
+}
+
+#[cfg(test)]
+mod from_str {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unprintable() {
+        assert_eq!(Text::from_str("a\tb"), None)
+    }
+
+    #[test]
+    fn printable() {
+        assert_eq!(
+            Text::from_str("A B"),
+            Some(Text(vec![Printable::A, Printable::Space, Printable::B]))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(TextBuilder(vec![]).done(), Some(Text(vec![])))
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn printables() {
+        let item = Text::from_str("a b").unwrap();
+
+        assert_eq!(emit(&item), Ok("a b".to_string()))
+    }
+}
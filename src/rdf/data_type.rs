@@ -0,0 +1,235 @@
+use crate::{
+    build::{Builder, Error, Target},
+    primitive::{Natural, Text},
+    text::{Digit, NonZeroDigit, Printable},
+};
+
+// RXN:VARIATION(1):STEPNO
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataType(Vec<Field>);
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Field {
+    pub name: Text,
+    pub index: Option<Natural>,
+}
+
+impl DataType {
+    pub fn start() -> impl Builder<Product = DataType> {
+        DataTypeBuilder::Name(vec![], vec![])
+    }
+
+    pub fn new(fields: Vec<Field>) -> Option<Self> {
+        if fields.is_empty() {
+            None
+        } else {
+            Some(DataType(fields))
+        }
+    }
+
+    pub fn fields(&self) -> &[Field] {
+        &self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum DataTypeBuilder {
+    Name(Vec<Field>, Vec<Printable>),
+    Index(Vec<Field>, Text, Option<Natural>),
+    Indexed(Vec<Field>),
+}
+
+impl Builder for DataTypeBuilder {
+    type Product = DataType;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self {
+            Self::Name(mut fields, mut name) => match printable {
+                Printable::Colon | Printable::OpenParen if name.is_empty() => {
+                    Err(Error::Character(vec![]))
+                }
+                Printable::Colon => {
+                    fields.push(Field {
+                        name: Text::from_printables(name),
+                        index: None,
+                    });
+
+                    Ok(Target::Builder(Self::Name(fields, vec![])))
+                }
+                Printable::OpenParen => Ok(Target::Builder(Self::Index(
+                    fields,
+                    Text::from_printables(name),
+                    None,
+                ))),
+                Printable::CloseParen => Err(Error::Character(vec![
+                    Printable::Colon,
+                    Printable::OpenParen,
+                ])),
+                _ => {
+                    name.push(printable);
+
+                    Ok(Target::Builder(Self::Name(fields, name)))
+                }
+            },
+            Self::Index(fields, name, None) => {
+                match NonZeroDigit::from_printable(printable) {
+                    Some(digit) => Ok(Target::Builder(Self::Index(
+                        fields,
+                        name,
+                        Some(Natural::new(digit)),
+                    ))),
+                    None => Err(Error::non_zero_digit()),
+                }
+            }
+            Self::Index(mut fields, name, Some(mut index)) => {
+                if printable == Printable::CloseParen {
+                    fields.push(Field {
+                        name,
+                        index: Some(index),
+                    });
+
+                    Ok(Target::Builder(Self::Indexed(fields)))
+                } else {
+                    match Digit::from_printable(printable) {
+                        Some(digit) => {
+                            index.push(digit);
+
+                            Ok(Target::Builder(Self::Index(
+                                fields,
+                                name,
+                                Some(index),
+                            )))
+                        }
+                        None => {
+                            let mut allowed = Printable::digits();
+
+                            allowed.push(Printable::CloseParen);
+
+                            Err(Error::Character(allowed))
+                        }
+                    }
+                }
+            }
+            Self::Indexed(fields) => {
+                if printable == Printable::Colon {
+                    Ok(Target::Builder(Self::Name(fields, vec![])))
+                } else {
+                    Err(Error::Character(vec![Printable::Colon]))
+                }
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        match self {
+            Self::Name(mut fields, name) => {
+                if name.is_empty() {
+                    return None;
+                }
+
+                fields.push(Field {
+                    name: Text::from_printables(name),
+                    index: None,
+                });
+
+                Some(DataType(fields))
+            }
+            Self::Index(..) => None,
+            Self::Indexed(fields) => Some(DataType(fields)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty_name() {
+        let builder = DataTypeBuilder::Name(vec![], vec![]);
+
+        assert_eq!(
+            builder.push(Printable::Colon),
+            Err(Error::Character(vec![]))
+        )
+    }
+
+    #[test]
+    fn name_colon() {
+        let builder = DataTypeBuilder::Name(vec![], vec![Printable::R]);
+
+        assert_eq!(
+            builder.push(Printable::Colon),
+            Ok(Target::Builder(DataTypeBuilder::Name(
+                vec![Field {
+                    name: Text::from_str("R").unwrap(),
+                    index: None
+                }],
+                vec![]
+            )))
+        )
+    }
+
+    #[test]
+    fn index_zero() {
+        let builder =
+            DataTypeBuilder::Index(vec![], Text::from_str("R").unwrap(), None);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::non_zero_digit()))
+    }
+
+    #[test]
+    fn indexed_letter() {
+        let builder = DataTypeBuilder::Indexed(vec![]);
+
+        assert_eq!(
+            builder.push(Printable::A),
+            Err(Error::Character(vec![Printable::Colon]))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn trailing_colon() {
+        let builder = DataTypeBuilder::Name(
+            vec![Field {
+                name: Text::from_str("RXN").unwrap(),
+                index: None,
+            }],
+            vec![],
+        );
+
+        assert_eq!(builder.done(), None)
+    }
+
+    #[test]
+    fn open_index() {
+        let builder = DataTypeBuilder::Index(
+            vec![],
+            Text::from_str("VARIATION").unwrap(),
+            Natural::from_int(1),
+        );
+
+        assert_eq!(builder.done(), None)
+    }
+
+    #[test]
+    fn indexed() {
+        let field = Field {
+            name: Text::from_str("VARIATION").unwrap(),
+            index: Natural::from_int(1),
+        };
+        let builder = DataTypeBuilder::Indexed(vec![field.clone()]);
+
+        assert_eq!(builder.done(), Some(DataType(vec![field])))
+    }
+}
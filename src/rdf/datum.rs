@@ -0,0 +1,7 @@
+use super::{DataType, DatumValue};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Datum {
+    pub data_type: DataType,
+    pub value: DatumValue,
+}
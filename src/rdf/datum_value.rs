@@ -0,0 +1,8 @@
+use crate::{molfile::Molfile, primitive::Text};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum DatumValue {
+    Text(Vec<Text>),
+    // $DATUM $MFMT
+    Molfile(Box<Molfile>),
+}
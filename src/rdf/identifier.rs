@@ -0,0 +1,9 @@
+use crate::primitive::{Natural, Text};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Identifier {
+    // $MIREG, $RIREG
+    Internal(Natural),
+    // $MEREG, $REREG
+    External(Text),
+}
@@ -0,0 +1,13 @@
+mod data_type;
+mod datum;
+mod datum_value;
+mod identifier;
+mod rd_record;
+mod structure;
+
+pub use data_type::{DataType, Field};
+pub use datum::Datum;
+pub use datum_value::DatumValue;
+pub use identifier::Identifier;
+pub use rd_record::RdRecord;
+pub use structure::Structure;
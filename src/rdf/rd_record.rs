@@ -0,0 +1,8 @@
+use super::{Datum, Identifier, Structure};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RdRecord {
+    pub structure: Structure,
+    pub identifier: Option<Identifier>,
+    pub data: Vec<Datum>,
+}
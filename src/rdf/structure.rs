@@ -0,0 +1,8 @@
+use crate::{molfile::Molfile, rxn::Reaction};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Structure {
+    // None when only a registry number is given
+    Molecule(Option<Box<Molfile>>),
+    Reaction(Option<Box<Reaction>>),
+}
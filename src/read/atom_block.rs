@@ -0,0 +1,414 @@
+use crate::{
+    build::Target,
+    molfile::{Atom, Counts},
+    primitive::{FixedCount, FixedInteger, FixedReal, Padding, Sequence},
+    text::Printable,
+};
+
+use super::{path::At, Error, Reader, WarningKind};
+
+pub fn atom_block(
+    reader: &mut Reader,
+    counts: &Counts,
+) -> Result<Vec<Atom>, Error> {
+    let mut result = Vec::new();
+
+    for index in 0..counts.atoms.to_int() as usize {
+        result.push(atom(reader).at(index).at("atoms").at("ctab")?);
+    }
+
+    Ok(result)
+}
+
+fn atom(reader: &mut Reader) -> Result<Atom, Error> {
+    let x = reader.read(Target::Builder(FixedReal::start())).at("x")?;
+    let y = reader.read(Target::Builder(FixedReal::start())).at("y")?;
+    let z = reader.read(Target::Builder(FixedReal::start())).at("z")?;
+
+    reader.read(Target::Builder(Padding::<1>::start()))?;
+
+    let symbol = symbol(reader).at("symbol")?;
+    let mut atom = Atom {
+        x,
+        y,
+        z,
+        symbol,
+        mass_difference: FixedInteger::Zero,
+        charge: FixedCount::Zero,
+        stereo_parity: FixedCount::Zero,
+        hydrogen_count: FixedCount::Zero,
+        stereo_care: FixedCount::Zero,
+        valence: FixedCount::Zero,
+        h0_designator: FixedCount::Zero,
+        mapping: FixedCount::Zero,
+        inversion: FixedCount::Zero,
+        exact_change: FixedCount::Zero,
+    };
+
+    fields(reader, &mut atom)?;
+
+    Ok(atom)
+}
+
+// everything after the symbol, which a short line may leave out
+fn fields(reader: &mut Reader, atom: &mut Atom) -> Result<(), Error> {
+    if short(reader)? {
+        return Ok(());
+    }
+
+    atom.mass_difference = reader
+        .read(Target::Builder(FixedInteger::start()))
+        .at("mass_difference")?;
+
+    for (field, value) in [
+        ("charge", &mut atom.charge),
+        ("stereo_parity", &mut atom.stereo_parity),
+        ("hydrogen_count", &mut atom.hydrogen_count),
+        ("stereo_care", &mut atom.stereo_care),
+        ("valence", &mut atom.valence),
+        ("h0_designator", &mut atom.h0_designator),
+    ] {
+        if short(reader)? {
+            return Ok(());
+        }
+
+        *value = reader
+            .read(Target::Builder(FixedCount::start()))
+            .at(field)?;
+    }
+
+    if short(reader)? {
+        return Ok(());
+    }
+
+    // rrr, iii
+    reader.read(Target::Builder(Sequence::<6>::start()))?;
+
+    for (field, value) in [
+        ("mapping", &mut atom.mapping),
+        ("inversion", &mut atom.inversion),
+        ("exact_change", &mut atom.exact_change),
+    ] {
+        if short(reader)? {
+            return Ok(());
+        }
+
+        *value = reader
+            .read(Target::Builder(FixedCount::start()))
+            .at(field)?;
+    }
+
+    reader.next_line().at("exact_change")
+}
+
+fn symbol(reader: &mut Reader) -> Result<Sequence<3>, Error> {
+    let row = reader.row;
+    let column = reader.column;
+    let symbol = reader.read(Target::Builder(Sequence::<3>::start()))?;
+
+    match symbol.printables().first().map(Printable::to_byte) {
+        Some(first) if reader.is_lenient() && first.is_ascii_lowercase() => {
+            reader.warn(row, column, WarningKind::LowercaseSymbol);
+
+            let mut bytes = symbol
+                .printables()
+                .iter()
+                .map(Printable::to_byte)
+                .collect::<Vec<_>>();
+
+            bytes[0] = first.to_ascii_uppercase();
+
+            Ok(Sequence::from_str(&String::from_utf8_lossy(&bytes))
+                .expect("symbol"))
+        }
+        _ => Ok(symbol),
+    }
+}
+
+// lenient readers take the fields missing from a short line as zero
+fn short(reader: &mut Reader) -> Result<bool, Error> {
+    if !reader.is_lenient() || !(reader.has_blank() || reader.is_eof()) {
+        return Ok(false);
+    }
+
+    reader.warn(reader.row, reader.column, WarningKind::ShortAtomLine);
+
+    if !reader.is_eof() {
+        reader.next_line()?;
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::ErrorKind;
+    use crate::read::{path::field, Strictness, Warning};
+    use crate::{
+        molfile::{ChiralFlag, Version},
+        text::{Digit, Printable},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn counts(atoms: u32) -> Counts {
+        Counts {
+            atoms: FixedCount::from_int(atoms).unwrap(),
+            bonds: FixedCount::Zero,
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::NotChiral,
+            stext: None,
+            version: Version::V2,
+        }
+    }
+
+    #[test]
+    fn zero_atoms() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(atom_block(&mut reader, &counts(0)), Ok(vec![]))
+    }
+
+    #[test]
+    fn eof() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Err(field("ctab.atoms[0].x", ErrorKind::Eof(0)))
+        )
+    }
+
+    #[test]
+    fn eof_after_first_atom() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(2)),
+            Err(field("ctab.atoms[1].x", ErrorKind::Eof(1)))
+        )
+    }
+
+    #[test]
+    fn short_line() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Err(field("ctab.atoms[0].inversion", ErrorKind::Eol(0)))
+        )
+    }
+
+    #[test]
+    fn long_line() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.atoms[0].exact_change",
+                ErrorKind::Overflow(0, 69)
+            ))
+        )
+    }
+
+    #[test]
+    fn bad_separator() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000xC   0  0  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.atoms[0]",
+                ErrorKind::Character(0, 30, vec![Printable::D0])
+            ))
+        )
+    }
+
+    #[test]
+    fn bad_charge() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000 C   0  x  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.atoms[0].charge",
+                ErrorKind::Character(0, 38, Printable::digits())
+            ))
+        )
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn valid() {
+        let mut bytes = [
+           //xxxxx.xxxxyyyyy.yyyyzzzzz.zzzz aaaddcccssshhhbbbvvvHHHrrriiimmmnnneee
+            "   -0.5000    1.2500    0.0000 N  -1  3  0  1  0  0  0  0  0  7  0  0",
+            ""
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)),
+            Ok(vec![Atom {
+                x: FixedReal::NegativeZero(vec![
+                    Digit::d5(),
+                    Digit::d0(),
+                    Digit::d0(),
+                    Digit::d0()
+                ]),
+                y: FixedReal::Integer(
+                    FixedInteger::from_int(1).unwrap(),
+                    vec![Digit::d2(), Digit::d5(), Digit::d0(), Digit::d0()]
+                ),
+                z: FixedReal::Integer(
+                    FixedInteger::Zero,
+                    vec![Digit::d0(), Digit::d0(), Digit::d0(), Digit::d0()]
+                ),
+                symbol: Sequence::from_str("N  ").unwrap(),
+                mass_difference: FixedInteger::from_int(-1).unwrap(),
+                charge: FixedCount::from_int(3).unwrap(),
+                stereo_parity: FixedCount::Zero,
+                hydrogen_count: FixedCount::from_int(1).unwrap(),
+                stereo_care: FixedCount::Zero,
+                valence: FixedCount::Zero,
+                h0_designator: FixedCount::Zero,
+                mapping: FixedCount::from_int(7).unwrap(),
+                inversion: FixedCount::Zero,
+                exact_change: FixedCount::Zero,
+            }])
+        )
+    }
+    fn lenient(line: &str) -> (Result<Vec<Atom>, Error>, Vec<Warning>) {
+        let mut bytes = [line, ""].join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.options.strictness = Strictness::Lenient;
+
+        let result = atom_block(&mut reader, &counts(1));
+
+        (result, reader.warnings)
+    }
+
+    #[test]
+    fn lenient_short_line() {
+        let (result, warnings) = lenient(
+            "    0.0000    0.0000    0.0000 C   0  3  0  0  0  0  0  0  0  4",
+        );
+        let atom = result.unwrap().remove(0);
+
+        assert_eq!(
+            (atom.charge, atom.mapping, atom.inversion),
+            (
+                FixedCount::from_int(3).unwrap(),
+                FixedCount::from_int(4).unwrap(),
+                FixedCount::Zero
+            )
+        );
+        assert_eq!(
+            warnings,
+            vec![Warning {
+                row: 0,
+                column: 63,
+                kind: WarningKind::ShortAtomLine
+            }]
+        )
+    }
+
+    #[test]
+    fn lenient_symbol_only() {
+        let (result, warnings) = lenient("    0.0000    0.0000    0.0000 C  ");
+        let atom = result.unwrap().remove(0);
+
+        assert_eq!(atom.mass_difference, FixedInteger::Zero);
+        assert_eq!(warnings.len(), 1)
+    }
+
+    #[test]
+    fn lenient_partial_field() {
+        let (result, _) = lenient("    0.0000    0.0000    0.0000 C   0  ");
+
+        assert_eq!(
+            result,
+            Err(field("ctab.atoms[0].charge", ErrorKind::End(0, 38)))
+        )
+    }
+
+    #[test]
+    fn lenient_lowercase_symbol() {
+        let (result, warnings) = lenient(
+            "    0.0000    0.0000    0.0000 cl  0  0  0  0  0  0  0  0  0  0  0  0",
+        );
+
+        assert_eq!(
+            result.unwrap()[0].symbol,
+            Sequence::from_str("Cl ").unwrap()
+        );
+        assert_eq!(
+            warnings,
+            vec![Warning {
+                row: 0,
+                column: 31,
+                kind: WarningKind::LowercaseSymbol
+            }]
+        )
+    }
+
+    #[test]
+    fn strict_lowercase_symbol() {
+        let mut bytes = [
+            "    0.0000    0.0000    0.0000 cl  0  0  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_block(&mut reader, &counts(1)).unwrap()[0].symbol,
+            Sequence::from_str("cl ").unwrap()
+        );
+        assert_eq!(reader.warnings, vec![])
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    build::Target,
+    molfile::{AtomList, Counts, ListType},
+    primitive::{FixedCount, Natural, Padding},
+};
+
+use super::{path::At, Error, Reader};
+
+pub fn atom_list_block(
+    reader: &mut Reader,
+    counts: &Counts,
+) -> Result<Vec<AtomList>, Error> {
+    let mut result = Vec::new();
+
+    for index in 0..counts.atom_lists.to_int() as usize {
+        result.push(atom_list(reader).at(index).at("atom_lists").at("ctab")?);
+    }
+
+    Ok(result)
+}
+
+fn atom_list(reader: &mut Reader) -> Result<AtomList, Error> {
+    let atom = reader
+        .read(Target::Builder(Natural::start_fixed::<3>()))
+        .at("atom")?;
+
+    reader.read(Target::Builder(Padding::<1>::start()))?;
+
+    let list_type = reader
+        .read(Target::Builder(ListType::start()))
+        .at("list_type")?;
+
+    reader.read(Target::Builder(Padding::<4>::start()))?;
+
+    let count = reader.read(Target::Builder(FixedCount::<1>::start()))?;
+    let mut elements = Vec::new();
+
+    for index in 0..count.to_int() as usize {
+        reader
+            .read(Target::Builder(Padding::<1>::start()))
+            .at(index)
+            .at("elements")?;
+        elements.push(
+            reader
+                .read(Target::Builder(Natural::start_fixed::<3>()))
+                .at(index)
+                .at("elements")?,
+        );
+    }
+
+    reader.next_line()?;
+
+    Ok(AtomList {
+        atom,
+        list_type,
+        elements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::molfile::{ChiralFlag, Version};
+    use crate::read::path::field;
+    use crate::read::ErrorKind;
+    use pretty_assertions::assert_eq;
+
+    fn counts(atom_lists: u32) -> Counts {
+        Counts {
+            atoms: FixedCount::Zero,
+            bonds: FixedCount::Zero,
+            atom_lists: FixedCount::from_int(atom_lists).unwrap(),
+            chiral: ChiralFlag::NotChiral,
+            stext: None,
+            version: Version::V2,
+        }
+    }
+
+    #[test]
+    fn eof() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_list_block(&mut reader, &counts(1)),
+            Err(field("ctab.atom_lists[0].atom", ErrorKind::Eof(0)))
+        )
+    }
+
+    #[test]
+    fn extra_element() {
+        let mut bytes = ["  3 F    1   7   8", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_list_block(&mut reader, &counts(1)),
+            Err(field("ctab.atom_lists[0]", ErrorKind::Overflow(0, 14)))
+        )
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn valid() {
+        let mut bytes = [
+           //aaa kSSSSn 111 222
+            "  3 T    2   7   8",
+            ""
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            atom_list_block(&mut reader, &counts(1)),
+            Ok(vec![AtomList {
+                atom: Natural::from_int(3).unwrap(),
+                list_type: ListType::NotList,
+                elements: vec![
+                    Natural::from_int(7).unwrap(),
+                    Natural::from_int(8).unwrap()
+                ]
+            }])
+        )
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{
+    build::Target,
+    molfile::{
+        Bond, BondStereo, BondTopology, BondType, Counts, ReactingCenter,
+    },
+    primitive::{Natural, Sequence},
+};
+
+use super::{path::At, Error, Reader};
+
+pub fn bond_block(
+    reader: &mut Reader,
+    counts: &Counts,
+) -> Result<Vec<Bond>, Error> {
+    let mut result = Vec::new();
+
+    for index in 0..counts.bonds.to_int() as usize {
+        result.push(
+            bond(reader, counts.atoms.to_int())
+                .at(index)
+                .at("bonds")
+                .at("ctab")?,
+        );
+    }
+
+    Ok(result)
+}
+
+fn bond(reader: &mut Reader, atom_count: u32) -> Result<Bond, Error> {
+    let first = reader
+        .read(Target::Builder(Natural::start_fixed_reference::<3>(
+            atom_count,
+        )))
+        .at("first")?;
+    let second = reader
+        .read(Target::Builder(Natural::start_fixed_reference::<3>(
+            atom_count,
+        )))
+        .at("second")?;
+    let bond_type = reader
+        .read(Target::Builder(BondType::start()))
+        .at("bond_type")?;
+    let stereo = reader
+        .read(Target::Builder(BondStereo::start()))
+        .at("stereo")?;
+
+    // xxx
+    reader.read(Target::Builder(Sequence::<3>::start()))?;
+
+    let topology = reader
+        .read(Target::Builder(BondTopology::start()))
+        .at("topology")?;
+    let reacting_center = reader
+        .read_line(Target::Builder(ReactingCenter::start()))
+        .at("reacting_center")?;
+
+    Ok(Bond {
+        first,
+        second,
+        bond_type,
+        stereo,
+        topology,
+        reacting_center,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::path::field;
+    use crate::read::ErrorKind;
+    use crate::{
+        molfile::{ChiralFlag, Version},
+        primitive::FixedCount,
+        text::Printable,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn counts(bonds: u32) -> Counts {
+        Counts {
+            atoms: FixedCount::from_int(3).unwrap(),
+            bonds: FixedCount::from_int(bonds).unwrap(),
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::NotChiral,
+            stext: None,
+            version: Version::V2,
+        }
+    }
+
+    #[test]
+    fn zero_bonds() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(bond_block(&mut reader, &counts(0)), Ok(vec![]))
+    }
+
+    #[test]
+    fn eof() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(1)),
+            Err(field("ctab.bonds[0].first", ErrorKind::Eof(0)))
+        )
+    }
+
+    #[test]
+    fn zero_atom_index() {
+        let mut bytes = ["  1  0  1  0  0  0  0", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.bonds[0].second",
+                ErrorKind::Character(0, 5, Printable::non_zero_digits())
+            ))
+        )
+    }
+
+    #[test]
+    fn atom_out_of_range() {
+        let mut bytes = ["  1  4  1  0  0  0  0", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.bonds[0].second",
+                ErrorKind::Reference(0, 5, 4, 3)
+            ))
+        )
+    }
+
+    #[test]
+    fn bad_bond_type() {
+        let mut bytes = ["  1  2  9  0  0  0  0", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(1)),
+            Err(field(
+                "ctab.bonds[0].bond_type",
+                ErrorKind::Character(
+                    0,
+                    8,
+                    vec![
+                        Printable::D1,
+                        Printable::D2,
+                        Printable::D3,
+                        Printable::D4,
+                        Printable::D5,
+                        Printable::D6,
+                        Printable::D7,
+                        Printable::D8,
+                    ]
+                )
+            ))
+        )
+    }
+
+    #[test]
+    fn short_line() {
+        let mut bytes =
+            ["  1  2  1  0", ""].join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(1)),
+            Err(field("ctab.bonds[0]", ErrorKind::Eol(0)))
+        )
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn valid() {
+        let mut bytes = [
+           //111222tttsssxxxrrrccc
+            "  1  2  2  3  0  1 -1",
+            "  2  3  4  6  0  2 13",
+            ""
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            bond_block(&mut reader, &counts(2)),
+            Ok(vec![
+                Bond {
+                    first: Natural::from_int(1).unwrap(),
+                    second: Natural::from_int(2).unwrap(),
+                    bond_type: BondType::Double,
+                    stereo: BondStereo::CisTransEither,
+                    topology: BondTopology::Ring,
+                    reacting_center: ReactingCenter::NotCenter,
+                },
+                Bond {
+                    first: Natural::from_int(2).unwrap(),
+                    second: Natural::from_int(3).unwrap(),
+                    bond_type: BondType::Aromatic,
+                    stereo: BondStereo::Down,
+                    topology: BondTopology::Chain,
+                    reacting_center:
+                        ReactingCenter::CenterMakeOrBreakAndOrderChange,
+                }
+            ])
+        )
+    }
+}
@@ -0,0 +1,373 @@
+use crate::text::{Character, Eol, Printable};
+
+use super::{
+    path::Segment::{self, Field, Index, Keyword},
+    Path,
+};
+
+// splits source into lines the way Reader::next_line does
+pub(super) fn lines(source: &[u8]) -> Vec<&[u8]> {
+    let mut result = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < source.len() {
+        let next = source
+            .get(index + 1)
+            .map(|byte| Character::from_byte(*byte));
+
+        match Character::from_byte(source[index]) {
+            Character::Eol(eol) => {
+                result.push(&source[start..index]);
+
+                index += match (eol, next) {
+                    (Eol::Cr, Some(next)) if next.is_lf() => 2,
+                    (Eol::Lf, Some(next)) if next.is_cr() => 2,
+                    _ => 1,
+                };
+                start = index;
+            }
+            _ => index += 1,
+        }
+    }
+
+    result.push(&source[start..]);
+
+    result
+}
+
+pub(super) fn expected(allowed: &[Printable]) -> String {
+    let mut parts = Vec::new();
+    let mut rest = allowed.to_vec();
+
+    for (digits, label) in [
+        (Printable::digits(), "0-9"),
+        (Printable::non_zero_digits(), "1-9"),
+    ] {
+        if digits.iter().all(|digit| rest.contains(digit)) {
+            rest.retain(|printable| !digits.contains(printable));
+            parts.push(label.to_string());
+        }
+    }
+
+    for printable in rest {
+        parts.push(match printable {
+            Printable::Space => "space".to_string(),
+            printable => format!("'{}'", printable.to_byte() as char),
+        })
+    }
+
+    parts.join(", ")
+}
+
+// names the block, item and field a path leads to
+pub(super) fn describe(path: &Path) -> Option<String> {
+    let segments = path.segments();
+    // record, component and structure segments lead to a header or CTAB
+    let start = segments.iter().position(|segment| {
+        matches!(segment, Segment::Field("header") | Segment::Field("ctab"))
+    })?;
+
+    if start > 0 && segments[start - 1] == Field("v3000") {
+        return describe_v3000(&segments[start..]);
+    }
+
+    match &segments[start..] {
+        [Field("header"), Field("molecule_name")] => {
+            Some("header, molecule name".to_string())
+        }
+        [Field("header"), Field("parameters"), rest @ ..] => {
+            item("header, parameters line".to_string(), PARAMETERS, rest)
+        }
+        [Field("header"), Field("comment")] => {
+            Some("header, comment".to_string())
+        }
+        [Field("header"), Field("counts"), rest @ ..] => {
+            item("counts line".to_string(), COUNTS, rest)
+        }
+        [Field("ctab"), Field("atoms"), Index(index), rest @ ..] => {
+            item(format!("atom block, atom {}", index + 1), ATOM, rest)
+        }
+        [Field("ctab"), Field("bonds"), Index(index), rest @ ..] => {
+            item(format!("bond block, bond {}", index + 1), BOND, rest)
+        }
+        [Field("ctab"), Field("atom_lists"), Index(index), rest @ ..] => item(
+            format!("atom list block, atom list {}", index + 1),
+            ATOM_LIST,
+            rest,
+        ),
+        [Field("ctab"), Field("stext"), Index(index), rest @ ..] => {
+            item(format!("stext block, entry {}", index + 1), STEXT, rest)
+        }
+        [Field("ctab"), Field("properties"), Index(index), ..] => {
+            Some(format!("properties block, property {}", index + 1))
+        }
+        [Field("ctab"), Field("sgroups"), ..] => {
+            Some("Sgroup block".to_string())
+        }
+        [Field("ctab"), Field("stereo_groups"), ..] => {
+            Some("collection block".to_string())
+        }
+        _ => None,
+    }
+}
+
+// V3000 lines have no columns, so fields are named by position
+fn describe_v3000(segments: &[Segment]) -> Option<String> {
+    match segments {
+        [Field("ctab"), Field("atoms"), Index(index), rest @ ..] => entry(
+            format!("V3000 atom block, atom {}", index + 1),
+            V3000_ATOM,
+            rest,
+        ),
+        [Field("ctab"), Field("bonds"), Index(index), rest @ ..] => entry(
+            format!("V3000 bond block, bond {}", index + 1),
+            V3000_BOND,
+            rest,
+        ),
+        [Field("ctab"), Field("sgroups"), ..] => {
+            Some("V3000 Sgroup block".to_string())
+        }
+        [Field("ctab"), Field("stereo_groups"), ..] => {
+            Some("V3000 collection block".to_string())
+        }
+        [Field("ctab")] => Some("V3000 CTAB".to_string()),
+        _ => None,
+    }
+}
+
+// appends the positional field or keyword a rest of path names
+fn entry(
+    line: String,
+    layout: &[(&str, &str, usize)],
+    rest: &[Segment],
+) -> Option<String> {
+    match rest.first() {
+        Some(Field(name)) => {
+            let (_, name, position) =
+                layout.iter().find(|(field, _, _)| field == name)?;
+
+            Some(format!("{}, {} (position {})", line, name, position))
+        }
+        Some(Keyword(keyword)) => {
+            Some(format!("{}, keyword `{}`", line, keyword))
+        }
+        Some(Index(_)) => None,
+        None => Some(line),
+    }
+}
+
+// appends the field or keyword a rest of path names
+fn item(
+    line: String,
+    layout: &[(&str, &str, &str)],
+    rest: &[Segment],
+) -> Option<String> {
+    match rest.first() {
+        Some(Field(name)) => {
+            let (_, name, code) =
+                layout.iter().find(|(field, _, _)| field == name)?;
+
+            Some(format!("{}, {} `{}`", line, name, code))
+        }
+        Some(Keyword(_) | Index(_)) => None,
+        None => Some(line),
+    }
+}
+
+// model field, field name, column code
+const PARAMETERS: &[(&str, &str, &str)] = &[
+    ("user_initials", "user initials", "II"),
+    ("program_name", "program name", "PPPPPPPP"),
+    ("timestamp", "timestamp", "MMDDYYHHmm"),
+    ("dimensional_codes", "dimensional codes", "dd"),
+    ("major_scaling", "major scaling factor", "SS"),
+    ("minor_scaling", "minor scaling factor", "ssssssssss"),
+    ("energy", "energy", "EEEEEEEEEEEE"),
+    ("registry_number", "registry number", "RRRRRR"),
+];
+
+const COUNTS: &[(&str, &str, &str)] = &[
+    ("atoms", "atom count", "aaa"),
+    ("bonds", "bond count", "bbb"),
+    ("atom_lists", "atom list count", "lll"),
+    ("chiral", "chiral flag", "ccc"),
+    ("stext", "stext count", "sss"),
+    ("version", "version", "vvvvvv"),
+];
+
+const ATOM: &[(&str, &str, &str)] = &[
+    ("x", "x coordinate", "xxxxx.xxxx"),
+    ("y", "y coordinate", "yyyyy.yyyy"),
+    ("z", "z coordinate", "zzzzz.zzzz"),
+    ("symbol", "atom symbol", "aaa"),
+    ("mass_difference", "mass difference", "dd"),
+    ("charge", "charge", "ccc"),
+    ("stereo_parity", "stereo parity", "sss"),
+    ("hydrogen_count", "hydrogen count", "hhh"),
+    ("stereo_care", "stereo care box", "bbb"),
+    ("valence", "valence", "vvv"),
+    ("h0_designator", "H0 designator", "HHH"),
+    ("mapping", "atom-atom mapping number", "mmm"),
+    ("inversion", "inversion/retention flag", "nnn"),
+    ("exact_change", "exact change flag", "eee"),
+];
+
+const BOND: &[(&str, &str, &str)] = &[
+    ("first", "first atom", "111"),
+    ("second", "second atom", "222"),
+    ("bond_type", "bond type", "ttt"),
+    ("stereo", "bond stereo", "sss"),
+    ("topology", "bond topology", "rrr"),
+    ("reacting_center", "reacting center status", "ccc"),
+];
+
+const ATOM_LIST: &[(&str, &str, &str)] = &[
+    ("atom", "atom", "aaa"),
+    ("list_type", "list type", "t"),
+    ("elements", "elements", "nnn"),
+];
+
+const STEXT: &[(&str, &str, &str)] = &[
+    ("x", "x coordinate", "xxxxx.xxxx"),
+    ("y", "y coordinate", "yyyyy.yyyy"),
+    ("text", "text", "TTT"),
+];
+
+// model field, field name, position after M  V30
+const V3000_ATOM: &[(&str, &str, usize)] = &[
+    ("symbol", "atom type", 2),
+    ("x", "x coordinate", 3),
+    ("y", "y coordinate", 4),
+    ("z", "z coordinate", 5),
+    ("mapping", "atom-atom mapping number", 6),
+];
+
+const V3000_BOND: &[(&str, &str, usize)] = &[
+    ("bond_type", "bond type", 2),
+    ("first", "first atom", 3),
+    ("second", "second atom", 4),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::{path::field, ErrorKind};
+    use pretty_assertions::assert_eq;
+
+    fn describe_path(path: &'static str) -> Option<String> {
+        describe(&field(path, ErrorKind::Eof(0)).path)
+    }
+
+    #[test]
+    fn lines_mixed_eol() {
+        assert_eq!(
+            lines(b"a\r\nb\n\rc\r\rd\x1ee"),
+            vec![&b"a"[..], b"b", b"c", b"d", b"e"]
+        )
+    }
+
+    #[test]
+    fn expected_digits_and_space() {
+        let mut allowed = vec![Printable::Space];
+
+        allowed.append(&mut Printable::digits());
+
+        assert_eq!(expected(&allowed), "0-9, space")
+    }
+
+    #[test]
+    fn expected_non_zero_digits() {
+        assert_eq!(expected(&Printable::non_zero_digits()), "1-9")
+    }
+
+    #[test]
+    fn expected_characters() {
+        assert_eq!(
+            expected(&[Printable::Minus, Printable::DoubleQuote]),
+            "'-', '\"'"
+        )
+    }
+
+    #[test]
+    fn describe_parameters() {
+        assert_eq!(
+            describe_path("header.parameters.minor_scaling"),
+            Some(
+                "header, parameters line, minor scaling factor `ssssssssss`"
+                    .to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn describe_counts() {
+        assert_eq!(
+            describe_path("header.counts.bonds"),
+            Some("counts line, bond count `bbb`".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_atom() {
+        assert_eq!(
+            describe_path("ctab.atoms[1].charge"),
+            Some("atom block, atom 2, charge `ccc`".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_bond() {
+        assert_eq!(
+            describe_path("ctab.bonds[0].bond_type"),
+            Some("bond block, bond 1, bond type `ttt`".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_keyword() {
+        assert_eq!(
+            describe_path("v3000.ctab.atoms[0].CHG"),
+            Some("V3000 atom block, atom 1, keyword `CHG`".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_v3000_position() {
+        assert_eq!(
+            describe_path("v3000.ctab.bonds[2].first"),
+            Some(
+                "V3000 bond block, bond 3, first atom (position 3)".to_string()
+            )
+        )
+    }
+
+    #[test]
+    fn describe_v3000_component() {
+        assert_eq!(
+            describe_path("reactants[0].v3000.ctab.sgroups"),
+            Some("V3000 Sgroup block".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_property() {
+        assert_eq!(
+            describe_path("ctab.properties[3]"),
+            Some("properties block, property 4".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_component() {
+        assert_eq!(
+            describe_path("products[1].header.counts.atoms"),
+            Some("counts line, atom count `aaa`".to_string())
+        )
+    }
+
+    #[test]
+    fn describe_without_block() {
+        assert_eq!(describe_path("data[0]"), None)
+    }
+}
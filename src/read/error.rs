@@ -1,10 +1,431 @@
-use crate::text::Printable;
+use std::{fmt, io, ops::Range};
 
-#[derive(Debug, PartialEq)]
-pub enum Error {
+use crate::{build, text::Printable};
+
+use super::{diagnostic, Path, Segment};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Error {
+    // field being read when the error occurred, empty outside any field
+    pub path: Path,
+    pub kind: ErrorKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ErrorKind {
     Character(usize, usize, Vec<Printable>),
+    End(usize, usize),
     Eof(usize),
     Eol(usize),
+    Index(usize, usize),
+    // kind and message of the I/O error
+    Io(io::ErrorKind, String),
+    Keyword(usize, usize),
+    Length(usize, usize, usize),
     Overflow(usize, usize),
+    Range(usize, usize, i32, i32),
+    // record index and the bytes it spans
+    Record(usize, Range<usize>, Box<Error>),
+    Reference(usize, usize, u32, u32),
     Unprintable(usize, usize, u8),
 }
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Self {
+            path: Path::default(),
+            kind,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        ErrorKind::Io(error.kind(), error.to_string()).into()
+    }
+}
+
+impl Error {
+    // message with the offending line of source and a caret under the column
+    pub fn render(&self, source: &[u8]) -> String {
+        let mut result = self.to_string();
+
+        if self.position().is_none() {
+            return result;
+        }
+
+        // a record error reads its field from the error it wraps
+        let path = match &self.kind {
+            ErrorKind::Record(_, _, error) => &error.path,
+            _ => &self.path,
+        };
+
+        if let Some(field) = diagnostic::describe(path) {
+            result.push_str(&format!("\nin {}", field));
+        }
+
+        result.push('\n');
+        result.push_str(&self.snippet(source));
+
+        result
+    }
+
+    // places a builder error at the row and column being read
+    pub(crate) fn from_build(
+        row: usize,
+        column: usize,
+        error: build::Error,
+    ) -> Self {
+        match error {
+            build::Error::Character(allowed) => {
+                ErrorKind::Character(row, column, allowed)
+            }
+            build::Error::End => ErrorKind::End(row, column),
+            build::Error::Length(length) => {
+                ErrorKind::Length(row, column, length)
+            }
+            build::Error::Range(min, max) => {
+                ErrorKind::Range(row, column, min, max)
+            }
+            build::Error::Reference(index, count) => {
+                ErrorKind::Reference(row, column, index, count)
+            }
+        }
+        .into()
+    }
+
+    pub(crate) fn at(mut self, segment: impl Into<Segment>) -> Self {
+        self.path.prepend(segment.into());
+
+        self
+    }
+
+    fn position(&self) -> Option<(usize, Option<usize>)> {
+        match &self.kind {
+            ErrorKind::Character(row, column, _)
+            | ErrorKind::End(row, column)
+            | ErrorKind::Index(row, column)
+            | ErrorKind::Keyword(row, column)
+            | ErrorKind::Length(row, column, _)
+            | ErrorKind::Overflow(row, column)
+            | ErrorKind::Range(row, column, _, _)
+            | ErrorKind::Reference(row, column, _, _)
+            | ErrorKind::Unprintable(row, column, _) => {
+                Some((*row, Some(*column)))
+            }
+            ErrorKind::Eof(row) | ErrorKind::Eol(row) => Some((*row, None)),
+            ErrorKind::Io(..) => None,
+            ErrorKind::Record(_, _, error) => error.position(),
+        }
+    }
+
+    fn snippet(&self, source: &[u8]) -> String {
+        let (row, column) = match self.position() {
+            Some(position) => position,
+            None => return String::new(),
+        };
+        let lines = diagnostic::lines(source);
+        let line = lines.get(row).copied().unwrap_or_default();
+        let column = column.unwrap_or(line.len());
+        let number = (row + 1).to_string();
+
+        format!(
+            "{} | {}\n{} | {}^",
+            number,
+            String::from_utf8_lossy(line),
+            " ".repeat(number.len()),
+            " ".repeat(column)
+        )
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.segments().is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}: {}", self.path, self.kind)
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Character(row, column, allowed) => {
+                write!(
+                    f,
+                    "unexpected character at {}:{}",
+                    row + 1,
+                    column + 1
+                )?;
+
+                if allowed.is_empty() {
+                    Ok(())
+                } else {
+                    write!(f, ", expected {}", diagnostic::expected(allowed))
+                }
+            }
+            Self::End(row, column) => {
+                write!(f, "incomplete field at {}:{}", row + 1, column + 1)
+            }
+            Self::Eof(row) => {
+                write!(f, "unexpected end of input at line {}", row + 1)
+            }
+            Self::Eol(row) => {
+                write!(f, "unexpected end of line at line {}", row + 1)
+            }
+            Self::Index(row, column) => {
+                write!(f, "unknown index at {}:{}", row + 1, column + 1)
+            }
+            Self::Io(_, message) => write!(f, "I/O error: {}", message),
+            Self::Keyword(row, column) => {
+                write!(f, "unknown keyword at {}:{}", row + 1, column + 1)
+            }
+            Self::Length(row, column, length) => write!(
+                f,
+                "expected {} items at {}:{}",
+                length,
+                row + 1,
+                column + 1
+            ),
+            Self::Overflow(row, column) => write!(
+                f,
+                "unexpected character past the end of the field at {}:{}",
+                row + 1,
+                column + 1
+            ),
+            Self::Range(row, column, min, max) => write!(
+                f,
+                "value outside {}..{} at {}:{}",
+                min,
+                max,
+                row + 1,
+                column + 1
+            ),
+            Self::Record(index, _, error) => {
+                write!(f, "record {}: {}", index + 1, error)
+            }
+            Self::Reference(row, column, index, count) => write!(
+                f,
+                "index {} exceeds count {} at {}:{}",
+                index,
+                count,
+                row + 1,
+                column + 1
+            ),
+            Self::Unprintable(row, column, byte) => write!(
+                f,
+                "unprintable byte 0x{:02x} at {}:{}",
+                byte,
+                row + 1,
+                column + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::{molfile, Reader};
+    use pretty_assertions::assert_eq;
+
+    const SOURCE: &[u8] = b"\n\n\n  1 x0  0  0  0  0            999 V2000\n";
+
+    #[test]
+    fn display_character() {
+        assert_eq!(
+            Error::from(ErrorKind::Character(
+                3,
+                4,
+                vec![Printable::Space, Printable::D1]
+            ))
+            .to_string(),
+            "unexpected character at 4:5, expected space, '1'"
+        )
+    }
+
+    #[test]
+    fn display_record() {
+        assert_eq!(
+            Error::from(ErrorKind::Record(
+                0,
+                0..10,
+                Box::new(Error::from(ErrorKind::Eof(4)))
+            ))
+            .to_string(),
+            "record 1: unexpected end of input at line 5"
+        )
+    }
+
+    #[test]
+    fn display_field() {
+        let error = Error::from(ErrorKind::Eof(4))
+            .at("x")
+            .at(0)
+            .at("atoms")
+            .at("ctab");
+
+        assert_eq!(
+            error.to_string(),
+            "ctab.atoms[0].x: unexpected end of input at line 5"
+        )
+    }
+
+    #[test]
+    fn display_record_field() {
+        let error = Error::from(ErrorKind::Eof(4)).at("name");
+
+        assert_eq!(
+            Error::from(ErrorKind::Record(0, 0..10, Box::new(error)))
+                .to_string(),
+            "record 1: name: unexpected end of input at line 5"
+        )
+    }
+
+    #[test]
+    fn display_range() {
+        assert_eq!(
+            Error::from(ErrorKind::Range(4, 10, -15, 15)).to_string(),
+            "value outside -15..15 at 5:11"
+        )
+    }
+
+    #[test]
+    fn display_reference() {
+        assert_eq!(
+            Error::from(ErrorKind::Reference(40, 3, 57, 40)).to_string(),
+            "index 57 exceeds count 40 at 41:4"
+        )
+    }
+
+    #[test]
+    fn from_build() {
+        assert_eq!(
+            Error::from_build(2, 5, build::Error::Reference(3, 2)),
+            Error::from(ErrorKind::Reference(2, 5, 3, 2))
+        )
+    }
+
+    #[test]
+    fn display_io() {
+        assert_eq!(
+            Error::from(io::Error::new(
+                io::ErrorKind::NotFound,
+                "No such file or directory"
+            ))
+            .to_string(),
+            "I/O error: No such file or directory"
+        )
+    }
+
+    #[test]
+    fn render_character() {
+        let mut allowed = vec![Printable::Space];
+
+        allowed.append(&mut Printable::digits());
+
+        assert_eq!(
+            Error::from(ErrorKind::Character(3, 4, allowed))
+                .at("bonds")
+                .at("counts")
+                .at("header")
+                .render(SOURCE),
+            [
+                "header.counts.bonds: unexpected character at 4:5, expected 0-9, space",
+                "in counts line, bond count `bbb`",
+                "4 |   1 x0  0  0  0  0            999 V2000",
+                "  |     ^",
+            ]
+            .join("\n")
+        )
+    }
+
+    #[test]
+    fn render_eol() {
+        assert_eq!(
+            Error::from(ErrorKind::Eol(3)).render(b"\n\n\n  1"),
+            ["unexpected end of line at line 4", "4 |   1", "  |    ^"]
+                .join("\n")
+        )
+    }
+
+    #[test]
+    fn render_record() {
+        assert_eq!(
+            Error::from(ErrorKind::Record(
+                1,
+                5..12,
+                Box::new(Error::from(ErrorKind::Keyword(1, 0)))
+            ))
+            .render(b"$$$$\nM  XYZ\n"),
+            ["record 2: unknown keyword at 2:1", "2 | M  XYZ", "  | ^"]
+                .join("\n")
+        )
+    }
+
+    #[test]
+    fn render_record_field() {
+        let error = Error::from(ErrorKind::Range(1, 5, -15, 15))
+            .at("charge")
+            .at(0)
+            .at("atoms");
+
+        assert_eq!(
+            Error::from(ErrorKind::Record(
+                1,
+                5..12,
+                Box::new(error.at("ctab"))
+            ))
+            .render(b"$$$$\n  C  99\n"),
+            [
+                "record 2: ctab.atoms[0].charge: value outside -15..15 at 2:6",
+                "in atom block, atom 1, charge `ccc`",
+                "2 |   C  99",
+                "  |      ^"
+            ]
+            .join("\n")
+        )
+    }
+
+    #[test]
+    fn render_v3000_field() {
+        let source = [
+            "",
+            "",
+            "",
+            "  0  0  0     0  0            999 V3000",
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C x 0 0 0",
+            "",
+        ]
+        .join("\n");
+        let mut bytes = source.bytes();
+        let mut reader = Reader::new(&mut bytes);
+        let error = molfile(&mut reader).unwrap_err();
+
+        assert_eq!(
+            error.render(source.as_bytes()),
+            [
+                "v3000.ctab.atoms[0].x: unexpected character at 8:12, expected 0-9",
+                "in V3000 atom block, atom 1, x coordinate (position 3)",
+                "8 | M  V30 1 C x 0 0 0",
+                "  |            ^",
+            ]
+            .join("\n")
+        )
+    }
+
+    #[test]
+    fn render_io() {
+        assert_eq!(
+            Error::from(io::Error::from(io::ErrorKind::Other)).render(SOURCE),
+            "I/O error: other error"
+        )
+    }
+}
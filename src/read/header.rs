@@ -1,75 +1,226 @@
 use crate::{
-    build::Target,
+    build::{self, Builder, Target},
     molfile::{ChiralFlag, Counts, Header, MoleculeName, Parameters, Version},
     primitive::{FixedCount, FixedInteger, FixedReal, Line, Sequence},
+    text::Printable,
 };
 
-use super::{Error, Reader};
+use super::{path::At, Error, ErrorKind, Reader, WarningKind};
 
 pub fn header(reader: &mut Reader) -> Result<Header, Error> {
-    let molecule_name =
-        reader.read_line(Target::Builder(MoleculeName::start()))?;
+    let (molecule_name, parameters, comment) =
+        header_block(reader).at("header")?;
+    let counts = counts(reader).at("counts").at("header")?;
+
+    Ok(Header {
+        molecule_name,
+        parameters,
+        comment,
+        counts,
+    })
+}
+
+// embedded CTABs carry no header of their own
+pub(super) fn blank(counts: Counts) -> Header {
+    Header {
+        molecule_name: MoleculeName::from_str("").expect("name"),
+        parameters: None,
+        comment: Line::from_str("").expect("comment"),
+        counts,
+    }
+}
+
+pub(super) fn blank_v3000() -> Header {
+    blank(Counts {
+        atoms: FixedCount::Zero,
+        bonds: FixedCount::Zero,
+        atom_lists: FixedCount::Zero,
+        chiral: ChiralFlag::NotChiral,
+        stext: Some(FixedCount::Zero),
+        version: Version::V3,
+    })
+}
+
+pub(super) fn header_block(
+    reader: &mut Reader,
+) -> Result<(MoleculeName, Option<Parameters>, Line<80>), Error> {
+    let molecule_name = reader
+        .read_line(Target::Builder(MoleculeName::start()))
+        .at("molecule_name")?;
 
     let parameters = if reader.has_blank() {
-        reader.next_line()?;
+        reader.next_line().at("parameters")?;
 
         None
     } else {
-        let user_initials = reader.read(Target::Builder(Sequence::start()))?;
-        let program_name = reader.read(Target::Builder(Sequence::start()))?;
-        let timestamp = reader.read(Target::Builder(Sequence::start()))?;
-        let dimensional_codes =
-            reader.read(Target::Builder(Sequence::start()))?;
-        let major_scaling =
-            reader.read(Target::Builder(FixedInteger::start()))?;
-        let minor_scaling = reader.read(Target::Builder(FixedReal::start()))?;
-        let energy = reader.read(Target::Builder(FixedReal::start()))?;
-        let registry_number =
-            reader.read_line(Target::Builder(FixedInteger::start()))?;
-
-        Some(Parameters {
-            user_initials,
-            program_name,
-            timestamp,
-            dimensional_codes,
-            major_scaling,
-            minor_scaling,
-            energy,
-            registry_number,
-        })
+        Some(parameters(reader).at("parameters")?)
     };
 
-    let comment = reader.read_line(Target::Builder(Line::start()))?;
-    let atoms = reader.read(Target::Builder(FixedCount::start()))?;
-    let bonds = reader.read(Target::Builder(FixedCount::start()))?;
-    let atom_lists = reader.read(Target::Builder(FixedCount::start()))?;
+    let comment = reader
+        .read_line(Target::Builder(Line::start()))
+        .at("comment")?;
+
+    Ok((molecule_name, parameters, comment))
+}
+
+fn parameters(reader: &mut Reader) -> Result<Parameters, Error> {
+    let user_initials = reader
+        .read(Target::Builder(Sequence::start()))
+        .at("user_initials")?;
+    let program_name = reader
+        .read(Target::Builder(Sequence::start()))
+        .at("program_name")?;
+    let timestamp = reader
+        .read(Target::Builder(Sequence::start()))
+        .at("timestamp")?;
+    let dimensional_codes = reader
+        .read(Target::Builder(Sequence::start()))
+        .at("dimensional_codes")?;
+    let major_scaling = reader
+        .read(Target::Builder(FixedInteger::start()))
+        .at("major_scaling")?;
+    let minor_scaling = reader
+        .read(Target::Builder(FixedReal::start()))
+        .at("minor_scaling")?;
+    let energy = reader
+        .read(Target::Builder(FixedReal::start()))
+        .at("energy")?;
+    let registry_number = reader
+        .read_line(Target::Builder(FixedInteger::start()))
+        .at("registry_number")?;
+
+    Ok(Parameters {
+        user_initials,
+        program_name,
+        timestamp,
+        dimensional_codes,
+        major_scaling,
+        minor_scaling,
+        energy,
+        registry_number,
+    })
+}
+
+pub(super) fn counts(reader: &mut Reader) -> Result<Counts, Error> {
+    let atoms = reader
+        .read(Target::Builder(FixedCount::start()))
+        .at("atoms")?;
+    let bonds = reader
+        .read(Target::Builder(FixedCount::start()))
+        .at("bonds")?;
+    let atom_lists = reader
+        .read(Target::Builder(FixedCount::start()))
+        .at("atom_lists")?;
 
     reader.read(Target::Builder(Sequence::<3>::start()))?;
 
-    let chiral = reader.read(Target::Builder(ChiralFlag::start()))?;
+    let chiral = if reader.is_lenient() {
+        lenient_chiral(reader)
+    } else {
+        reader.read(Target::Builder(ChiralFlag::start()))
+    }
+    .at("chiral")?;
 
-    // sss, xxx, rrr, ppp, iii, mmm
-    reader.read(Target::Builder(Sequence::<18>::start()))?;
+    let stext = reader
+        .read(Target::Builder(FixedCount::start_optional()))
+        .at("stext")?;
 
-    let version = reader.read_line(Target::Builder(Version::start()))?;
+    let version = if reader.is_lenient() {
+        lenient_version(reader).at("version")?
+    } else {
+        // xxx, rrr, ppp, iii, mmm
+        reader.read(Target::Builder(Sequence::<15>::start()))?;
 
-    Ok(Header {
-        molecule_name,
-        parameters,
-        comment,
-        counts: Counts {
-            atoms,
-            bonds,
-            atom_lists,
-            chiral,
-            version,
-        },
+        reader
+            .read_line(Target::Builder(Version::start()))
+            .at("version")?
+    };
+
+    Ok(Counts {
+        atoms,
+        bonds,
+        atom_lists,
+        chiral,
+        stext,
+        version,
+    })
+}
+
+fn lenient_chiral(reader: &mut Reader) -> Result<ChiralFlag, Error> {
+    let row = reader.row;
+    let column = reader.column;
+    let flag = reader.read(Target::Builder(Sequence::<3>::start()))?;
+
+    if flag.printables() == [Printable::Space; 3] {
+        reader.warn(row, column, WarningKind::BlankChiral);
+
+        Ok(ChiralFlag::NotChiral)
+    } else {
+        feed(ChiralFlag::start(), flag.printables(), row, column)
+    }
+}
+
+// reads the obsolete fields and version to the end of the line
+fn lenient_version(reader: &mut Reader) -> Result<Version, Error> {
+    let row = reader.row;
+    let column = reader.column + 15;
+    let rest = reader.read_line(Target::Builder(Line::<80>::start()))?;
+    let printables = rest.printables().get(15..).unwrap_or_default();
+    let text = printables
+        .iter()
+        .map(|printable| printable.to_byte() as char)
+        .collect::<String>();
+
+    let version = match text.trim() {
+        "" => {
+            reader.warn(row, column, WarningKind::BlankVersion);
+
+            return Ok(Version::V2);
+        }
+        "V2000" => Version::V2,
+        "V3000" => Version::V3,
+        _ => return feed(Version::start(), printables, row, column),
+    };
+
+    if text != format!(" {}", text.trim()) {
+        reader.warn(row, column, WarningKind::PaddedVersion);
+    }
+
+    Ok(version)
+}
+
+// builds from printables read earlier, starting at row, column
+fn feed<P, B: Builder<Product = P>>(
+    mut builder: B,
+    printables: &[Printable],
+    row: usize,
+    column: usize,
+) -> Result<P, Error> {
+    for (index, printable) in printables.iter().enumerate() {
+        builder = match builder.push(*printable) {
+            Ok(Target::Builder(builder)) => builder,
+            Ok(Target::Product(product)) if index + 1 == printables.len() => {
+                return Ok(product)
+            }
+            Ok(Target::Product(_)) => {
+                return Err(ErrorKind::Overflow(row, column + index + 1).into())
+            }
+            Err(error) => {
+                return Err(Error::from_build(row, column + index, error))
+            }
+        }
+    }
+
+    builder.done().ok_or_else(|| {
+        Error::from_build(row, column + printables.len(), build::Error::End)
     })
 }
 
 #[cfg(test)]
+#[allow(clippy::useless_vec)]
 mod tests {
     use super::*;
+    use crate::read::{path::field, Strictness, Warning};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -77,7 +228,10 @@ mod tests {
         let mut bytes = b"".iter().cloned();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(0)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.molecule_name", ErrorKind::Eof(0)))
+        )
     }
 
     #[test]
@@ -85,7 +239,10 @@ mod tests {
         let mut bytes = vec!["", "AB"].join("\n").into_bytes().into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.parameters.program_name", ErrorKind::Eof(1)))
+        )
     }
 
     #[test]
@@ -94,7 +251,10 @@ mod tests {
             vec!["", "AB-CTCORE-"].join("\n").into_bytes().into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.parameters.timestamp", ErrorKind::Eof(1)))
+        )
     }
 
     #[test]
@@ -105,7 +265,13 @@ mod tests {
             .into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field(
+                "header.parameters.dimensional_codes",
+                ErrorKind::Eof(1)
+            ))
+        )
     }
     #[test]
     fn eof_after_dimensional_codes() {
@@ -115,7 +281,10 @@ mod tests {
             .into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.parameters.major_scaling", ErrorKind::Eof(1)))
+        )
     }
 
     #[test]
@@ -126,7 +295,10 @@ mod tests {
             .into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.parameters.minor_scaling", ErrorKind::Eof(1)))
+        )
     }
     #[test]
     fn eof_after_minor_scaling_factor() {
@@ -136,7 +308,10 @@ mod tests {
             .into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.parameters.energy", ErrorKind::Eof(1)))
+        )
     }
 
     #[test]
@@ -148,7 +323,13 @@ mod tests {
                 .into_iter();
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(1)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field(
+                "header.parameters.registry_number",
+                ErrorKind::Eof(1)
+            ))
+        )
     }
 
     #[test]
@@ -164,7 +345,10 @@ mod tests {
 
         let mut reader = Reader::new(&mut bytes);
 
-        assert_eq!(header(&mut reader), Err(Error::Eof(2)))
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.comment", ErrorKind::Eof(2)))
+        )
     }
 
     #[test]
@@ -194,9 +378,137 @@ mod tests {
                     bonds: FixedCount::Zero,
                     atom_lists: FixedCount::Zero,
                     chiral: ChiralFlag::Chiral,
+                    stext: None,
                     version: Version::V3,
                 }
             })
         )
     }
+    fn lenient(counts: &str) -> (Result<Header, Error>, Vec<Warning>) {
+        let mut bytes = ["Name", "", "Comment", counts, ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.options.strictness = Strictness::Lenient;
+
+        let result = header(&mut reader);
+
+        (result, reader.warnings)
+    }
+
+    fn counts(chiral: ChiralFlag, version: Version) -> Counts {
+        Counts {
+            atoms: FixedCount::Zero,
+            bonds: FixedCount::Zero,
+            atom_lists: FixedCount::Zero,
+            chiral,
+            stext: None,
+            version,
+        }
+    }
+
+    fn warning(row: usize, column: usize, kind: WarningKind) -> Warning {
+        Warning { row, column, kind }
+    }
+
+    #[test]
+    fn lenient_exact() {
+        let (result, warnings) =
+            lenient("  0  0  0     0                   V2000");
+
+        assert_eq!(
+            result.map(|header| header.counts),
+            Ok(counts(ChiralFlag::NotChiral, Version::V2))
+        );
+        assert_eq!(warnings, vec![])
+    }
+
+    #[test]
+    fn lenient_blank_version() {
+        let (result, warnings) = lenient("  0  0  0     0               999");
+
+        assert_eq!(
+            result.map(|header| header.counts),
+            Ok(counts(ChiralFlag::NotChiral, Version::V2))
+        );
+        assert_eq!(warnings, vec![warning(3, 33, WarningKind::BlankVersion)])
+    }
+
+    #[test]
+    fn lenient_space_version() {
+        let (result, warnings) =
+            lenient("  0  0  0     0               999      ");
+
+        assert_eq!(result.map(|header| header.counts.version), Ok(Version::V2));
+        assert_eq!(warnings, vec![warning(3, 33, WarningKind::BlankVersion)])
+    }
+
+    #[test]
+    fn lenient_padded_version() {
+        let (result, warnings) =
+            lenient("  0  0  0     0               999  V3000 ");
+
+        assert_eq!(result.map(|header| header.counts.version), Ok(Version::V3));
+        assert_eq!(warnings, vec![warning(3, 33, WarningKind::PaddedVersion)])
+    }
+
+    #[test]
+    fn lenient_bad_version() {
+        let (result, _) = lenient("  0  0  0     0               999 V4000");
+
+        assert_eq!(
+            result,
+            Err(field(
+                "header.counts.version",
+                ErrorKind::Character(3, 35, vec![Printable::D2, Printable::D3])
+            ))
+        )
+    }
+
+    #[test]
+    fn lenient_blank_chiral() {
+        let (result, warnings) =
+            lenient("  0  0  0                         V2000");
+
+        assert_eq!(
+            result.map(|header| header.counts),
+            Ok(counts(ChiralFlag::NotChiral, Version::V2))
+        );
+        assert_eq!(warnings, vec![warning(3, 12, WarningKind::BlankChiral)])
+    }
+
+    #[test]
+    fn lenient_bad_chiral() {
+        let (result, _) = lenient("  0  0  0     2                   V2000");
+
+        assert_eq!(
+            result,
+            Err(field(
+                "header.counts.chiral",
+                ErrorKind::Character(3, 14, vec![Printable::D0, Printable::D1])
+            ))
+        )
+    }
+
+    #[test]
+    fn strict_blank_version() {
+        let mut bytes = [
+            "Name",
+            "",
+            "Comment",
+            "  0  0  0     0               999",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            header(&mut reader),
+            Err(field("header.counts.version", ErrorKind::Eol(3)))
+        )
+    }
 }
@@ -1,7 +1,34 @@
+mod atom_block;
+mod atom_list_block;
+mod bond_block;
+mod diagnostic;
 mod error;
 mod header;
+mod molfile;
+mod options;
+mod parents;
+mod path;
+mod properties;
+mod rdf_reader;
 mod reader;
+mod rgfile;
+mod rxnfile;
+mod sdf_reader;
+mod stext_block;
+pub mod v3000;
 
-pub use error::Error;
+pub use atom_block::atom_block;
+pub use atom_list_block::atom_list_block;
+pub use bond_block::bond_block;
+pub use error::{Error, ErrorKind};
 pub use header::header;
+pub use molfile::molfile;
+pub use options::{ReadOptions, Strictness, Warning, WarningKind};
+pub use path::{Path, Segment};
+pub use properties::properties;
+pub use rdf_reader::RdfReader;
 pub use reader::Reader;
+pub use rgfile::rgfile;
+pub use rxnfile::rxnfile;
+pub use sdf_reader::SdfReader;
+pub use stext_block::stext_block;
@@ -0,0 +1,173 @@
+use crate::molfile::{Header, Molfile, Version};
+
+use super::{
+    atom_block, atom_list_block, bond_block, header, properties, stext_block,
+    v3000, Error, Reader,
+};
+
+pub fn molfile(reader: &mut Reader) -> Result<Molfile, Error> {
+    let header = header(reader)?;
+
+    ctab(reader, header)
+}
+
+pub(super) fn ctab(
+    reader: &mut Reader,
+    header: Header,
+) -> Result<Molfile, Error> {
+    match header.counts.version {
+        Version::V2 => {
+            let atoms = atom_block(reader, &header.counts)?;
+            let bonds = bond_block(reader, &header.counts)?;
+            let atom_lists = atom_list_block(reader, &header.counts)?;
+            let stext = stext_block(reader, &header.counts)?;
+            let (properties, sgroups) = properties(reader)?;
+
+            Ok(Molfile {
+                header,
+                atoms,
+                bonds,
+                atom_lists,
+                stext,
+                sgroups,
+                stereo_groups: vec![],
+                properties,
+            })
+        }
+        Version::V3 => {
+            let mut molfile = v3000::ctab(reader, header)?;
+
+            let (mut properties, mut sgroups) = properties(reader)?;
+
+            molfile.properties.append(&mut properties);
+            molfile.sgroups.append(&mut sgroups);
+
+            Ok(molfile)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::molfile::Property;
+    use crate::read::path::field;
+    use crate::read::ErrorKind;
+    use crate::text::Printable;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn eof_after_header() {
+        let mut bytes =
+            ["", "", "", "  1  0  0  0  0  0            999 V2000", ""]
+                .join("\n")
+                .into_bytes()
+                .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            molfile(&mut reader),
+            Err(field("ctab.atoms[0].x", ErrorKind::Eof(4)))
+        )
+    }
+
+    #[test]
+    fn missing_end() {
+        let mut bytes = [
+            "",
+            "",
+            "",
+            "  1  0  0  0  0  0            999 V2000",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            molfile(&mut reader),
+            Err(field("ctab.properties[0]", ErrorKind::Eof(5)))
+        )
+    }
+
+    #[test]
+    fn v2000() {
+        let mut bytes = [
+            "",
+            "",
+            "",
+            "  2  1  0  0  0  0            999 V2000",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+            "    1.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0",
+            "  1  2  1  0  0  0  0",
+            "M  CHG  1   2  -1",
+            "M  END",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+        let molfile = molfile(&mut reader).unwrap();
+
+        assert_eq!(
+            (
+                molfile.atoms.len(),
+                molfile.bonds.len(),
+                molfile.properties.len()
+            ),
+            (2, 1, 2)
+        )
+    }
+
+    #[test]
+    fn v3000() {
+        let mut bytes = [
+            "",
+            "",
+            "",
+            "  0  0  0     0  0            999 V3000",
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 0 0 0 0 0",
+            "M  V30 END CTAB",
+            "M  END",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            molfile(&mut reader).unwrap().properties.last(),
+            Some(&Property::End)
+        )
+    }
+
+    #[test]
+    fn v3000_path() {
+        let mut bytes = [
+            "",
+            "",
+            "",
+            "  0  0  0     0  0            999 V3000",
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C x 0 0 0",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            molfile(&mut reader),
+            Err(field(
+                "v3000.ctab.atoms[0].x",
+                ErrorKind::Character(7, 11, Printable::digits())
+            ))
+        )
+    }
+}
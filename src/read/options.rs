@@ -0,0 +1,36 @@
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ReadOptions {
+    pub strictness: Strictness,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Strictness {
+    #[default]
+    Strict,
+    // repairs common departures from the spec, recording a warning for each
+    Lenient,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Warning {
+    pub row: usize,
+    pub column: usize,
+    pub kind: WarningKind,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum WarningKind {
+    // read as V2000
+    BlankVersion,
+    // version surrounded by extra spaces
+    PaddedVersion,
+    // read as not chiral
+    BlankChiral,
+    TrailingWhitespace,
+    // missing trailing fields read as zero
+    ShortAtomLine,
+    // first letter read as uppercase
+    LowercaseSymbol,
+    // read as a space
+    Tab,
+}
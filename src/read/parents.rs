@@ -0,0 +1,33 @@
+use crate::molfile::Sgroup;
+
+use super::{Error, ErrorKind};
+
+pub fn parents(
+    sgroups: &[Sgroup],
+    references: &[(usize, usize, usize)],
+) -> Result<(), Error> {
+    for (child, row, column) in references {
+        let mut current = &sgroups[*child];
+
+        for _ in 0..sgroups.len() {
+            current = match &current.parent {
+                Some(parent) => {
+                    match sgroups.iter().find(|sgroup| &sgroup.index == parent)
+                    {
+                        Some(sgroup) => sgroup,
+                        None => {
+                            return Err(ErrorKind::Index(*row, *column).into())
+                        }
+                    }
+                }
+                None => break,
+            }
+        }
+
+        if current.parent.is_some() {
+            return Err(ErrorKind::Index(*row, *column).into());
+        }
+    }
+
+    Ok(())
+}
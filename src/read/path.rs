@@ -0,0 +1,184 @@
+use std::fmt;
+
+use super::Error;
+#[cfg(test)]
+use super::ErrorKind;
+
+// header.parameters.minor_scaling, ctab.atoms[12].mass_difference
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Path(Vec<Segment>);
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Segment {
+    Field(&'static str),
+    Index(usize),
+    // V3000 keyword like CHG
+    Keyword(String),
+}
+
+impl Path {
+    pub fn new(segments: Vec<Segment>) -> Self {
+        Self(segments)
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.0
+    }
+
+    pub fn fields(&self) -> Vec<&'static str> {
+        self.0
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Field(name) => Some(*name),
+                Segment::Index(_) | Segment::Keyword(_) => None,
+            })
+            .collect()
+    }
+
+    pub(super) fn prepend(&mut self, segment: Segment) {
+        self.0.insert(0, segment)
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self.0.iter().enumerate() {
+            match segment {
+                Segment::Field(name) if index == 0 => write!(f, "{}", name)?,
+                Segment::Field(name) => write!(f, ".{}", name)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+                Segment::Keyword(keyword) => write!(f, ".{}", keyword)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&'static str> for Segment {
+    fn from(name: &'static str) -> Self {
+        Self::Field(name)
+    }
+}
+
+impl From<usize> for Segment {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+// prefixes the path of a failed read with a segment
+pub(crate) trait At<T> {
+    fn at(self, segment: impl Into<Segment>) -> Result<T, Error>;
+}
+
+impl<T> At<T> for Result<T, Error> {
+    fn at(self, segment: impl Into<Segment>) -> Result<T, Error> {
+        self.map_err(|error| error.at(segment))
+    }
+}
+
+// test shorthand for an error read at a path like "ctab.atoms[0].x"
+#[cfg(test)]
+pub(crate) fn field(path: &'static str, kind: ErrorKind) -> Error {
+    let mut segments = Vec::new();
+
+    for part in path.split('.') {
+        let mut parts = part.split('[');
+
+        match parts.next().filter(|name| !name.is_empty()) {
+            // upper case names are V3000 keywords
+            Some(name)
+                if name.bytes().all(|byte| byte.is_ascii_uppercase()) =>
+            {
+                segments.push(Segment::Keyword(name.to_string()))
+            }
+            Some(name) => segments.push(Segment::Field(name)),
+            None => (),
+        }
+
+        for index in parts {
+            segments.push(Segment::Index(
+                index.trim_end_matches(']').parse().expect("index"),
+            ));
+        }
+    }
+
+    Error {
+        path: Path::new(segments),
+        kind,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn display() {
+        let path = Path::new(vec![
+            "ctab".into(),
+            "atoms".into(),
+            12.into(),
+            "mass_difference".into(),
+        ]);
+
+        assert_eq!(path.to_string(), "ctab.atoms[12].mass_difference")
+    }
+
+    #[test]
+    fn display_keyword() {
+        let path = Path::new(vec![
+            "ctab".into(),
+            "atoms".into(),
+            0.into(),
+            Segment::Keyword("CHG".to_string()),
+        ]);
+
+        assert_eq!(path.to_string(), "ctab.atoms[0].CHG")
+    }
+
+    #[test]
+    fn display_leading_index() {
+        let path = Path::new(vec![0.into(), "header".into()]);
+
+        assert_eq!(path.to_string(), "[0].header")
+    }
+
+    #[test]
+    fn fields() {
+        let path = Path::new(vec!["ctab".into(), "atoms".into(), 1.into()]);
+
+        assert_eq!(path.fields(), vec!["ctab", "atoms"])
+    }
+
+    #[test]
+    fn field_shorthand() {
+        assert_eq!(
+            field("ctab.atoms[1].x", ErrorKind::Eof(0)),
+            Error {
+                path: Path::new(vec![
+                    "ctab".into(),
+                    "atoms".into(),
+                    1.into(),
+                    "x".into()
+                ]),
+                kind: ErrorKind::Eof(0)
+            }
+        )
+    }
+
+    #[test]
+    fn at() {
+        let result: Result<(), Error> = Err(ErrorKind::Eof(0).into());
+
+        assert_eq!(
+            result.at("x").at(1).at("atoms"),
+            Err(Error {
+                path: Path::new(vec!["atoms".into(), 1.into(), "x".into()]),
+                kind: ErrorKind::Eof(0)
+            })
+        )
+    }
+}
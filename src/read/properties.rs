@@ -0,0 +1,1304 @@
+use crate::{
+    build::{Builder, Target},
+    molfile::{
+        Bracket, Connectivity, CrossingVector, Entry, LinkAtom, ListType,
+        Property, Sgroup, SgroupAttachment, SgroupSubtype, SgroupType,
+    },
+    primitive::{
+        FixedCount, FixedInteger, FixedReal, Line, Literal, Natural, Padding,
+        Sequence, Text,
+    },
+    text::Printable,
+};
+
+use super::{
+    parents::parents, path::At, rgfile, Error, ErrorKind, Reader, WarningKind,
+};
+
+pub fn properties(
+    reader: &mut Reader,
+) -> Result<(Vec<Property>, Vec<Sgroup>), Error> {
+    let mut result = Vec::new();
+    let mut sgroups = Sgroups::default();
+
+    loop {
+        match property(reader, &mut sgroups)
+            .at(result.len())
+            .at("properties")
+            .at("ctab")?
+        {
+            Some(Property::End) => {
+                result.push(Property::End);
+
+                let sgroups = sgroups.finish().at("sgroups").at("ctab")?;
+
+                break Ok((result, sgroups));
+            }
+            Some(property) => result.push(property),
+            None => (),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Sgroups {
+    sgroups: Vec<Sgroup>,
+    parents: Vec<(usize, usize, usize)>,
+    data: Vec<(usize, usize, Vec<Printable>)>,
+}
+
+impl Sgroups {
+    fn find(&mut self, reader: &mut Reader) -> Result<&mut Sgroup, Error> {
+        space(reader)?;
+
+        let row = reader.row;
+        let column = reader.column;
+        let index =
+            reader.read(Target::Builder(Natural::start_fixed::<3>()))?;
+
+        self.sgroups
+            .iter_mut()
+            .find(|sgroup| sgroup.index == index)
+            .ok_or(ErrorKind::Index(row, column).into())
+    }
+
+    fn data(
+        &mut self,
+        reader: &mut Reader,
+    ) -> Result<&mut Vec<Printable>, Error> {
+        let row = reader.row;
+        let position = self.position(reader)?;
+
+        match self
+            .data
+            .iter()
+            .position(|(other, _, _)| *other == position)
+        {
+            Some(index) => Ok(&mut self.data[index].2),
+            None => {
+                self.data.push((position, row, Vec::new()));
+
+                Ok(&mut self.data.last_mut().expect("data").2)
+            }
+        }
+    }
+
+    fn position(&mut self, reader: &mut Reader) -> Result<usize, Error> {
+        let sgroup = self.find(reader)?.index.clone();
+
+        Ok(self
+            .sgroups
+            .iter()
+            .position(|other| other.index == sgroup)
+            .expect("sgroup"))
+    }
+
+    fn finish(self) -> Result<Vec<Sgroup>, Error> {
+        if let Some((_, row, _)) = self.data.first() {
+            return Err(ErrorKind::Keyword(*row, 3).into());
+        }
+
+        parents(&self.sgroups, &self.parents)?;
+
+        Ok(self.sgroups)
+    }
+}
+
+fn property(
+    reader: &mut Reader,
+    sgroups: &mut Sgroups,
+) -> Result<Option<Property>, Error> {
+    let row = reader.row;
+    let prefix = reader.read(Target::Builder(Line::<3>::start()))?;
+    let mut bytes = prefix
+        .printables()
+        .iter()
+        .map(Printable::to_byte)
+        .collect::<Vec<_>>();
+
+    match bytes.as_slice() {
+        b"M  " => (),
+        b"A  " | b"V  " | b"G  " => return legacy(reader, &prefix).map(Some),
+        b"" if reader.is_eof() => return Err(ErrorKind::Eof(row).into()),
+        _ => return Err(ErrorKind::Keyword(row, 0).into()),
+    }
+
+    let key = reader.read(Target::Builder(Line::<3>::start()))?;
+
+    bytes.extend(key.printables().iter().map(Printable::to_byte));
+
+    let property = match bytes.as_slice() {
+        b"M  END" => {
+            if reader.is_lenient() && reader.has(Printable::Space) {
+                trailing(reader)?;
+            }
+
+            if !reader.is_eof() {
+                reader.next_line()?;
+            }
+
+            return Ok(Some(Property::End));
+        }
+        b"M  CHG" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::Charge(entries(reader, count, || {
+                FixedInteger::start_range(-15, 15)
+            })?)
+        }
+        b"M  RAD" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::Radical(entries(reader, count, FixedCount::start)?)
+        }
+        b"M  ISO" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::Isotope(entries(reader, count, FixedCount::start)?)
+        }
+        b"M  RBC" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::RingBondCount(entries(
+                reader,
+                count,
+                FixedInteger::start,
+            )?)
+        }
+        b"M  SUB" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::SubstitutionCount(entries(
+                reader,
+                count,
+                FixedInteger::start,
+            )?)
+        }
+        b"M  UNS" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::Unsaturated(entries(reader, count, FixedCount::start)?)
+        }
+        b"M  ALS" => {
+            let atom = atom(reader)?;
+            let count =
+                reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+            space(reader)?;
+
+            let list_type = reader.read(Target::Builder(ListType::start()))?;
+            let mut elements = Vec::new();
+
+            space(reader)?;
+
+            for _ in 0..count.to_int() {
+                elements.push(reader.read(Target::Builder(Sequence::start()))?);
+            }
+
+            Property::AtomList {
+                atom,
+                list_type,
+                elements,
+            }
+        }
+        b"M  APO" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::AttachmentPoint(entries(
+                reader,
+                count,
+                FixedCount::start,
+            )?)
+        }
+        b"M  AAL" => {
+            let atom = atom(reader)?;
+            let count =
+                reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+            Property::AttachmentOrder {
+                atom,
+                entries: entries(reader, count.to_int(), FixedCount::start)?,
+            }
+        }
+        b"M  RGP" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::RgroupLabel(entries(
+                reader,
+                count,
+                Natural::start_fixed::<3>,
+            )?)
+        }
+        b"M  LOG" => {
+            let count =
+                reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+            if count.to_int() != 1 {
+                return Err(ErrorKind::Character(
+                    reader.row,
+                    reader.column - 1,
+                    vec![Printable::D1],
+                )
+                .into());
+            }
+
+            let rgroup = atom(reader)?;
+
+            space(reader)?;
+
+            let if_then = reader.read(Target::Builder(FixedCount::start()))?;
+
+            space(reader)?;
+
+            let rest_h = reader.read(Target::Builder(FixedCount::start()))?;
+
+            space(reader)?;
+
+            let row = reader.row;
+            let column = reader.column;
+            let occurrence = reader.read(Target::Builder(Line::start()))?;
+
+            rgfile::occurrence(&occurrence, row, column)?;
+
+            Property::RgroupLogic {
+                rgroup,
+                if_then,
+                rest_h,
+                occurrence,
+            }
+        }
+        b"M  LIN" => {
+            let count = entry_count(reader, 8)?;
+            let mut links = Vec::new();
+
+            for _ in 0..count {
+                let atom = atom(reader)?;
+
+                space(reader)?;
+
+                let repetitions =
+                    reader.read(Target::Builder(FixedCount::start()))?;
+                let first = self::atom(reader)?;
+                let second = self::atom(reader)?;
+
+                links.push(LinkAtom {
+                    atom,
+                    repetitions,
+                    first,
+                    second,
+                })
+            }
+
+            Property::Link(links)
+        }
+        b"M  ZCH" => {
+            let count = entry_count(reader, 8)?;
+
+            Property::ZeroOrderCharge(entries(
+                reader,
+                count,
+                FixedInteger::start,
+            )?)
+        }
+        b"M  ZZC" => {
+            let atom = atom(reader)?;
+
+            space(reader)?;
+
+            let label = reader.read(Target::Builder(Line::start()))?;
+
+            Property::AtomLabel { atom, label }
+        }
+        b"M  REG" => {
+            space(reader)?;
+
+            Property::Registry(reader.read(Target::Builder(Natural::start()))?)
+        }
+        b"M  STY" => {
+            for _ in 0..entry_count(reader, 8)? {
+                space(reader)?;
+
+                let row = reader.row;
+                let column = reader.column;
+                let index = reader
+                    .read(Target::Builder(Natural::start_fixed::<3>()))?;
+
+                space(reader)?;
+
+                let sgroup_type = match keyword::<3>(reader)?.as_slice() {
+                    b"SUP" => SgroupType::Superatom,
+                    b"MUL" => SgroupType::Multiple,
+                    b"SRU" => SgroupType::StructureRepeatingUnit,
+                    b"MON" => SgroupType::Monomer,
+                    b"COP" => SgroupType::Copolymer,
+                    b"DAT" => SgroupType::Data,
+                    b"GEN" => SgroupType::Generic,
+                    b"FOR" => SgroupType::Formulation,
+                    b"ANY" => SgroupType::AnyPolymer,
+                    b"COM" => SgroupType::Component,
+                    b"MIX" => SgroupType::Mixture,
+                    _ => {
+                        return Err(ErrorKind::Keyword(
+                            reader.row,
+                            reader.column - 3,
+                        )
+                        .into())
+                    }
+                };
+
+                if sgroups.sgroups.iter().any(|sgroup| sgroup.index == index) {
+                    return Err(ErrorKind::Index(row, column).into());
+                }
+
+                sgroups.sgroups.push(Sgroup::new(index, sgroup_type))
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SST" => {
+            for _ in 0..entry_count(reader, 8)? {
+                let sgroup = sgroups.find(reader)?;
+
+                space(reader)?;
+
+                sgroup.subtype = Some(match keyword::<3>(reader)?.as_slice() {
+                    b"ALT" => SgroupSubtype::Alternating,
+                    b"RAN" => SgroupSubtype::Random,
+                    b"BLO" => SgroupSubtype::Block,
+                    _ => {
+                        return Err(ErrorKind::Keyword(
+                            reader.row,
+                            reader.column - 3,
+                        )
+                        .into())
+                    }
+                })
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SLB" => {
+            for _ in 0..entry_count(reader, 8)? {
+                let sgroup = sgroups.find(reader)?;
+
+                space(reader)?;
+
+                let id =
+                    reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+                sgroup.external_index = Natural::from_int(id.to_int())
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SCN" => {
+            for _ in 0..entry_count(reader, 8)? {
+                let sgroup = sgroups.find(reader)?;
+
+                space(reader)?;
+
+                sgroup.connectivity =
+                    Some(match keyword::<3>(reader)?.as_slice() {
+                        b"HH " => Connectivity::HeadToHead,
+                        b"HT " => Connectivity::HeadToTail,
+                        b"EU " => Connectivity::EitherUnknown,
+                        _ => {
+                            return Err(ErrorKind::Keyword(
+                                reader.row,
+                                reader.column - 3,
+                            )
+                            .into())
+                        }
+                    })
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SDS" => {
+            reader.read(Target::Builder(Literal::start(b" EXP")))?;
+
+            for _ in 0..entry_count(reader, 15)? {
+                sgroups.find(reader)?.expanded = true
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SAL" => {
+            let sgroup = sgroups.find(reader)?;
+
+            for _ in 0..entry_count(reader, 15)? {
+                sgroup.atoms.push(atom(reader)?)
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SBL" => {
+            let sgroup = sgroups.find(reader)?;
+
+            for _ in 0..entry_count(reader, 15)? {
+                sgroup.crossing_bonds.push(atom(reader)?)
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SPA" => {
+            let sgroup = sgroups.find(reader)?;
+
+            for _ in 0..entry_count(reader, 15)? {
+                sgroup.paradigmatic_atoms.push(atom(reader)?)
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SMT" => {
+            let sgroup = sgroups.find(reader)?;
+
+            space(reader)?;
+
+            if sgroup.sgroup_type == SgroupType::Multiple {
+                sgroup.multiplier =
+                    Some(reader.read(Target::Builder(Natural::start()))?)
+            } else {
+                sgroup.label =
+                    Some(reader.read(Target::Builder(Text::start()))?)
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SDI" => {
+            let sgroup = sgroups.find(reader)?;
+            let count =
+                reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+            if count.to_int() != 4 {
+                return Err(ErrorKind::Character(
+                    reader.row,
+                    reader.column - 1,
+                    vec![Printable::D4],
+                )
+                .into());
+            }
+
+            sgroup.brackets.push(Bracket {
+                x1: reader.read(Target::Builder(FixedReal::start()))?,
+                y1: reader.read(Target::Builder(FixedReal::start()))?,
+                x2: reader.read(Target::Builder(FixedReal::start()))?,
+                y2: reader.read(Target::Builder(FixedReal::start()))?,
+            });
+
+            return sgroup_line(reader);
+        }
+        b"M  SBV" => {
+            let sgroup = sgroups.find(reader)?;
+
+            sgroup.crossing_vectors.push(CrossingVector {
+                bond: atom(reader)?,
+                x: reader.read(Target::Builder(FixedReal::start()))?,
+                y: reader.read(Target::Builder(FixedReal::start()))?,
+            });
+
+            return sgroup_line(reader);
+        }
+        b"M  SPL" => {
+            for _ in 0..entry_count(reader, 8)? {
+                let position = sgroups.position(reader)?;
+
+                space(reader)?;
+
+                let row = reader.row;
+                let column = reader.column;
+
+                sgroups.sgroups[position].parent = Some(
+                    reader
+                        .read(Target::Builder(Natural::start_fixed::<3>()))?,
+                );
+                sgroups.parents.push((position, row, column))
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SNC" => {
+            for _ in 0..entry_count(reader, 8)? {
+                let sgroup = sgroups.find(reader)?;
+
+                sgroup.component = Some(atom(reader)?)
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SAP" => {
+            let sgroup = sgroups.find(reader)?;
+
+            for _ in 0..entry_count(reader, 6)? {
+                let atom = atom(reader)?;
+
+                space(reader)?;
+
+                let leaving_atom =
+                    reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+                space(reader)?;
+
+                sgroup.attachments.push(SgroupAttachment {
+                    atom,
+                    leaving_atom: Natural::from_int(leaving_atom.to_int()),
+                    id: reader.read(Target::Builder(Sequence::start()))?,
+                })
+            }
+
+            return sgroup_line(reader);
+        }
+        b"M  SCL" => {
+            let sgroup = sgroups.find(reader)?;
+
+            space(reader)?;
+
+            sgroup.class = Some(reader.read(Target::Builder(Text::start()))?);
+
+            return sgroup_line(reader);
+        }
+        b"M  SDT" => {
+            let sgroup = sgroups.find(reader)?;
+
+            space(reader)?;
+
+            sgroup.field_name = trimmed::<30>(reader)?;
+            sgroup.field_type = trimmed::<2>(reader)?;
+            sgroup.field_info = trimmed::<20>(reader)?;
+            sgroup.query_type = trimmed::<2>(reader)?;
+            sgroup.query_operator = trimmed::<15>(reader)?;
+
+            return sgroup_line(reader);
+        }
+        b"M  SDD" => {
+            let sgroup = sgroups.find(reader)?;
+
+            space(reader)?;
+
+            sgroup.field_display =
+                Some(reader.read(Target::Builder(Text::start()))?);
+
+            return sgroup_line(reader);
+        }
+        b"M  SCD" => {
+            let data = sgroups.data(reader)?;
+
+            space(reader)?;
+
+            data.extend(
+                reader
+                    .read(Target::Builder(Text::start()))?
+                    .printables()
+                    .iter(),
+            );
+
+            return sgroup_line(reader);
+        }
+        b"M  SED" => {
+            let position = sgroups.position(reader)?;
+            let mut data = match sgroups
+                .data
+                .iter()
+                .position(|(other, _, _)| *other == position)
+            {
+                Some(index) => sgroups.data.remove(index).2,
+                None => Vec::new(),
+            };
+
+            space(reader)?;
+
+            data.extend(
+                reader
+                    .read(Target::Builder(Text::start()))?
+                    .printables()
+                    .iter(),
+            );
+            sgroups.sgroups[position]
+                .field_data
+                .push(text(&data).expect("text"));
+
+            return sgroup_line(reader);
+        }
+        _ => return unknown(reader, &prefix, &key).map(Some),
+    };
+
+    reader.next_line()?;
+
+    Ok(Some(property))
+}
+
+fn sgroup_line(reader: &mut Reader) -> Result<Option<Property>, Error> {
+    reader.next_line()?;
+
+    Ok(None)
+}
+
+fn keyword<const W: usize>(reader: &mut Reader) -> Result<Vec<u8>, Error> {
+    Ok(reader
+        .read(Target::Builder(Sequence::<W>::start()))?
+        .printables()
+        .iter()
+        .map(Printable::to_byte)
+        .collect())
+}
+
+fn trimmed<const L: usize>(reader: &mut Reader) -> Result<Option<Text>, Error> {
+    let line = reader.read(Target::Builder(Line::<L>::start()))?;
+    let printables = line.printables();
+    let end = printables
+        .iter()
+        .rposition(|printable| printable != &Printable::Space)
+        .map_or(0, |index| index + 1);
+
+    if end == 0 {
+        Ok(None)
+    } else {
+        Ok(text(&printables[..end]))
+    }
+}
+
+fn text(printables: &[Printable]) -> Option<Text> {
+    let mut builder = Text::start();
+
+    for printable in printables {
+        builder = match builder.push(*printable) {
+            Ok(Target::Builder(builder)) => builder,
+            _ => unreachable!("text accepts every printable"),
+        }
+    }
+
+    builder.done()
+}
+
+fn trailing(reader: &mut Reader) -> Result<(), Error> {
+    let row = reader.row;
+    let column = reader.column;
+    let text = reader.read(Target::Builder(Text::start()))?;
+
+    match text
+        .printables()
+        .iter()
+        .position(|printable| printable != &Printable::Space)
+    {
+        Some(index) => Err(ErrorKind::Overflow(row, column + index).into()),
+        None => {
+            reader.warn(row, column, WarningKind::TrailingWhitespace);
+
+            Ok(())
+        }
+    }
+}
+
+// obsolete lines that predate the M  prefix
+fn legacy(reader: &mut Reader, prefix: &Line<3>) -> Result<Property, Error> {
+    let atom = reader.read(Target::Builder(Natural::start_fixed::<3>()))?;
+
+    let property = match prefix.printables()[0] {
+        Printable::A => {
+            reader.next_line()?;
+
+            Property::AtomAlias {
+                atom,
+                alias: reader.read(Target::Builder(Line::start()))?,
+            }
+        }
+        Printable::V => {
+            space(reader)?;
+
+            Property::AtomValue {
+                atom,
+                value: reader.read(Target::Builder(Line::start()))?,
+            }
+        }
+        Printable::G => {
+            let matching =
+                reader.read(Target::Builder(Natural::start_fixed::<3>()))?;
+
+            reader.next_line()?;
+
+            Property::GroupAbbreviation {
+                atom,
+                matching,
+                label: reader.read(Target::Builder(Line::start()))?,
+            }
+        }
+        _ => unreachable!("legacy prefix"),
+    };
+
+    reader.next_line()?;
+
+    Ok(property)
+}
+
+fn unknown(
+    reader: &mut Reader,
+    prefix: &Line<3>,
+    key: &Line<3>,
+) -> Result<Property, Error> {
+    let mut target = Target::Builder(Line::<80>::start());
+
+    for printable in prefix.printables().iter().chain(key.printables()) {
+        target = match target {
+            Target::Builder(builder) => match builder.push(*printable) {
+                Ok(target) => target,
+                Err(_) => unreachable!("lines accept every printable"),
+            },
+            product => product,
+        }
+    }
+
+    Ok(Property::Unknown(reader.read_line(target)?))
+}
+
+fn entry_count(reader: &mut Reader, limit: u32) -> Result<u32, Error> {
+    let count = reader.read(Target::Builder(FixedCount::<3>::start()))?;
+
+    if (1..=limit).contains(&count.to_int()) {
+        Ok(count.to_int())
+    } else {
+        Err(
+            ErrorKind::Range(reader.row, reader.column - 1, 1, limit as i32)
+                .into(),
+        )
+    }
+}
+
+fn entries<V, B: Builder<Product = V>>(
+    reader: &mut Reader,
+    count: u32,
+    start: impl Fn() -> B,
+) -> Result<Vec<Entry<V>>, Error> {
+    let mut result = Vec::new();
+
+    for _ in 0..count {
+        let atom = atom(reader)?;
+
+        space(reader)?;
+
+        let value = reader.read(Target::Builder(start()))?;
+
+        result.push(Entry { atom, value })
+    }
+
+    Ok(result)
+}
+
+fn atom(reader: &mut Reader) -> Result<Natural, Error> {
+    space(reader)?;
+
+    reader.read(Target::Builder(Natural::start_fixed::<3>()))
+}
+
+fn space(reader: &mut Reader) -> Result<(), Error> {
+    reader.read(Target::Builder(Padding::<1>::start()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::{path::field, Strictness, Warning};
+    use crate::text::Digit;
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Result<Vec<Property>, Error> {
+        Ok(read_sgroups(lines)?.0)
+    }
+
+    fn read_sgroups(
+        lines: &[&str],
+    ) -> Result<(Vec<Property>, Vec<Sgroup>), Error> {
+        let mut bytes = lines.join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        properties(&mut reader)
+    }
+
+    fn natural(value: u32) -> Natural {
+        Natural::from_int(value).unwrap()
+    }
+
+    #[test]
+    fn eof() {
+        assert_eq!(
+            read(&[]),
+            Err(field("ctab.properties[0]", ErrorKind::Eof(0)))
+        )
+    }
+
+    #[test]
+    fn end() {
+        assert_eq!(read(&["M  END", ""]), Ok(vec![Property::End]))
+    }
+
+    #[test]
+    fn end_at_eof() {
+        assert_eq!(read(&["M  END"]), Ok(vec![Property::End]))
+    }
+
+    #[test]
+    fn end_trailing_space() {
+        assert_eq!(
+            read(&["M  END ", ""]),
+            Err(field("ctab.properties[0]", ErrorKind::Overflow(0, 6)))
+        )
+    }
+
+    #[test]
+    fn charge() {
+        assert_eq!(
+            read(&["M  CHG  2   1  -1   3   2", "M  END"]),
+            Ok(vec![
+                Property::Charge(vec![
+                    Entry {
+                        atom: Natural::from_int(1).unwrap(),
+                        value: FixedInteger::from_int(-1).unwrap()
+                    },
+                    Entry {
+                        atom: Natural::from_int(3).unwrap(),
+                        value: FixedInteger::from_int(2).unwrap()
+                    }
+                ]),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn charge_zero_entries() {
+        assert_eq!(
+            read(&["M  CHG  0", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Range(0, 8, 1, 8)))
+        )
+    }
+
+    #[test]
+    fn charge_out_of_range() {
+        assert_eq!(
+            read(&["M  CHG  1   1  16", "M  END"]),
+            Err(field(
+                "ctab.properties[0]",
+                ErrorKind::Range(0, 16, -15, 15)
+            ))
+        )
+    }
+
+    #[test]
+    fn charge_missing_entry() {
+        assert_eq!(
+            read(&["M  CHG  2   1  -1", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Eol(0)))
+        )
+    }
+
+    #[test]
+    fn charge_extra_entry() {
+        assert_eq!(
+            read(&["M  CHG  1   1  -1   3   2", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Overflow(0, 17)))
+        )
+    }
+
+    #[test]
+    fn isotope() {
+        assert_eq!(
+            read(&["M  ISO  1   2  13", "M  END"]),
+            Ok(vec![
+                Property::Isotope(vec![Entry {
+                    atom: Natural::from_int(2).unwrap(),
+                    value: FixedCount::from_int(13).unwrap()
+                }]),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn atom_list() {
+        assert_eq!(
+            read(&["M  ALS   4  2 T N   O   ", "M  END"]),
+            Ok(vec![
+                Property::AtomList {
+                    atom: Natural::from_int(4).unwrap(),
+                    list_type: ListType::NotList,
+                    elements: vec![
+                        Sequence::from_str("N   ").unwrap(),
+                        Sequence::from_str("O   ").unwrap()
+                    ]
+                },
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn rgroup_label() {
+        assert_eq!(
+            read(&["M  RGP  1   5  12", "M  END"]),
+            Ok(vec![
+                Property::RgroupLabel(vec![Entry {
+                    atom: Natural::from_int(5).unwrap(),
+                    value: Natural::from_int(12).unwrap()
+                }]),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn rgroup_logic_bad_occurrence() {
+        assert_eq!(
+            read(&["M  LOG  1   1   0   1 1-x", "M  END"]),
+            Err(field(
+                "ctab.properties[0]",
+                ErrorKind::Character(0, 24, Printable::digits())
+            ))
+        )
+    }
+
+    #[test]
+    fn rgroup_logic() {
+        assert_eq!(
+            read(&["M  LOG  1   1   0   1 1-3", "M  END"]),
+            Ok(vec![
+                Property::RgroupLogic {
+                    rgroup: Natural::from_int(1).unwrap(),
+                    if_then: FixedCount::Zero,
+                    rest_h: FixedCount::from_int(1).unwrap(),
+                    occurrence: Line::from_str("1-3").unwrap()
+                },
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn link() {
+        assert_eq!(
+            read(&["M  LIN  1   2   4   1   3", "M  END"]),
+            Ok(vec![
+                Property::Link(vec![LinkAtom {
+                    atom: Natural::from_int(2).unwrap(),
+                    repetitions: FixedCount::from_int(4).unwrap(),
+                    first: Natural::from_int(1).unwrap(),
+                    second: Natural::from_int(3).unwrap()
+                }]),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn registry() {
+        assert_eq!(
+            read(&["M  REG 12345678", "M  END"]),
+            Ok(vec![
+                Property::Registry(Natural::from_int(12345678).unwrap()),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn unknown_m_line() {
+        assert_eq!(
+            read(&["M  XYZ  1 whatever", "M  END"]),
+            Ok(vec![
+                Property::Unknown(
+                    Line::from_str("M  XYZ  1 whatever").unwrap()
+                ),
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn atom_alias() {
+        assert_eq!(
+            read(&["A    1", "OH", "M  END"]),
+            Ok(vec![
+                Property::AtomAlias {
+                    atom: natural(1),
+                    alias: Line::from_str("OH").unwrap()
+                },
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn atom_value() {
+        assert_eq!(
+            read(&["V   12 value", "M  END"]),
+            Ok(vec![
+                Property::AtomValue {
+                    atom: natural(12),
+                    value: Line::from_str("value").unwrap()
+                },
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn group_abbreviation() {
+        assert_eq!(
+            read(&["G    3  2", "Ph", "M  END"]),
+            Ok(vec![
+                Property::GroupAbbreviation {
+                    atom: natural(3),
+                    matching: natural(2),
+                    label: Line::from_str("Ph").unwrap()
+                },
+                Property::End
+            ])
+        )
+    }
+
+    #[test]
+    fn unknown_legacy_line() {
+        assert_eq!(
+            read(&["S  SKP  1", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Keyword(0, 0)))
+        )
+    }
+
+    #[test]
+    fn unprefixed_line() {
+        assert_eq!(
+            read(&["OH", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Keyword(0, 0)))
+        )
+    }
+
+    #[test]
+    fn sgroup_unknown_index() {
+        assert_eq!(
+            read(&["M  STY  1   1 SUP", "M  SAL   2  1   1", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Index(1, 7)))
+        )
+    }
+
+    #[test]
+    fn sgroup_duplicate_index() {
+        assert_eq!(
+            read(&["M  STY  2   1 SUP   1 DAT", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Index(0, 18)))
+        )
+    }
+
+    #[test]
+    fn sgroup_unknown_type() {
+        assert_eq!(
+            read(&["M  STY  1   1 XYZ", "M  END"]),
+            Err(field("ctab.properties[0]", ErrorKind::Keyword(0, 14)))
+        )
+    }
+
+    #[test]
+    fn sgroup_missing_parent() {
+        assert_eq!(
+            read(&["M  STY  1   1 SUP", "M  SPL  1   1   2", "M  END"]),
+            Err(field("ctab.sgroups", ErrorKind::Index(1, 14)))
+        )
+    }
+
+    #[test]
+    fn sgroup_unterminated_data() {
+        assert_eq!(
+            read(&["M  STY  1   1 DAT", "M  SCD   1 abc", "M  END"]),
+            Err(field("ctab.sgroups", ErrorKind::Keyword(1, 3)))
+        )
+    }
+
+    #[test]
+    fn sgroup_too_many_atoms() {
+        assert_eq!(
+            read(&[
+                "M  STY  1   1 SUP",
+                "M  SAL   1 16   1   2   3   4   5   6   7   8   9  10  11  12  13  14  15  16",
+                "M  END"
+            ]),
+            Err(field("ctab.properties[0]", ErrorKind::Range(1, 12, 1, 15)))
+        )
+    }
+
+    #[test]
+    fn superatom() {
+        let (properties, sgroups) = read_sgroups(&[
+            "M  STY  1   1 SUP",
+            "M  SLB  1   1   1",
+            "M  SAL   1  2   2   3",
+            "M  SBL   1  1   1",
+            "M  SMT   1 CO2H",
+            "M  SDS EXP  1   1",
+            "M  SBV   1   1   -1.5000    0.0000",
+            "M  SAP   1  1   2   1 1 ",
+            "M  SCL   1 AA",
+            "M  END",
+        ])
+        .unwrap();
+        let mut superatom = Sgroup::new(natural(1), SgroupType::Superatom);
+
+        superatom.external_index = Some(natural(1));
+        superatom.atoms = vec![natural(2), natural(3)];
+        superatom.crossing_bonds = vec![natural(1)];
+        superatom.label = Text::from_str("CO2H");
+        superatom.expanded = true;
+        superatom.crossing_vectors = vec![CrossingVector {
+            bond: natural(1),
+            x: FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()],
+            ),
+            y: FixedReal::Integer(FixedInteger::Zero, vec![Digit::d0(); 4]),
+        }];
+        superatom.attachments = vec![SgroupAttachment {
+            atom: natural(2),
+            leaving_atom: Some(natural(1)),
+            id: Sequence::from_str("1 ").unwrap(),
+        }];
+        superatom.class = Text::from_str("AA");
+
+        assert_eq!(
+            (properties, sgroups),
+            (vec![Property::End], vec![superatom])
+        )
+    }
+
+    #[test]
+    fn polymer() {
+        let (_, sgroups) = read_sgroups(&[
+            "M  STY  2   1 SRU   2 MUL",
+            "M  SST  1   1 ALT",
+            "M  SCN  1   1 HT ",
+            "M  SMT   1 n",
+            "M  SMT   2 3",
+            "M  SPA   2  1   4",
+            "M  SDI   1  4    1.0000    2.0000    1.0000    3.0000",
+            "M  SPL  1   2   1",
+            "M  SNC  1   2   5",
+            "M  END",
+        ])
+        .unwrap();
+        let one = || {
+            FixedReal::Integer(
+                FixedInteger::from_int(1).unwrap(),
+                vec![Digit::d0(); 4],
+            )
+        };
+
+        assert_eq!(
+            (
+                sgroups[0].subtype,
+                sgroups[0].connectivity,
+                sgroups[0].label.clone(),
+                sgroups[1].multiplier.clone(),
+                sgroups[1].paradigmatic_atoms.clone(),
+                sgroups[1].parent.clone(),
+                sgroups[1].component.clone(),
+            ),
+            (
+                Some(SgroupSubtype::Alternating),
+                Some(Connectivity::HeadToTail),
+                Text::from_str("n"),
+                Some(natural(3)),
+                vec![natural(4)],
+                Some(natural(1)),
+                Some(natural(5)),
+            )
+        );
+        assert_eq!(
+            sgroups[0].brackets,
+            vec![Bracket {
+                x1: one(),
+                y1: FixedReal::Integer(
+                    FixedInteger::from_int(2).unwrap(),
+                    vec![Digit::d0(); 4]
+                ),
+                x2: one(),
+                y2: FixedReal::Integer(
+                    FixedInteger::from_int(3).unwrap(),
+                    vec![Digit::d0(); 4]
+                ),
+            }]
+        )
+    }
+
+    #[test]
+    fn data() {
+        let (_, sgroups) = read_sgroups(&[
+            "M  STY  1   1 DAT",
+            "M  SAL   1  1   1",
+            "M  SDT   1 pKa                           N                       <  ",
+            "M  SDD   1     0.0000    0.0000    DA    ALL  1       5",
+            "M  SCD   1 first part, ",
+            "M  SED   1 second part",
+            "M  SED   1 another",
+            "M  END",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            (
+                sgroups[0].field_name.clone(),
+                sgroups[0].field_type.clone(),
+                sgroups[0].field_info.clone(),
+                sgroups[0].query_type.clone(),
+                sgroups[0].query_operator.clone(),
+                sgroups[0].field_display.clone(),
+                sgroups[0].field_data.clone(),
+            ),
+            (
+                Text::from_str("pKa"),
+                Text::from_str("N"),
+                None,
+                None,
+                Text::from_str("<"),
+                Text::from_str("    0.0000    0.0000    DA    ALL  1       5"),
+                vec![
+                    Text::from_str("first part, second part").unwrap(),
+                    Text::from_str("another").unwrap()
+                ]
+            )
+        )
+    }
+    #[test]
+    fn lenient_end_trailing_space() {
+        let mut bytes = "M  END \t\n".bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.options.strictness = Strictness::Lenient;
+
+        assert_eq!(properties(&mut reader), Ok((vec![Property::End], vec![])));
+        assert_eq!(
+            reader.warnings,
+            vec![
+                Warning {
+                    row: 0,
+                    column: 7,
+                    kind: WarningKind::Tab
+                },
+                Warning {
+                    row: 0,
+                    column: 6,
+                    kind: WarningKind::TrailingWhitespace
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn lenient_end_trailing_text() {
+        let mut bytes = "M  END  x\n".bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.options.strictness = Strictness::Lenient;
+
+        assert_eq!(
+            properties(&mut reader),
+            Err(field("ctab.properties[0]", ErrorKind::Overflow(0, 8)))
+        )
+    }
+}
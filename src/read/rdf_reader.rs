@@ -0,0 +1,578 @@
+use std::io::BufRead;
+
+use crate::{
+    build::{self, Builder, Target},
+    primitive::{Literal, Natural, Text},
+    rdf::{DataType, Datum, DatumValue, Identifier, RdRecord, Structure},
+    text::Printable,
+};
+
+use super::{molfile, path::At, rxnfile, Error, ErrorKind, Reader, Warning};
+
+// ends after the first failed record, unlike SdfReader, which resumes at
+// the next $$$$; a failed record may leave no way to find the next $RFMT
+// or $MFMT
+pub struct RdfReader<'a> {
+    reader: Reader<'a>,
+    // $DATM
+    pub timestamp: Text,
+    keyword: Option<Keyword>,
+    index: usize,
+    failed: bool,
+}
+
+impl<'a> RdfReader<'a> {
+    pub fn new(iter: &'a mut dyn Iterator<Item = u8>) -> Result<Self, Error> {
+        Self::from_reader(Reader::new(iter))
+    }
+
+    pub fn from_read(read: impl BufRead + 'a) -> Result<Self, Error> {
+        Self::from_reader(Reader::from_read(read))
+    }
+
+    pub fn from_reader(mut reader: Reader<'a>) -> Result<Self, Error> {
+        reader.read_line(Target::Builder(Literal::start(b"$RDFILE 1")))?;
+        reader.read(Target::Builder(Literal::start(b"$DATM")))?;
+
+        let timestamp = reader.read(Target::Builder(Text::start()))?;
+        let start = timestamp
+            .printables()
+            .iter()
+            .position(|printable| printable != &Printable::Space)
+            .unwrap_or(timestamp.printables().len());
+
+        end_line(&mut reader)?;
+
+        Ok(Self {
+            reader,
+            timestamp: Text::from_printables(
+                timestamp.printables()[start..].to_vec(),
+            ),
+            keyword: None,
+            index: 0,
+            failed: false,
+        })
+    }
+}
+
+impl RdfReader<'_> {
+    pub fn warnings(&self) -> &[Warning] {
+        &self.reader.warnings
+    }
+}
+
+impl Iterator for RdfReader<'_> {
+    type Item = Result<RdRecord, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        let start = self
+            .keyword
+            .as_ref()
+            .map_or(self.reader.offset, |keyword| keyword.offset);
+        let keyword = match self.keyword.take() {
+            Some(keyword) => Ok(keyword),
+            None if self.reader.is_eof() => return None,
+            None => keyword(&mut self.reader),
+        };
+        let result = keyword
+            .and_then(|keyword| record(&mut self.reader, keyword))
+            .map(|(record, keyword)| {
+                self.keyword = keyword;
+
+                record
+            })
+            .map_err(|error| {
+                ErrorKind::Record(
+                    self.index,
+                    start..self.reader.offset,
+                    Box::new(error),
+                )
+                .into()
+            });
+
+        self.index += 1;
+        self.failed = result.is_err();
+
+        Some(result)
+    }
+}
+
+fn record(
+    reader: &mut Reader,
+    keyword: Keyword,
+) -> Result<(RdRecord, Option<Keyword>), Error> {
+    let (structure, identifier) = match keyword.bytes().as_slice() {
+        b"MFMT" => {
+            let identifier = identifier(reader, b"MIREG", b"MEREG")?;
+
+            (
+                Structure::Molecule(Some(Box::new(
+                    molfile(reader).at("structure")?,
+                ))),
+                identifier,
+            )
+        }
+        b"RFMT" => {
+            let identifier = identifier(reader, b"RIREG", b"REREG")?;
+
+            (
+                Structure::Reaction(Some(Box::new(
+                    rxnfile(reader).at("structure")?,
+                ))),
+                identifier,
+            )
+        }
+        b"MIREG" | b"RIREG" => {
+            let identifier = Identifier::Internal(
+                reader.read(Target::Builder(Natural::start()))?,
+            );
+
+            end_line(reader)?;
+
+            (reference(&keyword), Some(identifier))
+        }
+        b"MEREG" | b"REREG" => {
+            let identifier = Identifier::External(
+                reader.read(Target::Builder(Text::start()))?,
+            );
+
+            end_line(reader)?;
+
+            (reference(&keyword), Some(identifier))
+        }
+        _ => return Err(ErrorKind::Keyword(keyword.row, keyword.column).into()),
+    };
+    let mut data = Vec::new();
+
+    loop {
+        if reader.is_eof() {
+            break Ok((
+                RdRecord {
+                    structure,
+                    identifier,
+                    data,
+                },
+                None,
+            ));
+        }
+
+        let keyword = self::keyword(reader)?;
+
+        if keyword.bytes() != b"DTYPE" {
+            break Ok((
+                RdRecord {
+                    structure,
+                    identifier,
+                    data,
+                },
+                Some(keyword),
+            ));
+        }
+
+        data.push(datum(reader).at(data.len()).at("data")?);
+    }
+}
+
+fn reference(keyword: &Keyword) -> Structure {
+    if keyword.bytes().starts_with(b"M") {
+        Structure::Molecule(None)
+    } else {
+        Structure::Reaction(None)
+    }
+}
+
+fn identifier(
+    reader: &mut Reader,
+    internal: &[u8],
+    external: &[u8],
+) -> Result<Option<Identifier>, Error> {
+    if reader.has_blank() || reader.is_eof() {
+        end_line(reader)?;
+
+        return Ok(None);
+    }
+
+    let keyword = keyword(reader)?;
+    let bytes = keyword.bytes();
+    let result = if bytes == internal {
+        Identifier::Internal(reader.read(Target::Builder(Natural::start()))?)
+    } else if bytes == external {
+        Identifier::External(reader.read(Target::Builder(Text::start()))?)
+    } else {
+        return Err(ErrorKind::Keyword(keyword.row, keyword.column).into());
+    };
+
+    end_line(reader)?;
+
+    Ok(Some(result))
+}
+
+fn datum(reader: &mut Reader) -> Result<Datum, Error> {
+    let data_type = reader.read(Target::Builder(DataType::start()))?;
+
+    end_line(reader)?;
+
+    let keyword = keyword(reader)?;
+
+    if keyword.bytes() != b"DATUM" {
+        return Err(ErrorKind::Keyword(keyword.row, keyword.column).into());
+    }
+
+    if reader.has(Printable::Dollar) {
+        let keyword = self::keyword(reader)?;
+
+        if keyword.bytes() != b"MFMT" {
+            return Err(ErrorKind::Keyword(keyword.row, keyword.column).into());
+        }
+
+        end_line(reader)?;
+
+        return Ok(Datum {
+            data_type,
+            value: DatumValue::Molfile(Box::new(molfile(reader)?)),
+        });
+    }
+
+    let mut lines = vec![reader.read(Target::Builder(Text::start()))?];
+
+    end_line(reader)?;
+
+    while !reader.is_eof() && !reader.has(Printable::Dollar) {
+        lines.push(reader.read(Target::Builder(Text::start()))?);
+        end_line(reader)?;
+    }
+
+    Ok(Datum {
+        data_type,
+        value: DatumValue::Text(lines),
+    })
+}
+
+fn end_line(reader: &mut Reader) -> Result<(), Error> {
+    if reader.is_eof() {
+        Ok(())
+    } else {
+        reader.next_line()
+    }
+}
+
+fn keyword(reader: &mut Reader) -> Result<Keyword, Error> {
+    let row = reader.row;
+    let column = reader.column;
+    let offset = reader.offset;
+    let printables = reader.read(Target::Builder(KeywordBuilder::Dollar))?;
+
+    Ok(Keyword {
+        row,
+        column,
+        offset,
+        printables,
+    })
+}
+
+// $ and capital letters, ended by a space or the end of the line
+struct Keyword {
+    row: usize,
+    column: usize,
+    offset: usize,
+    printables: Vec<Printable>,
+}
+
+impl Keyword {
+    fn bytes(&self) -> Vec<u8> {
+        self.printables.iter().map(Printable::to_byte).collect()
+    }
+}
+
+enum KeywordBuilder {
+    Dollar,
+    Letters(Vec<Printable>),
+}
+
+impl Builder for KeywordBuilder {
+    type Product = Vec<Printable>;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, build::Error> {
+        match self {
+            Self::Dollar => {
+                if printable == Printable::Dollar {
+                    Ok(Target::Builder(Self::Letters(vec![])))
+                } else {
+                    Err(build::Error::Character(vec![Printable::Dollar]))
+                }
+            }
+            Self::Letters(mut letters) => {
+                let byte = printable.to_byte();
+
+                if byte.is_ascii_uppercase() {
+                    letters.push(printable);
+
+                    Ok(Target::Builder(Self::Letters(letters)))
+                } else if printable == Printable::Space && !letters.is_empty() {
+                    Ok(Target::Product(letters))
+                } else {
+                    Err(build::Error::Character(
+                        (b'A'..=b'Z')
+                            .filter_map(Printable::from_byte)
+                            .collect(),
+                    ))
+                }
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        match self {
+            Self::Letters(letters) if !letters.is_empty() => Some(letters),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rdf::Field;
+    use crate::read::{path, ReadOptions, Strictness, WarningKind};
+    use pretty_assertions::assert_eq;
+
+    const MOLFILE: [&str; 5] = [
+        "",
+        "",
+        "",
+        "  0  0  0  0  0  0            999 V2000",
+        "M  END",
+    ];
+
+    fn read(lines: &[&str]) -> Result<Vec<Result<RdRecord, Error>>, Error> {
+        let text = [&["$RDFILE 1", "$DATM    01/01/23 12:34"], lines]
+            .concat()
+            .join("\n")
+            + "\n";
+        let mut bytes = text.bytes();
+        let reader = RdfReader::new(&mut bytes)?;
+
+        Ok(reader.collect())
+    }
+
+    fn field(name: &str, index: Option<u32>) -> Field {
+        Field {
+            name: Text::from_str(name).unwrap(),
+            index: index.and_then(Natural::from_int),
+        }
+    }
+
+    fn text(lines: &[&str]) -> DatumValue {
+        DatumValue::Text(
+            lines
+                .iter()
+                .map(|line| Text::from_str(line).unwrap())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn timestamp() {
+        let mut bytes = "$RDFILE 1\n$DATM    01/01/23 12:34\n".bytes();
+
+        assert_eq!(
+            RdfReader::new(&mut bytes).map(|reader| reader.timestamp),
+            Ok(Text::from_str("01/01/23 12:34").unwrap())
+        )
+    }
+
+    #[test]
+    fn bad_version() {
+        let mut bytes = "$RDFILE 2\n".bytes();
+
+        assert_eq!(
+            RdfReader::new(&mut bytes).err(),
+            Some(ErrorKind::Character(0, 8, vec![Printable::D1]).into())
+        )
+    }
+
+    #[test]
+    fn lenient_warnings() {
+        let text = [
+            &["$RDFILE 1", "$DATM    01/01/23 12:34", "$MFMT"],
+            &MOLFILE[..4],
+            &["M  END  ", ""],
+        ]
+        .concat()
+        .join("\n");
+        let mut bytes = text.bytes();
+        let options = ReadOptions {
+            strictness: Strictness::Lenient,
+        };
+        let mut reader = RdfReader::from_reader(
+            Reader::new(&mut bytes).with_options(options),
+        )
+        .unwrap();
+
+        assert!(matches!(reader.next(), Some(Ok(_))));
+        assert_eq!(
+            reader.warnings(),
+            &[Warning {
+                row: 7,
+                column: 6,
+                kind: WarningKind::TrailingWhitespace
+            }]
+        )
+    }
+
+    #[test]
+    fn no_records() {
+        assert_eq!(read(&[]), Ok(vec![]))
+    }
+
+    #[test]
+    fn references() {
+        assert_eq!(
+            read(&["$MIREG 12", "$REREG CT-0001"]),
+            Ok(vec![
+                Ok(RdRecord {
+                    structure: Structure::Molecule(None),
+                    identifier: Some(Identifier::Internal(
+                        Natural::from_int(12).unwrap()
+                    )),
+                    data: vec![]
+                }),
+                Ok(RdRecord {
+                    structure: Structure::Reaction(None),
+                    identifier: Some(Identifier::External(
+                        Text::from_str("CT-0001").unwrap()
+                    )),
+                    data: vec![]
+                })
+            ])
+        )
+    }
+
+    #[test]
+    fn molecule_with_data() {
+        let records = read(
+            &[
+                &["$MFMT $MIREG 7"][..],
+                &MOLFILE,
+                &[
+                    "$DTYPE RXN:VARIATION(1):STEPNO",
+                    "$DATUM 1",
+                    "$DTYPE NOTES",
+                    "$DATUM first line",
+                    "second line",
+                    "",
+                ],
+            ]
+            .concat(),
+        )
+        .unwrap();
+        let record = records[0].as_ref().unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert!(matches!(record.structure, Structure::Molecule(Some(_))));
+        assert_eq!(
+            record.identifier,
+            Some(Identifier::Internal(Natural::from_int(7).unwrap()))
+        );
+        assert_eq!(
+            record.data,
+            vec![
+                Datum {
+                    data_type: DataType::new(vec![
+                        field("RXN", None),
+                        field("VARIATION", Some(1)),
+                        field("STEPNO", None)
+                    ])
+                    .unwrap(),
+                    value: text(&["1"])
+                },
+                Datum {
+                    data_type: DataType::new(vec![field("NOTES", None)])
+                        .unwrap(),
+                    value: text(&["first line", "second line", ""])
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn nested_molfile() {
+        let records = read(
+            &[
+                &["$RFMT", "$RXN", "", "", "", "  0  0"][..],
+                &["$DTYPE RXN:REACTANT(1):MOL(1):MOLSTRUCTURE", "$DATUM $MFMT"],
+                &MOLFILE,
+                &["$DTYPE RXN:REACTANT(1):MOL(1):NAME", "$DATUM water"],
+                &["$MFMT"],
+                &MOLFILE,
+            ]
+            .concat(),
+        )
+        .unwrap();
+        let record = records[0].as_ref().unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(record.structure, Structure::Reaction(Some(_))));
+        assert_eq!(record.identifier, None);
+        assert!(matches!(record.data[0].value, DatumValue::Molfile(_)));
+        assert_eq!(record.data[1].value, text(&["water"]));
+        assert!(records[1].is_ok())
+    }
+
+    #[test]
+    fn missing_datum() {
+        assert_eq!(
+            read(
+                &[&["$MFMT"][..], &MOLFILE, &["$DTYPE NAME", "$MFMT"]].concat()
+            ),
+            Ok(vec![Err(ErrorKind::Record(
+                0,
+                34..107,
+                Box::new(path::field("data[0]", ErrorKind::Keyword(9, 0)))
+            )
+            .into())])
+        )
+    }
+
+    #[test]
+    fn unknown_record() {
+        assert_eq!(
+            read(&["$MIREG 1", "$DATM"]),
+            Ok(vec![
+                Ok(RdRecord {
+                    structure: Structure::Molecule(None),
+                    identifier: Some(Identifier::Internal(
+                        Natural::from_int(1).unwrap()
+                    )),
+                    data: vec![]
+                }),
+                Err(ErrorKind::Record(
+                    1,
+                    43..48,
+                    Box::new(ErrorKind::Keyword(3, 0).into())
+                )
+                .into())
+            ])
+        )
+    }
+
+    #[test]
+    fn wrong_identifier() {
+        assert_eq!(
+            read(&["$MFMT $RIREG 1"]),
+            Ok(vec![Err(ErrorKind::Record(
+                0,
+                34..47,
+                Box::new(ErrorKind::Keyword(2, 6).into())
+            )
+            .into())])
+        )
+    }
+}
@@ -1,27 +1,91 @@
-use std::iter::Peekable;
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+    iter::Peekable,
+};
 
 use crate::{
     build::{self, Builder, Target},
-    text::{Character, Eol},
+    text::{Character, Eol, Printable},
 };
 
-use super::Error;
+use super::{Error, ErrorKind, ReadOptions, Strictness, Warning, WarningKind};
 
 pub struct Reader<'a> {
-    iter: Peekable<&'a mut dyn Iterator<Item = u8>>,
+    source: Source<'a>,
     pub row: usize,
     pub column: usize,
+    // bytes consumed
+    pub offset: usize,
+    pub options: ReadOptions,
+    // repairs made by a lenient reader so far
+    pub warnings: Vec<Warning>,
+    capture: Option<Capture>,
+    // just past the first $$$$ line captured
+    delimiter: Option<Mark>,
+    // bytes given back by rewind, read before the source
+    replay: VecDeque<u8>,
+    // I/O error met by a query, returned by the next read
+    pending: Option<Error>,
+}
+
+enum Capture {
+    All(Vec<u8>),
+    // dropped at each line feed until a $$$$ line
+    Line(Vec<u8>),
+}
+
+struct Mark {
+    offset: usize,
+    row: usize,
+    // captured bytes up to the mark
+    captured: usize,
+    warnings: usize,
+}
+
+enum Source<'a> {
+    Iter(Peekable<&'a mut dyn Iterator<Item = u8>>),
+    Read(Box<dyn BufRead + 'a>),
 }
 
 impl<'a> Reader<'a> {
     pub fn new(iter: &'a mut dyn Iterator<Item = u8>) -> Self {
         Self {
-            iter: iter.peekable(),
+            source: Source::Iter(iter.peekable()),
+            row: 0,
+            column: 0,
+            offset: 0,
+            options: ReadOptions::default(),
+            warnings: Vec::new(),
+            capture: None,
+            delimiter: None,
+            replay: VecDeque::new(),
+            pending: None,
+        }
+    }
+
+    pub fn from_read(read: impl BufRead + 'a) -> Self {
+        Self {
+            source: Source::Read(Box::new(read)),
             row: 0,
             column: 0,
+            offset: 0,
+            options: ReadOptions::default(),
+            warnings: Vec::new(),
+            capture: None,
+            delimiter: None,
+            replay: VecDeque::new(),
+            pending: None,
         }
     }
 
+    // SdfReader::from_reader and RdfReader::from_reader read with these
+    pub fn with_options(mut self, options: ReadOptions) -> Self {
+        self.options = options;
+
+        self
+    }
+
     pub fn read_line<P, B: Builder<Product = P>>(
         &mut self,
         target: Target<P, B>,
@@ -36,43 +100,73 @@ impl<'a> Reader<'a> {
         &mut self,
         mut target: Target<P, B>,
     ) -> Result<P, Error> {
+        let start = self.column;
+
         loop {
             target = match target {
-                Target::Builder(builder) => match self.iter.peek() {
-                    Some(byte) => match Character::from_byte(*byte) {
-                        Character::Eol(_) => match builder.done() {
-                            Some(product) => break Ok(product),
-                            None => break Err(Error::Eol(self.row)),
+                Target::Builder(builder) => match self.peek()? {
+                    Some(byte) => match self.character(byte) {
+                        Character::Eol(_) => match builder.finish() {
+                            Ok(product) => break Ok(product),
+                            Err(build::Error::End) if self.column == start => {
+                                break Err(ErrorKind::Eol(self.row).into())
+                            }
+                            // line ended partway through the field
+                            Err(error) => {
+                                break Err(Error::from_build(
+                                    self.row,
+                                    self.column,
+                                    error,
+                                ))
+                            }
                         },
                         Character::Printable(printable) => {
                             match builder.push(printable) {
                                 Ok(next) => {
+                                    if byte == b'\t' {
+                                        self.warn(
+                                            self.row,
+                                            self.column,
+                                            WarningKind::Tab,
+                                        );
+                                    }
+
                                     self.column += 1;
 
-                                    self.iter.next();
+                                    self.advance(byte);
 
                                     next
                                 }
-                                Err(build::Error::Character(allowed)) => {
-                                    break Err(Error::Character(
+                                Err(error) => {
+                                    break Err(Error::from_build(
                                         self.row,
                                         self.column,
-                                        allowed,
+                                        error,
                                     ))
                                 }
                             }
                         }
                         Character::Unprintable(byte) => {
-                            break Err(Error::Unprintable(
+                            break Err(ErrorKind::Unprintable(
                                 self.row,
                                 self.column,
                                 byte,
-                            ))
+                            )
+                            .into())
                         }
                     },
-                    None => match builder.done() {
-                        Some(product) => break Ok(product),
-                        None => break Err(Error::Eof(self.row)),
+                    None => match builder.finish() {
+                        Ok(product) => break Ok(product),
+                        Err(build::Error::End) => {
+                            break Err(ErrorKind::Eof(self.row).into())
+                        }
+                        Err(error) => {
+                            break Err(Error::from_build(
+                                self.row,
+                                self.column,
+                                error,
+                            ))
+                        }
                     },
                 },
                 Target::Product(product) => break Ok(product),
@@ -81,56 +175,561 @@ impl<'a> Reader<'a> {
     }
 
     pub fn next_line(&mut self) -> Result<(), Error> {
-        let next = match self.iter.peek() {
-            Some(byte) => Character::from_byte(*byte),
-            None => return Err(Error::Eof(self.row)),
+        if self.is_lenient() {
+            self.trailing_whitespace()?;
+        }
+
+        let (byte, next) = match self.peek()? {
+            Some(byte) => (byte, Character::from_byte(byte)),
+            None => return Err(ErrorKind::Eof(self.row).into()),
         };
+        let delimiter = self.delimiter.is_none()
+            && self.captured_line().starts_with(b"$$$$");
 
         match next {
             Character::Eol(eol) => match eol {
                 Eol::Cr => {
-                    self.iter.next();
+                    self.advance(byte);
 
-                    if let Some(byte) = self.iter.peek() {
-                        if Character::from_byte(*byte).is_lf() {
-                            self.iter.next();
+                    if let Some(byte) = self.peek()? {
+                        if Character::from_byte(byte).is_lf() {
+                            self.advance(byte);
                         }
                     }
                 }
                 Eol::Lf => {
-                    self.iter.next();
+                    self.advance(byte);
 
-                    if let Some(byte) = self.iter.peek() {
-                        if Character::from_byte(*byte).is_cr() {
-                            self.iter.next();
+                    if let Some(byte) = self.peek()? {
+                        if Character::from_byte(byte).is_cr() {
+                            self.advance(byte);
                         }
                     }
                 }
                 Eol::Rs => {
-                    self.iter.next();
+                    self.advance(byte);
                 }
             },
             Character::Printable(_) => {
-                return Err(Error::Overflow(self.row, self.column))
+                return Err(ErrorKind::Overflow(self.row, self.column).into())
             }
             Character::Unprintable(byte) => {
-                return Err(Error::Unprintable(self.row, self.column, byte))
+                return Err(
+                    ErrorKind::Unprintable(self.row, self.column, byte).into()
+                )
             }
         }
 
         self.row += 1;
         self.column = 0;
 
+        if delimiter {
+            self.delimiter = Some(Mark {
+                offset: self.offset,
+                row: self.row,
+                captured: match &self.capture {
+                    Some(Capture::All(capture) | Capture::Line(capture)) => {
+                        capture.len()
+                    }
+                    None => 0,
+                },
+                warnings: self.warnings.len(),
+            })
+        }
+
         Ok(())
     }
 
+    // consumes the rest of the line whatever it holds, returning its bytes
+    pub(crate) fn skip_line(&mut self) -> Result<Vec<u8>, Error> {
+        let mut result = Vec::new();
+
+        while let Some(byte) = self.peek()? {
+            if let Character::Eol(_) = Character::from_byte(byte) {
+                return self.next_line().map(|_| result);
+            }
+
+            result.push(byte);
+            self.column += 1;
+
+            self.advance(byte);
+        }
+
+        Ok(result)
+    }
+
+    pub(crate) fn capture(&mut self) {
+        self.capture = Some(Capture::All(Vec::new()));
+        self.delimiter = None;
+    }
+
+    // keeps only the current line for captured_line
+    pub(crate) fn capture_line(&mut self) {
+        self.capture = Some(Capture::Line(Vec::new()));
+        self.delimiter = None;
+    }
+
+    // bytes of the current line consumed since capture began
+    pub(crate) fn captured_line(&self) -> Vec<u8> {
+        match &self.capture {
+            Some(Capture::All(capture) | Capture::Line(capture)) => capture
+                [capture.len() - self.column.min(capture.len())..]
+                .to_vec(),
+            None => Vec::new(),
+        }
+    }
+
+    // returns to just past the first $$$$ line captured, if any, reading
+    // the bytes after it again
+    pub(crate) fn rewind(&mut self) -> bool {
+        let (mark, capture) = match (self.delimiter.take(), &mut self.capture) {
+            (
+                Some(mark),
+                Some(Capture::All(capture) | Capture::Line(capture)),
+            ) => (mark, capture),
+            _ => return false,
+        };
+
+        for byte in capture.drain(mark.captured..).rev() {
+            self.replay.push_front(byte);
+        }
+
+        self.offset = mark.offset;
+        self.row = mark.row;
+        self.column = 0;
+        self.warnings.truncate(mark.warnings);
+
+        true
+    }
+
+    // bytes consumed since capture began
+    pub(crate) fn captured(&mut self) -> Vec<u8> {
+        self.delimiter = None;
+
+        match self.capture.take() {
+            Some(Capture::All(capture) | Capture::Line(capture)) => capture,
+            None => Vec::new(),
+        }
+    }
+
+    // I/O errors answer false and are returned by the next read
     pub fn has_blank(&mut self) -> bool {
-        match self.iter.peek() {
-            Some(byte) => match Character::from_byte(*byte) {
-                Character::Eol(_) => true,
-                _ => false,
+        match self.peek() {
+            Ok(Some(byte)) => {
+                matches!(Character::from_byte(byte), Character::Eol(_))
+            }
+            Ok(None) => false,
+            Err(error) => self.defer(error),
+        }
+    }
+
+    pub fn has(&mut self, printable: Printable) -> bool {
+        match self.peek() {
+            Ok(Some(byte)) => {
+                self.character(byte) == Character::Printable(printable)
+            }
+            Ok(None) => false,
+            Err(error) => self.defer(error),
+        }
+    }
+
+    pub fn is_eof(&mut self) -> bool {
+        match self.peek() {
+            Ok(byte) => byte.is_none(),
+            Err(error) => self.defer(error),
+        }
+    }
+
+    pub(crate) fn is_lenient(&self) -> bool {
+        self.options.strictness == Strictness::Lenient
+    }
+
+    pub(crate) fn warn(
+        &mut self,
+        row: usize,
+        column: usize,
+        kind: WarningKind,
+    ) {
+        self.warnings.push(Warning { row, column, kind })
+    }
+
+    // lenient readers take a tab for a space
+    fn character(&self, byte: u8) -> Character {
+        match Character::from_byte(byte) {
+            Character::Unprintable(b'\t') if self.is_lenient() => {
+                Character::Printable(Printable::Space)
+            }
+            character => character,
+        }
+    }
+
+    // lenient readers skip spaces and tabs before a line break
+    fn trailing_whitespace(&mut self) -> Result<(), Error> {
+        let (row, column) = (self.row, self.column);
+
+        while let Some(byte) = self.peek()? {
+            match self.character(byte) {
+                Character::Printable(Printable::Space) => {
+                    if byte == b'\t' {
+                        self.warn(self.row, self.column, WarningKind::Tab);
+                    }
+
+                    self.column += 1;
+                    self.advance(byte);
+                }
+                _ => break,
+            }
+        }
+
+        if self.column > column {
+            self.warn(row, column, WarningKind::TrailingWhitespace);
+        }
+
+        Ok(())
+    }
+
+    fn defer(&mut self, error: Error) -> bool {
+        if let ErrorKind::Io(..) = error.kind {
+            self.pending = Some(error)
+        }
+
+        false
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, Error> {
+        if let Some(error) = self.pending.take() {
+            return Err(error);
+        }
+
+        if let Some(byte) = self.replay.front() {
+            return Ok(Some(*byte));
+        }
+
+        match &mut self.source {
+            Source::Iter(iter) => Ok(iter.peek().copied()),
+            Source::Read(read) => loop {
+                match read.fill_buf() {
+                    Ok(buffer) => break Ok(buffer.first().copied()),
+                    Err(error)
+                        if error.kind() == io::ErrorKind::Interrupted => {}
+                    Err(error) => break Err(error.into()),
+                }
             },
-            None => false,
         }
     }
+
+    fn advance(&mut self, byte: u8) {
+        if self.replay.pop_front().is_none() {
+            match &mut self.source {
+                Source::Iter(iter) => {
+                    iter.next();
+                }
+                Source::Read(read) => read.consume(1),
+            }
+        }
+
+        self.offset += 1;
+
+        match &mut self.capture {
+            Some(Capture::All(capture)) => capture.push(byte),
+            Some(Capture::Line(capture))
+                if self.delimiter.is_none()
+                    && matches!(
+                        Character::from_byte(byte),
+                        Character::Eol(_)
+                    ) =>
+            {
+                capture.clear()
+            }
+            Some(Capture::Line(capture)) => capture.push(byte),
+            None => (),
+        }
+    }
+}
+
+// test source failing its first read, then yielding rest
+#[cfg(test)]
+pub(crate) fn fail_once(rest: &'static [u8]) -> impl io::Read {
+    struct FailOnce(Option<&'static [u8]>);
+
+    impl io::Read for FailOnce {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            match &mut self.0 {
+                Some(bytes) => bytes.read(buffer),
+                None => {
+                    self.0 = Some(b"");
+
+                    Err(io::Error::new(io::ErrorKind::InvalidData, "bad byte"))
+                }
+            }
+        }
+    }
+
+    io::Read::chain(FailOnce(None), rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive::{Line, Literal};
+    use pretty_assertions::assert_eq;
+
+    struct Failing;
+
+    impl io::Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::InvalidData, "bad byte"))
+        }
+    }
+
+    #[test]
+    fn from_read() {
+        let mut bytes = &b"ab\ncd"[..];
+        let mut reader = Reader::from_read(&mut bytes);
+
+        assert_eq!(
+            reader.read_line(Target::Builder(Line::<2>::start())),
+            Ok(Line::from_str("ab").unwrap())
+        );
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"cd"))),
+            Ok(Literal)
+        );
+        assert_eq!((reader.row, reader.column, reader.is_eof()), (1, 2, true))
+    }
+
+    #[test]
+    fn from_read_chunks() {
+        let bytes = io::Read::chain(&b"a"[..], &b"\r\nb"[..]);
+        let mut buffer = io::BufReader::with_capacity(1, bytes);
+        let mut reader = Reader::from_read(&mut buffer);
+
+        reader
+            .read_line(Target::Builder(Literal::start(b"a")))
+            .unwrap();
+
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"b"))),
+            Ok(Literal)
+        );
+        assert_eq!(reader.row, 1)
+    }
+
+    #[test]
+    fn io_error() {
+        let mut buffer = io::BufReader::new(Failing);
+        let mut reader = Reader::from_read(&mut buffer);
+
+        assert!(!reader.is_eof());
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"a"))),
+            Err(ErrorKind::Io(io::ErrorKind::InvalidData, "bad byte".into())
+                .into())
+        )
+    }
+
+    #[test]
+    fn pending_io_error() {
+        let mut reader = Reader::from_read(io::BufReader::new(fail_once(b"a")));
+
+        assert!(!reader.has(Printable::A));
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"a"))),
+            Err(ErrorKind::Io(io::ErrorKind::InvalidData, "bad byte".into())
+                .into())
+        );
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"a"))),
+            Ok(Literal)
+        )
+    }
+
+    #[test]
+    fn partial_field_at_eol() {
+        let mut bytes = b"ab\n".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"abc"))),
+            Err(ErrorKind::End(0, 2).into())
+        )
+    }
+
+    #[test]
+    fn empty_field_at_eol() {
+        let mut bytes = b"\n".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            reader.read(Target::Builder(Literal::start(b"abc"))),
+            Err(ErrorKind::Eol(0).into())
+        )
+    }
+    #[test]
+    fn strict_tab() {
+        let mut bytes = b"a\tb".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            reader.read(Target::Builder(Line::<3>::start())),
+            Err(ErrorKind::Unprintable(0, 1, b'\t').into())
+        )
+    }
+
+    #[test]
+    fn lenient_tab() {
+        let mut bytes = b"a\tb".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.options.strictness = Strictness::Lenient;
+
+        assert_eq!(
+            reader.read(Target::Builder(Line::<3>::start())),
+            Ok(Line::from_str("a b").unwrap())
+        );
+        assert_eq!(
+            reader.warnings,
+            vec![Warning {
+                row: 0,
+                column: 1,
+                kind: WarningKind::Tab
+            }]
+        )
+    }
+    #[test]
+    fn offset() {
+        let mut bytes = b"ab\r\ncd".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader
+            .read_line(Target::Builder(Literal::start(b"ab")))
+            .unwrap();
+
+        assert_eq!(reader.offset, 4)
+    }
+
+    #[test]
+    fn skip_line() {
+        let mut bytes = b"a\x01b\ncd".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture();
+
+        assert_eq!(reader.skip_line(), Ok(b"a\x01b".to_vec()));
+        assert_eq!((reader.row, reader.column), (1, 0));
+        assert_eq!(reader.skip_line(), Ok(b"cd".to_vec()));
+        assert_eq!(reader.captured(), b"a\x01b\ncd".to_vec())
+    }
+
+    #[test]
+    fn captured_line() {
+        let mut bytes = b"ab\ncd".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture();
+        reader
+            .read_line(Target::Builder(Literal::start(b"ab")))
+            .unwrap();
+        reader.read(Target::Builder(Literal::start(b"c"))).unwrap();
+
+        assert_eq!(reader.captured_line(), b"c".to_vec())
+    }
+
+    #[test]
+    fn rewind() {
+        let mut bytes = b"a\n$$$$\nbc\n".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture_line();
+        reader.skip_line().unwrap();
+        reader.skip_line().unwrap();
+        reader.read(Target::Builder(Literal::start(b"b"))).unwrap();
+
+        assert!(reader.rewind());
+        assert_eq!((reader.row, reader.column, reader.offset), (2, 0, 7));
+        assert_eq!(
+            reader.read_line(Target::Builder(Literal::start(b"bc"))),
+            Ok(Literal)
+        );
+        assert!(reader.is_eof())
+    }
+
+    #[test]
+    fn rewind_without_delimiter() {
+        let mut bytes = b"a\nb".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture();
+        reader.skip_line().unwrap();
+
+        assert!(!reader.rewind())
+    }
+
+    #[test]
+    fn capture_line() {
+        let mut bytes = b"ab\ncd".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture_line();
+        reader
+            .read_line(Target::Builder(Literal::start(b"ab")))
+            .unwrap();
+        reader.read(Target::Builder(Literal::start(b"c"))).unwrap();
+
+        assert_eq!(reader.captured_line(), b"c".to_vec());
+        assert_eq!(reader.captured(), b"c".to_vec())
+    }
+
+    #[test]
+    fn capture_line_cr() {
+        let mut bytes = b"ab\rcd\x1eef".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        reader.capture_line();
+        reader.skip_line().unwrap();
+        reader.skip_line().unwrap();
+        reader.read(Target::Builder(Literal::start(b"e"))).unwrap();
+
+        assert_eq!(reader.captured(), b"e".to_vec())
+    }
+
+    #[test]
+    fn lenient_tab_before_line_end() {
+        let mut bytes = b"a \t\nb".iter().cloned();
+        let mut reader = Reader::new(&mut bytes).with_options(ReadOptions {
+            strictness: Strictness::Lenient,
+        });
+
+        reader
+            .read_line(Target::Builder(Literal::start(b"a")))
+            .unwrap();
+
+        assert_eq!(reader.row, 1);
+        assert_eq!(
+            reader.warnings,
+            vec![
+                Warning {
+                    row: 0,
+                    column: 2,
+                    kind: WarningKind::Tab
+                },
+                Warning {
+                    row: 0,
+                    column: 1,
+                    kind: WarningKind::TrailingWhitespace
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn strict_tab_before_line_end() {
+        let mut bytes = b"a\t\nb".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            reader.read_line(Target::Builder(Literal::start(b"a"))),
+            Err(ErrorKind::Unprintable(0, 1, b'\t').into())
+        )
+    }
 }
@@ -0,0 +1,562 @@
+use crate::{
+    build::{Builder, Target},
+    molfile::{Header, Property, Version},
+    primitive::{Line, Literal, Natural, Text},
+    rgroup::{Occurrence, Rgroup, RgroupQuery},
+    text::Printable,
+};
+
+use super::{
+    header,
+    header::{blank, blank_v3000, counts, header_block},
+    molfile::ctab,
+    path::At,
+    v3000, Error, ErrorKind, Reader,
+};
+
+pub fn rgfile(reader: &mut Reader) -> Result<RgroupQuery, Error> {
+    if reader.has(Printable::Dollar) {
+        v2000(reader)
+    } else {
+        v3000(reader)
+    }
+}
+
+// checks an M  LOG occurrence read as a line starting at row, column
+pub(super) fn occurrence(
+    line: &Line<80>,
+    row: usize,
+    column: usize,
+) -> Result<Vec<Occurrence>, Error> {
+    let mut builder = Occurrence::start_list();
+
+    for (index, printable) in line.printables().iter().enumerate() {
+        builder = match builder.push(*printable) {
+            Ok(Target::Builder(builder)) => builder,
+            Ok(Target::Product(product)) => return Ok(product),
+            Err(error) => {
+                return Err(Error::from_build(row, column + index, error))
+            }
+        }
+    }
+
+    builder.done().ok_or(ErrorKind::Eol(row).into())
+}
+
+fn v2000(reader: &mut Reader) -> Result<RgroupQuery, Error> {
+    // $MDL  REV  1 date/time
+    reader.read(Target::Builder(Literal::start(b"$MDL")))?;
+    reader.read_line(Target::Builder(Text::start()))?;
+    reader.read_line(Target::Builder(Literal::start(b"$MOL")))?;
+    reader.read_line(Target::Builder(Literal::start(b"$HDR")))?;
+
+    let (molecule_name, parameters, comment) = header_block(reader)?;
+
+    reader.read_line(Target::Builder(Literal::start(b"$END HDR")))?;
+    reader.read_line(Target::Builder(Literal::start(b"$CTAB")))?;
+
+    let counts = counts(reader)?;
+    let root = ctab(
+        reader,
+        Header {
+            molecule_name,
+            parameters,
+            comment,
+            counts,
+        },
+    )?;
+
+    reader.read_line(Target::Builder(Literal::start(b"$END CTAB")))?;
+
+    let mut rgroups = Vec::new();
+
+    loop {
+        reader.read(Target::Builder(Literal::start(b"$")))?;
+
+        if reader.has(Printable::R) {
+            reader.read_line(Target::Builder(Literal::start(b"RGP")))?;
+
+            let index = rgroups.len();
+            let number =
+                rgroup_number(reader).at("number").at(index).at("rgroups")?;
+            let mut rgroup = logic(&root.properties, number);
+
+            while !rgp_member(reader, &mut rgroup)
+                .at(rgroup.members.len())
+                .at("members")
+                .at(index)
+                .at("rgroups")?
+            {}
+
+            rgroups.push(rgroup);
+        } else {
+            reader.read(Target::Builder(Literal::start(b"END MOL")))?;
+
+            if !reader.is_eof() {
+                reader.next_line()?;
+            }
+
+            break Ok(RgroupQuery { root, rgroups });
+        }
+    }
+}
+
+fn rgroup_number(reader: &mut Reader) -> Result<Natural, Error> {
+    while reader.has(Printable::Space) {
+        reader.read(Target::Builder(Literal::start(b" ")))?;
+    }
+
+    reader.read_line(Target::Builder(Natural::start()))
+}
+
+// reads a $CTAB member or $END RGP, returning true on the latter
+fn rgp_member(reader: &mut Reader, rgroup: &mut Rgroup) -> Result<bool, Error> {
+    reader.read(Target::Builder(Literal::start(b"$")))?;
+
+    if reader.has(Printable::C) {
+        reader.read_line(Target::Builder(Literal::start(b"CTAB")))?;
+
+        let counts = counts(reader)?;
+
+        rgroup.members.push(ctab(reader, blank(counts))?);
+        reader.read_line(Target::Builder(Literal::start(b"$END CTAB")))?;
+
+        Ok(false)
+    } else {
+        reader.read_line(Target::Builder(Literal::start(b"END RGP")))?;
+
+        Ok(true)
+    }
+}
+
+fn logic(properties: &[Property], number: Natural) -> Rgroup {
+    let mut result = Rgroup {
+        number,
+        members: vec![],
+        occurrence: vec![],
+        rest_h: false,
+        if_then: None,
+    };
+
+    for property in properties {
+        if let Property::RgroupLogic {
+            rgroup,
+            if_then,
+            rest_h,
+            occurrence: line,
+        } = property
+        {
+            if rgroup == &result.number {
+                result.if_then = Natural::from_int(if_then.to_int());
+                result.rest_h = rest_h.to_int() == 1;
+                // checked by the properties reader
+                result.occurrence = occurrence(line, 0, 0).expect("occurrence");
+            }
+        }
+    }
+
+    result
+}
+
+fn v3000(reader: &mut Reader) -> Result<RgroupQuery, Error> {
+    let header = header(reader)?;
+
+    if header.counts.version == Version::V2 {
+        return Err(ErrorKind::Character(
+            reader.row - 1,
+            35,
+            vec![Printable::D3],
+        )
+        .into());
+    }
+
+    let mut root = v3000::ctab(reader, header)?;
+    let mut rgroups = Vec::new();
+
+    loop {
+        let row = reader.row;
+        let key = reader.read(Target::Builder(Line::<6>::start()))?;
+        let bytes = key
+            .printables()
+            .iter()
+            .map(Printable::to_byte)
+            .collect::<Vec<_>>();
+
+        match bytes.as_slice() {
+            b"M  END" => {
+                if !reader.is_eof() {
+                    reader.next_line()?;
+                }
+
+                root.properties.push(Property::End);
+
+                break Ok(RgroupQuery { root, rgroups });
+            }
+            b"M  V30" => {
+                reader.read(Target::Builder(Literal::start(b" ")))?;
+
+                let mut tokens = v3000::line_rest(reader)?;
+
+                tokens.next()?.expect("BEGIN")?;
+                tokens.next()?.expect("RGROUP")?;
+
+                let number = tokens.next()?.build(Natural::start())?;
+
+                tokens.finish()?;
+                rgroups.push(
+                    rgroup(reader, number).at(rgroups.len()).at("rgroups")?,
+                );
+            }
+            _ => return Err(ErrorKind::Keyword(row, 0).into()),
+        }
+    }
+}
+
+fn rgroup(reader: &mut Reader, number: Natural) -> Result<Rgroup, Error> {
+    let mut result = Rgroup {
+        number,
+        members: vec![],
+        occurrence: vec![],
+        rest_h: false,
+        if_then: None,
+    };
+
+    loop {
+        let mut tokens = v3000::line(reader)?;
+        let first = tokens.next()?;
+
+        if first.is("RLOGIC") {
+            result.if_then = Natural::from_int(tokens.next()?.count()?);
+
+            let rest_h = tokens.next()?;
+
+            result.rest_h = match rest_h.count()? {
+                0 => false,
+                1 => true,
+                _ => {
+                    return Err(
+                        rest_h.error(0, vec![Printable::D0, Printable::D1])
+                    )
+                }
+            };
+
+            let occurrence = tokens.next()?;
+
+            result.occurrence = if occurrence.is_quoted() {
+                occurrence.unquote()?.build(Occurrence::start_list())?
+            } else {
+                occurrence.build(Occurrence::start_list())?
+            };
+
+            tokens.finish()?;
+        } else if first.is("BEGIN") {
+            tokens.next()?.expect("CTAB")?;
+            tokens.finish()?;
+            let index = result.members.len();
+
+            result.members.push(
+                v3000::ctab_rest(reader, blank_v3000())
+                    .at(index)
+                    .at("members")?,
+            );
+        } else {
+            first.expect("END")?;
+            tokens.next()?.expect("RGROUP")?;
+            tokens.finish()?;
+
+            break Ok(result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::path::field;
+    use crate::{
+        molfile::{Entry, MoleculeName},
+        primitive::FixedCount,
+    };
+    use pretty_assertions::assert_eq;
+
+    const ROOT: [&str; 16] = [
+        "$MDL  REV  1 0123456789",
+        "$MOL",
+        "$HDR",
+        "query",
+        "",
+        "",
+        "$END HDR",
+        "$CTAB",
+        "  2  1  0  0  0  0            999 V2000",
+        "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+        "    1.0000    0.0000    0.0000 R#  0  0  0  0  0  0  0  0  0  0  0  0",
+        "  1  2  1  0  0  0  0",
+        "M  RGP  1   2   1",
+        "M  LOG  1   1   2   1 1-3,>4",
+        "M  END",
+        "$END CTAB",
+    ];
+
+    const MEMBER: [&str; 6] = [
+        "$CTAB",
+        "  1  0  0  0  0  0            999 V2000",
+        "    0.0000    0.0000    0.0000 O   0  0  0  0  0  0  0  0  0  0  0  0",
+        "M  APO  1   1   1",
+        "M  END",
+        "$END CTAB",
+    ];
+
+    const V3000: [&str; 10] = [
+        "query",
+        "",
+        "",
+        "  0  0  0     0  0            999 V3000",
+        "M  V30 BEGIN CTAB",
+        "M  V30 COUNTS 2 1 0 0 0",
+        "M  V30 BEGIN ATOM",
+        "M  V30 1 C 0 0 0 0",
+        "M  V30 2 R# 1 0 0 0 RGROUPS=(1 1)",
+        "M  V30 END ATOM",
+    ];
+
+    const V3000_MEMBER: [&str; 7] = [
+        "M  V30 BEGIN CTAB",
+        "M  V30 COUNTS 1 0 0 0 0",
+        "M  V30 BEGIN ATOM",
+        "M  V30 1 O 0 0 0 0 ATTCHPT=1",
+        "M  V30 END ATOM",
+        "M  V30 END CTAB",
+        "M  V30 BEGIN RGROUP 1",
+    ];
+
+    fn read(lines: &[&[&str]]) -> Result<RgroupQuery, Error> {
+        let text = lines.concat().join("\n");
+        let mut bytes = text.into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        rgfile(&mut reader)
+    }
+
+    fn member_properties() -> Vec<Property> {
+        vec![
+            Property::AttachmentPoint(vec![Entry {
+                atom: Natural::from_int(1).unwrap(),
+                value: FixedCount::from_int(1).unwrap(),
+            }]),
+            Property::End,
+        ]
+    }
+
+    fn v3000_root() -> Vec<&'static str> {
+        [
+            &V3000[..],
+            &[
+                "M  V30 BEGIN BOND",
+                "M  V30 1 1 1 2",
+                "M  V30 END BOND",
+                "M  V30 END CTAB",
+            ],
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn v2000_missing_mol() {
+        assert_eq!(
+            read(&[&ROOT[..1], &["$CTAB"]]),
+            Err(ErrorKind::Character(1, 1, vec![Printable::M]).into())
+        )
+    }
+
+    #[test]
+    fn v2000_bad_occurrence() {
+        let mut root = ROOT;
+
+        root[13] = "M  LOG  1   1   2   1 1-";
+
+        assert_eq!(
+            read(&[&root, &["$END MOL"]]),
+            Err(field("ctab.properties[1]", ErrorKind::Eol(13)))
+        )
+    }
+
+    #[test]
+    fn v2000_bad_rgroup_number() {
+        assert_eq!(
+            read(&[&ROOT, &["$RGP", "  x"]]),
+            Err(field(
+                "rgroups[0].number",
+                ErrorKind::Character(17, 2, Printable::non_zero_digits())
+            ))
+        )
+    }
+
+    #[test]
+    fn v2000_missing_end_rgp() {
+        assert_eq!(
+            read(&[&ROOT, &["$RGP", "  1"], &MEMBER, &["$END MOL"]]),
+            Err(field(
+                "rgroups[0].members[1]",
+                ErrorKind::Character(24, 5, vec![Printable::R])
+            ))
+        )
+    }
+
+    #[test]
+    fn v2000_no_rgroups() {
+        let query = read(&[&ROOT, &["$END MOL"]]).unwrap();
+
+        assert_eq!((query.root.atoms.len(), query.rgroups), (2, vec![]))
+    }
+
+    #[test]
+    fn v2000() {
+        let query = read(&[
+            &ROOT,
+            &["$RGP", "  1"],
+            &MEMBER,
+            &MEMBER,
+            &["$END RGP", "$RGP", "  2"],
+            &MEMBER,
+            &["$END RGP", "$END MOL", ""],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            query.root.header.molecule_name,
+            MoleculeName::from_str("query").unwrap()
+        );
+        assert_eq!(
+            query
+                .rgroups
+                .iter()
+                .map(|rgroup| (
+                    rgroup.number.to_int(),
+                    rgroup.members.len(),
+                    rgroup.occurrence.clone(),
+                    rgroup.rest_h,
+                    rgroup.if_then.as_ref().map(Natural::to_int)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1,
+                    2,
+                    vec![Occurrence::Range(1, 3), Occurrence::GreaterThan(4)],
+                    true,
+                    Some(2)
+                ),
+                (2, 1, vec![], false, None)
+            ]
+        );
+        assert_eq!(query.rgroups[0].members[0].properties, member_properties())
+    }
+
+    #[test]
+    fn v3000_v2000_counts() {
+        assert_eq!(
+            read(&[&[
+                "",
+                "",
+                "",
+                "  0  0  0     0  0            999 V2000",
+                ""
+            ]]),
+            Err(ErrorKind::Character(3, 35, vec![Printable::D3]).into())
+        )
+    }
+
+    #[test]
+    fn v3000_unknown_line() {
+        assert_eq!(
+            read(&[&v3000_root(), &["M  CHG  1   1  -1"]]),
+            Err(ErrorKind::Keyword(14, 0).into())
+        )
+    }
+
+    #[test]
+    fn v3000_bad_rest_h() {
+        assert_eq!(
+            read(&[
+                &v3000_root(),
+                &V3000_MEMBER[6..],
+                &["M  V30 RLOGIC 0 2 \"1\"", ""],
+            ]),
+            Err(field(
+                "rgroups[0]",
+                ErrorKind::Character(
+                    15,
+                    16,
+                    vec![Printable::D0, Printable::D1]
+                )
+            ))
+        )
+    }
+
+    #[test]
+    fn v3000_bad_occurrence() {
+        assert_eq!(
+            read(&[
+                &v3000_root(),
+                &V3000_MEMBER[6..],
+                &["M  V30 RLOGIC 0 1 \"1,\"", ""],
+            ]),
+            Err(field("rgroups[0]", ErrorKind::End(15, 21)))
+        )
+    }
+
+    #[test]
+    fn v3000_no_rgroups() {
+        let query = read(&[&v3000_root(), &["M  END"]]).unwrap();
+
+        assert_eq!(
+            (query.root.bonds.len(), query.root.properties.last()),
+            (1, Some(&Property::End))
+        );
+        assert_eq!(query.rgroups, vec![])
+    }
+
+    #[test]
+    fn v3000() {
+        let query = read(&[
+            &v3000_root(),
+            &V3000_MEMBER[6..],
+            &["M  V30 RLOGIC 2 1 \"1-3,>4\""],
+            &V3000_MEMBER[..6],
+            &V3000_MEMBER[..6],
+            &["M  V30 END RGROUP", "M  V30 BEGIN RGROUP 2"],
+            &V3000_MEMBER[..6],
+            &["M  V30 END RGROUP", "M  END"],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            query
+                .rgroups
+                .iter()
+                .map(|rgroup| (
+                    rgroup.number.to_int(),
+                    rgroup.members.len(),
+                    rgroup.occurrence.clone(),
+                    rgroup.rest_h,
+                    rgroup.if_then.as_ref().map(Natural::to_int)
+                ))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    1,
+                    2,
+                    vec![Occurrence::Range(1, 3), Occurrence::GreaterThan(4)],
+                    true,
+                    Some(2)
+                ),
+                (2, 1, vec![], false, None)
+            ]
+        );
+        assert_eq!(
+            query.rgroups[1].members[0].properties,
+            member_properties()[..1]
+        )
+    }
+}
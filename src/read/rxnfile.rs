@@ -0,0 +1,428 @@
+use crate::{
+    build::Target,
+    molfile::{Molfile, Version},
+    primitive::{FixedCount, Line, Literal, Padding, Sequence},
+    rxn::{Parameters, Reaction},
+};
+
+use super::{header::blank_v3000, molfile, path::At, v3000, Error, Reader};
+
+pub fn rxnfile(reader: &mut Reader) -> Result<Reaction, Error> {
+    reader.read(Target::Builder(Literal::start(b"$RXN")))?;
+
+    let version = if reader.has_blank() || reader.is_eof() {
+        Version::V2
+    } else {
+        reader.read(Target::Builder(Literal::start(b" V3000")))?;
+
+        Version::V3
+    };
+
+    reader.next_line()?;
+
+    let name = reader.read_line(Target::Builder(Line::start()))?;
+    let parameters = parameters(reader)?;
+    let comment = reader.read_line(Target::Builder(Line::start()))?;
+
+    match version {
+        Version::V2 => v2000(reader, name, parameters, comment),
+        Version::V3 => v3000(reader, name, parameters, comment),
+    }
+}
+
+fn v2000(
+    reader: &mut Reader,
+    name: Line<80>,
+    parameters: Option<Parameters>,
+    comment: Line<80>,
+) -> Result<Reaction, Error> {
+    let reactants = reader.read(Target::Builder(FixedCount::<3>::start()))?;
+    let products = reader.read(Target::Builder(FixedCount::<3>::start()))?;
+    let agents = if reader.has_blank() || reader.is_eof() {
+        FixedCount::Zero
+    } else {
+        reader.read(Target::Builder(FixedCount::<3>::start()))?
+    };
+
+    reader.next_line()?;
+
+    Ok(Reaction {
+        name,
+        parameters,
+        comment,
+        reactants: molfiles(reader, &reactants).at("reactants")?,
+        products: molfiles(reader, &products).at("products")?,
+        agents: molfiles(reader, &agents).at("agents")?,
+    })
+}
+
+fn v3000(
+    reader: &mut Reader,
+    name: Line<80>,
+    parameters: Option<Parameters>,
+    comment: Line<80>,
+) -> Result<Reaction, Error> {
+    let mut tokens = v3000::line(reader)?;
+
+    tokens.next()?.expect("COUNTS")?;
+
+    let reactants = tokens.next()?.count()?;
+    let products = tokens.next()?.count()?;
+    let agents = match tokens.next() {
+        Ok(token) => token.count()?,
+        Err(_) => 0,
+    };
+
+    tokens.finish()?;
+
+    let reactants = ctabs(reader, "REACTANT", reactants).at("reactants")?;
+    let products = ctabs(reader, "PRODUCT", products).at("products")?;
+    let agents = if agents == 0 {
+        vec![]
+    } else {
+        ctabs(reader, "AGENT", agents).at("agents")?
+    };
+
+    reader.read(Target::Builder(Literal::start(b"M  END")))?;
+
+    if !reader.is_eof() {
+        reader.next_line()?;
+    }
+
+    Ok(Reaction {
+        name,
+        parameters,
+        comment,
+        reactants,
+        products,
+        agents,
+    })
+}
+
+fn ctabs(
+    reader: &mut Reader,
+    block: &str,
+    count: u32,
+) -> Result<Vec<Molfile>, Error> {
+    let mut tokens = v3000::line(reader)?;
+
+    tokens.next()?.expect("BEGIN")?;
+    tokens.next()?.expect(block)?;
+    tokens.finish()?;
+
+    let mut result = Vec::new();
+
+    for index in 0..count as usize {
+        result.push(v3000::ctab(reader, blank_v3000()).at(index)?);
+    }
+
+    let mut tokens = v3000::line(reader)?;
+
+    tokens.next()?.expect("END")?;
+    tokens.next()?.expect(block)?;
+    tokens.finish()?;
+
+    Ok(result)
+}
+
+fn parameters(reader: &mut Reader) -> Result<Option<Parameters>, Error> {
+    if reader.has_blank() {
+        reader.next_line()?;
+
+        return Ok(None);
+    }
+
+    let user_initials = reader.read(Target::Builder(Sequence::start()))?;
+    let program_name = reader.read(Target::Builder(Sequence::start()))?;
+    let timestamp = reader.read(Target::Builder(Sequence::start()))?;
+    let registry_number = if reader.has_blank() || reader.is_eof() {
+        None
+    } else {
+        reader.read(Target::Builder(Padding::<1>::start()))?;
+
+        Some(reader.read(Target::Builder(Line::start()))?)
+    };
+
+    reader.next_line()?;
+
+    Ok(Some(Parameters {
+        user_initials,
+        program_name,
+        timestamp,
+        registry_number,
+    }))
+}
+
+fn molfiles(
+    reader: &mut Reader,
+    count: &FixedCount<3>,
+) -> Result<Vec<Molfile>, Error> {
+    let mut result = Vec::new();
+
+    for index in 0..count.to_int() as usize {
+        reader
+            .read_line(Target::Builder(Literal::start(b"$MOL")))
+            .at(index)?;
+        result.push(molfile(reader).at(index)?);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::path::field;
+    use crate::read::ErrorKind;
+    use crate::{molfile::ChiralFlag, text::Printable};
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Result<Reaction, Error> {
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        rxnfile(&mut reader)
+    }
+
+    fn molfile(symbol: &str, mapping: u32) -> Vec<String> {
+        vec![
+            "$MOL".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            "  1  0  0  0  0  0            999 V2000".to_string(),
+            format!(
+                "    0.0000    0.0000    0.0000 {:<3} 0  0  0  0  0  0  0  0  0{:>3}  0  0",
+                symbol, mapping
+            ),
+            "M  END".to_string(),
+        ]
+    }
+
+    fn reaction(counts: &str, molfiles: &[(&str, u32)]) -> Vec<String> {
+        let mut result = vec![
+            "$RXN".to_string(),
+            "esterification".to_string(),
+            "CTUSERCTCORE   010120231234 1234567".to_string(),
+            "a comment".to_string(),
+            counts.to_string(),
+        ];
+
+        for (symbol, mapping) in molfiles {
+            result.extend(molfile(symbol, *mapping))
+        }
+
+        result
+    }
+
+    fn lines(lines: &[String]) -> Vec<&str> {
+        lines.iter().map(String::as_str).collect()
+    }
+
+    #[test]
+    fn missing_rxn() {
+        assert_eq!(
+            read(&["$MOL"]),
+            Err(ErrorKind::Character(0, 1, vec![Printable::R]).into())
+        )
+    }
+
+    #[test]
+    fn header() {
+        let reaction = read(&lines(&reaction("  0  0", &[]))).unwrap();
+
+        assert_eq!(reaction.name, Line::from_str("esterification").unwrap());
+        assert_eq!(
+            reaction.parameters,
+            Some(Parameters {
+                user_initials: Sequence::from_str("CTUSER").unwrap(),
+                program_name: Sequence::from_str("CTCORE   ").unwrap(),
+                timestamp: Sequence::from_str("010120231234").unwrap(),
+                registry_number: Line::from_str("1234567")
+            })
+        );
+        assert_eq!(reaction.comment, Line::from_str("a comment").unwrap())
+    }
+
+    #[test]
+    fn blank_parameters() {
+        let mut lines = reaction("  0  0", &[]);
+
+        lines[2] = String::new();
+
+        assert_eq!(read(&self::lines(&lines)).unwrap().parameters, None)
+    }
+
+    #[test]
+    fn no_registry_number() {
+        let mut lines = reaction("  0  0", &[]);
+
+        lines[2] = "CTUSERCTCORE   010120231234".to_string();
+
+        assert_eq!(
+            read(&self::lines(&lines))
+                .unwrap()
+                .parameters
+                .unwrap()
+                .registry_number,
+            None
+        )
+    }
+
+    #[test]
+    fn short_parameters() {
+        let mut lines = reaction("  0  0", &[]);
+
+        lines[2] = "CTUSERCTCORE".to_string();
+
+        assert_eq!(
+            read(&self::lines(&lines)),
+            Err(ErrorKind::End(2, 12).into())
+        )
+    }
+
+    #[test]
+    fn molfiles() {
+        let reaction = read(&lines(&reaction(
+            "  2  1  1",
+            &[("C", 1), ("O", 2), ("C", 1), ("N", 0)],
+        )))
+        .unwrap();
+        let symbols = |molfiles: &[Molfile]| {
+            molfiles
+                .iter()
+                .map(|molfile| {
+                    (
+                        molfile.atoms[0].symbol.clone(),
+                        molfile.atoms[0].mapping.to_int(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            symbols(&reaction.reactants),
+            vec![
+                (Sequence::from_str("C  ").unwrap(), 1),
+                (Sequence::from_str("O  ").unwrap(), 2)
+            ]
+        );
+        assert_eq!(
+            symbols(&reaction.products),
+            vec![(Sequence::from_str("C  ").unwrap(), 1)]
+        );
+        assert_eq!(
+            symbols(&reaction.agents),
+            vec![(Sequence::from_str("N  ").unwrap(), 0)]
+        )
+    }
+
+    #[test]
+    fn missing_molfile() {
+        assert_eq!(
+            read(&lines(&reaction("  1  1", &[("C", 0)]))),
+            Err(field("products[0]", ErrorKind::Eof(12)))
+        )
+    }
+
+    #[test]
+    fn bad_separator() {
+        let mut lines = reaction("  1  0", &[("C", 0)]);
+
+        lines[5] = "$MOLFILE".to_string();
+
+        assert_eq!(
+            read(&self::lines(&lines)),
+            Err(field("reactants[0]", ErrorKind::Overflow(5, 4)))
+        )
+    }
+
+    fn v3000(counts: &str, blocks: &[&str]) -> Vec<String> {
+        let mut result = vec![
+            "$RXN V3000".to_string(),
+            String::new(),
+            String::new(),
+            String::new(),
+            format!("M  V30 COUNTS {}", counts),
+        ];
+
+        for block in blocks {
+            result.push(format!("M  V30 BEGIN {}", block));
+            result.extend(
+                [
+                    "M  V30 BEGIN CTAB",
+                    "M  V30 COUNTS 1 0 0 0 1",
+                    "M  V30 BEGIN ATOM",
+                    "M  V30 1 C 0 0 0 3",
+                    "M  V30 END ATOM",
+                    "M  V30 END CTAB",
+                ]
+                .map(String::from),
+            );
+            result.push(format!("M  V30 END {}", block));
+        }
+
+        result.push("M  END".to_string());
+
+        result
+    }
+
+    #[test]
+    fn bad_version() {
+        assert_eq!(
+            read(&["$RXN V2000"]),
+            Err(ErrorKind::Character(0, 6, vec![Printable::D3]).into())
+        )
+    }
+
+    #[test]
+    fn v3000_components() {
+        let reaction =
+            read(&lines(&v3000("1 1 1", &["REACTANT", "PRODUCT", "AGENT"])))
+                .unwrap();
+
+        assert_eq!(
+            [&reaction.reactants, &reaction.products, &reaction.agents].map(
+                |molfiles| molfiles
+                    .iter()
+                    .map(|molfile| (
+                        molfile.header.counts.chiral.clone(),
+                        molfile.atoms[0].mapping.to_int()
+                    ))
+                    .collect::<Vec<_>>()
+            ),
+            [
+                vec![(ChiralFlag::Chiral, 3)],
+                vec![(ChiralFlag::Chiral, 3)],
+                vec![(ChiralFlag::Chiral, 3)]
+            ]
+        )
+    }
+
+    #[test]
+    fn v3000_without_agents() {
+        let reaction =
+            read(&lines(&v3000("1 1", &["REACTANT", "PRODUCT"]))).unwrap();
+
+        assert_eq!(
+            (
+                reaction.reactants.len(),
+                reaction.products.len(),
+                reaction.agents.len()
+            ),
+            (1, 1, 0)
+        )
+    }
+
+    #[test]
+    fn v3000_wrong_block() {
+        assert_eq!(
+            read(&lines(&v3000("1 1", &["PRODUCT", "REACTANT"]))),
+            Err(field(
+                "reactants",
+                ErrorKind::Character(5, 13, vec![Printable::R])
+            ))
+        )
+    }
+}
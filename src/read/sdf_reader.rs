@@ -0,0 +1,557 @@
+use std::io::{BufRead, Write};
+
+use crate::{
+    build::Target,
+    primitive::{Literal, Text},
+    sdf::{DataHeader, DataItem, SdRecord},
+    text::Printable,
+};
+
+use super::{molfile, path::At, Error, ErrorKind, Reader, Warning};
+
+pub struct SdfReader<'a> {
+    reader: Reader<'a>,
+    // receives the text of each failed record
+    pub quarantine: Option<&'a mut dyn Write>,
+    index: usize,
+    failed: bool,
+}
+
+impl<'a> SdfReader<'a> {
+    pub fn new(iter: &'a mut dyn Iterator<Item = u8>) -> Self {
+        Self::from_reader(Reader::new(iter))
+    }
+
+    pub fn from_read(read: impl BufRead + 'a) -> Self {
+        Self::from_reader(Reader::from_read(read))
+    }
+
+    pub fn from_reader(reader: Reader<'a>) -> Self {
+        Self {
+            reader,
+            quarantine: None,
+            index: 0,
+            failed: false,
+        }
+    }
+}
+
+impl SdfReader<'_> {
+    pub fn warnings(&self) -> &[Warning] {
+        &self.reader.warnings
+    }
+
+    // skips the rest of a failed record, sending its text to quarantine
+    fn recover(&mut self, error: Error) -> Error {
+        if let ErrorKind::Io(..) = error.kind {
+            self.failed = true;
+
+            return error;
+        }
+
+        if let Err(error) = skip(&mut self.reader) {
+            self.failed = true;
+
+            return error;
+        }
+
+        if let Some(quarantine) = &mut self.quarantine {
+            if let Err(error) = quarantine.write_all(&self.reader.captured()) {
+                self.failed = true;
+
+                return error.into();
+            }
+        }
+
+        error
+    }
+}
+
+impl Iterator for SdfReader<'_> {
+    type Item = Result<SdRecord, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.reader.is_eof() {
+            return None;
+        }
+
+        let start = self.reader.offset;
+
+        if self.quarantine.is_some() {
+            self.reader.capture();
+        } else {
+            self.reader.capture_line();
+        }
+
+        let result = record(&mut self.reader).map_err(|error| {
+            let error = self.recover(error);
+
+            ErrorKind::Record(
+                self.index,
+                start..self.reader.offset,
+                Box::new(error),
+            )
+            .into()
+        });
+
+        self.reader.captured();
+        self.index += 1;
+
+        Some(result)
+    }
+}
+
+// moves past the $$$$ line ending a failed record, going back to it if a
+// short record's $$$$ was read as part of the record
+fn skip(reader: &mut Reader) -> Result<(), Error> {
+    if reader.rewind() {
+        return Ok(());
+    }
+
+    let mut line = reader.captured_line();
+
+    line.append(&mut reader.skip_line()?);
+
+    while !line.starts_with(b"$$$$") && !reader.is_eof() {
+        line = reader.skip_line()?;
+    }
+
+    Ok(())
+}
+
+fn record(reader: &mut Reader) -> Result<SdRecord, Error> {
+    let molfile = molfile(reader)?;
+    let mut data = Vec::new();
+
+    loop {
+        if reader.is_eof() {
+            break Err(ErrorKind::Eof(reader.row).into());
+        } else if reader.has_blank() {
+            reader.next_line()?;
+        } else if reader.has(Printable::Dollar) {
+            reader.read(Target::Builder(Literal::start(b"$$$$")))?;
+
+            if !reader.is_eof() {
+                reader.next_line()?;
+            }
+
+            break Ok(SdRecord { molfile, data });
+        } else if reader.has(Printable::GreaterThan) {
+            data.push(data_item(reader).at(data.len()).at("data")?);
+        } else {
+            break Err(ErrorKind::Character(
+                reader.row,
+                reader.column,
+                vec![Printable::Dollar, Printable::GreaterThan],
+            )
+            .into());
+        }
+    }
+}
+
+fn data_item(reader: &mut Reader) -> Result<DataItem, Error> {
+    let header = reader.read_line(Target::Builder(DataHeader::start()))?;
+    let mut value = Vec::new();
+
+    loop {
+        if reader.is_eof() {
+            break Err(ErrorKind::Eof(reader.row).into());
+        } else if reader.has_blank() {
+            reader.next_line()?;
+
+            break Ok(DataItem { header, value });
+        }
+
+        value.push(reader.read_line(Target::Builder(Text::start()))?);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive::Natural;
+    use crate::read::{
+        path::field, reader::fail_once, ReadOptions, Strictness, WarningKind,
+    };
+    use pretty_assertions::assert_eq;
+    use std::io;
+
+    const MOLFILE: [&str; 5] = [
+        "",
+        "",
+        "",
+        "  1  0  0  0  0  0            999 V2000",
+        "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+    ];
+
+    fn read(records: &[&[&str]]) -> Vec<Result<SdRecord, Error>> {
+        let mut lines = Vec::new();
+
+        for record in records {
+            lines.extend(MOLFILE);
+            lines.push("M  END");
+            lines.extend(*record);
+        }
+
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+
+        SdfReader::new(&mut bytes).collect()
+    }
+
+    fn tag(tag: &str) -> DataHeader {
+        DataHeader {
+            tag: Text::from_str(tag),
+            ..DataHeader::default()
+        }
+    }
+
+    fn text(lines: &[&str]) -> Vec<Text> {
+        lines
+            .iter()
+            .map(|line| Text::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn empty() {
+        let mut bytes = "".bytes();
+
+        assert_eq!(SdfReader::new(&mut bytes).next(), None)
+    }
+
+    #[test]
+    fn no_data() {
+        let records = read(&[&["$$$$"], &["$$$$"]]);
+
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|record| record
+            .as_ref()
+            .is_ok_and(|record| record.data.is_empty())))
+    }
+
+    #[test]
+    fn data_items() {
+        let records = read(&[&[
+            "> <NAME>",
+            "methane",
+            "",
+            ">  25  <NOTES> (MD-08974) DT13",
+            "first",
+            "  second",
+            "",
+            "> <EMPTY>",
+            "",
+            "$$$$",
+        ]]);
+
+        assert_eq!(
+            records[0].as_ref().map(|record| &record.data),
+            Ok(&vec![
+                DataItem {
+                    header: tag("NAME"),
+                    value: text(&["methane"])
+                },
+                DataItem {
+                    header: DataHeader {
+                        number: Natural::from_int(25),
+                        tag: Text::from_str("NOTES"),
+                        external_registry: Text::from_str("MD-08974"),
+                        field: Natural::from_int(13)
+                    },
+                    value: text(&["first", "  second"])
+                },
+                DataItem {
+                    header: tag("EMPTY"),
+                    value: vec![]
+                }
+            ])
+        )
+    }
+
+    #[test]
+    fn missing_separator() {
+        assert_eq!(
+            read(&[&["> <NAME>", "methane", ""]]),
+            vec![Err(ErrorKind::Record(
+                0,
+                0..138,
+                Box::new(ErrorKind::Eof(9).into())
+            )
+            .into())]
+        )
+    }
+
+    #[test]
+    fn unterminated_value() {
+        let text = [&MOLFILE[..], &["M  END", "> <NAME>", "methane"]]
+            .concat()
+            .join("\n");
+        let mut bytes = text.bytes();
+
+        assert_eq!(
+            SdfReader::new(&mut bytes).collect::<Vec<_>>(),
+            vec![Err(ErrorKind::Record(
+                0,
+                0..136,
+                Box::new(field("data[0]", ErrorKind::Eof(7)))
+            )
+            .into())]
+        )
+    }
+
+    #[test]
+    fn bad_header() {
+        let records = read(&[&["$$$$"], &["> NAME", "", "$$$$"], &["$$$$"]]);
+        let mut allowed = vec![
+            Printable::Space,
+            Printable::LessThan,
+            Printable::OpenParen,
+            Printable::D,
+        ];
+
+        allowed.extend(Printable::non_zero_digits());
+
+        assert_eq!(records.len(), 3);
+        assert!(records[0].is_ok());
+        assert_eq!(
+            records[1],
+            Err(ErrorKind::Record(
+                1,
+                125..258,
+                Box::new(field(
+                    "data[0]",
+                    ErrorKind::Character(13, 2, allowed)
+                ))
+            )
+            .into())
+        );
+        assert!(records[2].is_ok())
+    }
+
+    #[test]
+    fn stray_line() {
+        assert_eq!(
+            read(&[&["NAME", "$$$$"]]),
+            vec![Err(ErrorKind::Record(
+                0,
+                0..130,
+                Box::new(
+                    ErrorKind::Character(
+                        6,
+                        0,
+                        vec![Printable::Dollar, Printable::GreaterThan]
+                    )
+                    .into()
+                )
+            )
+            .into())]
+        )
+    }
+
+    #[test]
+    fn bad_separator() {
+        assert_eq!(
+            read(&[&["$$$"]]),
+            vec![Err(ErrorKind::Record(
+                0,
+                0..124,
+                Box::new(ErrorKind::End(6, 3).into())
+            )
+            .into())]
+        )
+    }
+
+    #[test]
+    fn separator_at_eof() {
+        let text = [&MOLFILE[..], &["M  END", "$$$$"]].concat().join("\n");
+        let mut bytes = text.bytes();
+
+        assert_eq!(SdfReader::new(&mut bytes).filter(Result::is_ok).count(), 1)
+    }
+
+    #[test]
+    fn io_error_after_molfile() {
+        let text = [&MOLFILE[..], &["M  END", "\r"]].concat().join("\n");
+        let read = io::Read::chain(text.as_bytes(), fail_once(b"$$$$\n"));
+        let records =
+            SdfReader::from_read(io::BufReader::new(read)).collect::<Vec<_>>();
+
+        assert_eq!(
+            records,
+            vec![Err(ErrorKind::Record(
+                0,
+                0..text.len(),
+                Box::new(
+                    ErrorKind::Io(
+                        io::ErrorKind::InvalidData,
+                        "bad byte".into()
+                    )
+                    .into()
+                )
+            )
+            .into())]
+        )
+    }
+
+    #[test]
+    fn lenient_warnings() {
+        let text = [
+            &MOLFILE[..4],
+            &[
+                "    0.0000    0.0000    0.0000 c   0  0  0  0  0  0  0  0  0  0  0  0",
+                "M  END",
+                "$$$$",
+                "",
+            ],
+        ]
+        .concat()
+        .join("\n");
+        let mut bytes = text.bytes();
+        let options = ReadOptions {
+            strictness: Strictness::Lenient,
+        };
+        let mut reader = SdfReader::from_reader(
+            Reader::new(&mut bytes).with_options(options),
+        );
+
+        assert!(matches!(reader.next(), Some(Ok(_))));
+        assert_eq!(
+            reader.warnings(),
+            &[Warning {
+                row: 4,
+                column: 31,
+                kind: WarningKind::LowercaseSymbol
+            }]
+        )
+    }
+
+    #[test]
+    fn resume_after_bad_record() {
+        let records = read(&[&["$$$$"], &["> NAME", "x", "$$$$"], &["$$$$"]]);
+
+        assert_eq!(records.len(), 3);
+        assert!(records[0].is_ok());
+        assert!(matches!(
+            &records[1],
+            Err(Error {
+                kind: ErrorKind::Record(1, range, _),
+                ..
+            }) if range == &(125..259)
+        ));
+        assert!(records[2].is_ok())
+    }
+
+    #[test]
+    fn resume_at_separator_of_short_record() {
+        let text = [
+            &MOLFILE[..3],
+            &["  1  0  0  0  0  0            999 V2000"],
+            &["$$$$"],
+            &MOLFILE,
+            &["M  END", "$$$$", ""],
+        ]
+        .concat()
+        .join("\n");
+        let mut bytes = text.bytes();
+        let records = SdfReader::new(&mut bytes).collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(
+            &records[0],
+            Err(Error {
+                kind: ErrorKind::Record(0, range, _),
+                ..
+            }) if range == &(0..48)
+        ));
+        assert!(records[1].is_ok())
+    }
+
+    #[test]
+    fn resume_after_bad_separator() {
+        let records = read(&[&["$$$$ x"], &["$$$$"]]);
+
+        assert_eq!(records.len(), 2);
+        assert!(matches!(
+            &records[0],
+            Err(Error {
+                kind: ErrorKind::Record(0, range, _),
+                ..
+            }) if range == &(0..127)
+        ));
+        assert!(records[1].is_ok())
+    }
+
+    #[test]
+    fn quarantine() {
+        let text = [
+            &MOLFILE[..],
+            &["M  END", "$$$$"],
+            &MOLFILE,
+            &["M  CHG  0", "$$$$"],
+            &MOLFILE,
+            &["M  END", "$$$$", ""],
+        ]
+        .concat()
+        .join("\n");
+        let mut bytes = text.bytes();
+        let mut quarantine = Vec::new();
+        let mut reader = SdfReader::new(&mut bytes);
+
+        reader.quarantine = Some(&mut quarantine);
+
+        let records = reader.collect::<Vec<_>>();
+
+        assert_eq!(
+            records.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+        assert_eq!(
+            String::from_utf8(quarantine).unwrap(),
+            [&MOLFILE[..], &["M  CHG  0", "$$$$", ""]]
+                .concat()
+                .join("\n")
+        )
+    }
+
+    #[test]
+    fn bad_record_shorter_than_header() {
+        let good = [&MOLFILE[..], &["M  END", "$$$$"]].concat();
+        let text = [&good[..], &["bad", "", "$$$$"], &good, &good, &[""]]
+            .concat()
+            .join("\n");
+        let mut bytes = text.bytes();
+        let records = SdfReader::new(&mut bytes).collect::<Vec<_>>();
+
+        assert_eq!(
+            records.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            vec![true, false, true, true]
+        );
+        assert!(matches!(
+            &records[1],
+            Err(Error {
+                kind: ErrorKind::Record(1, range, _),
+                ..
+            }) if range == &(125..135)
+        ))
+    }
+
+    #[test]
+    fn quarantine_bad_record_shorter_than_header() {
+        let good = [&MOLFILE[..], &["M  END", "$$$$"]].concat();
+        let text = [&good[..], &["bad", "", "$$$$"], &good, &[""]]
+            .concat()
+            .join("\n");
+        let mut bytes = text.bytes();
+        let mut quarantine = Vec::new();
+        let mut reader = SdfReader::new(&mut bytes);
+
+        reader.quarantine = Some(&mut quarantine);
+
+        assert_eq!(
+            reader.map(|record| record.is_ok()).collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+        assert_eq!(String::from_utf8(quarantine).unwrap(), "bad\n\n$$$$\n")
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    build::Target,
+    molfile::{Counts, Stext},
+    primitive::{FixedReal, Line},
+};
+
+use super::{path::At, Error, Reader};
+
+pub fn stext_block(
+    reader: &mut Reader,
+    counts: &Counts,
+) -> Result<Vec<Stext>, Error> {
+    let mut result = Vec::new();
+    let count = match &counts.stext {
+        Some(count) => count.to_int(),
+        None => 0,
+    };
+
+    for index in 0..count as usize {
+        result.push(stext(reader).at(index).at("stext").at("ctab")?)
+    }
+
+    Ok(result)
+}
+
+fn stext(reader: &mut Reader) -> Result<Stext, Error> {
+    let x = reader.read(Target::Builder(FixedReal::start())).at("x")?;
+    let y = reader
+        .read_line(Target::Builder(FixedReal::start()))
+        .at("y")?;
+    let text = reader
+        .read_line(Target::Builder(Line::start()))
+        .at("text")?;
+
+    Ok(Stext { x, y, text })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::path::field;
+    use crate::read::ErrorKind;
+    use crate::{
+        molfile::{ChiralFlag, Version},
+        primitive::{FixedCount, FixedInteger},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn counts(stext: Option<u32>) -> Counts {
+        Counts {
+            atoms: FixedCount::Zero,
+            bonds: FixedCount::Zero,
+            atom_lists: FixedCount::Zero,
+            chiral: ChiralFlag::NotChiral,
+            stext: stext.map(|stext| FixedCount::from_int(stext).unwrap()),
+            version: Version::V2,
+        }
+    }
+
+    #[test]
+    fn blank_count() {
+        let mut bytes = b"".iter().cloned();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(stext_block(&mut reader, &counts(None)), Ok(vec![]))
+    }
+
+    #[test]
+    fn eof_after_coordinates() {
+        let mut bytes = ["    1.0000    2.0000", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            stext_block(&mut reader, &counts(Some(1))),
+            Err(field("ctab.stext[0].text", ErrorKind::Eof(1)))
+        )
+    }
+
+    #[test]
+    fn valid() {
+        let mut bytes = ["    1.0000    2.0000", "Hello", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        assert_eq!(
+            stext_block(&mut reader, &counts(Some(1))),
+            Ok(vec![Stext {
+                x: FixedReal::Integer(
+                    FixedInteger::from_int(1).unwrap(),
+                    vec![Digit::d0(); 4]
+                ),
+                y: FixedReal::Integer(
+                    FixedInteger::from_int(2).unwrap(),
+                    vec![Digit::d0(); 4]
+                ),
+                text: Line::from_str("Hello").unwrap()
+            }])
+        )
+    }
+}
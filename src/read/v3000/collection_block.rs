@@ -0,0 +1,260 @@
+use crate::{
+    molfile::{Atom, Property, StereoGroup, StereoGroupKind},
+    primitive::{FixedCount, Natural, Text},
+};
+
+use super::{
+    super::{Error, ErrorKind, Reader},
+    line::{line, Tokens},
+    token::Token,
+};
+
+pub fn collection_block(
+    reader: &mut Reader,
+    atoms: &[Atom],
+    collections: &mut Vec<Property>,
+) -> Result<Vec<StereoGroup>, Error> {
+    let mut result = Vec::new();
+
+    loop {
+        let mut tokens = line(reader)?;
+        let first = tokens.next()?;
+
+        if first.is("END") {
+            tokens.next()?.expect("COLLECTION")?;
+            tokens.finish()?;
+
+            break Ok(result);
+        }
+
+        let bytes = first.bytes();
+        let kind = if first.is("MDLV30/STEABS") {
+            StereoGroupKind::Absolute
+        } else if bytes.starts_with(b"MDLV30/STERAC") {
+            StereoGroupKind::Racemic(
+                first.slice(13, bytes.len()).build(Natural::start())?,
+            )
+        } else if bytes.starts_with(b"MDLV30/STEREL") {
+            StereoGroupKind::Relative(
+                first.slice(13, bytes.len()).build(Natural::start())?,
+            )
+        } else {
+            collections.push(collection(first, tokens)?);
+
+            continue;
+        };
+        let token = tokens.next()?;
+        let (key, value) = token.keyword()?;
+
+        if key != b"ATOMS" {
+            return Err(ErrorKind::Keyword(token.row, token.column).into());
+        }
+
+        let mut members = Vec::new();
+
+        for item in value.list()? {
+            let atom = item.build(Natural::start())?;
+
+            match atoms.get(atom.to_int() as usize - 1) {
+                Some(found) if found.stereo_parity != FixedCount::Zero => {
+                    members.push(atom)
+                }
+                _ => {
+                    return Err(ErrorKind::Reference(
+                        item.row,
+                        item.column,
+                        atom.to_int(),
+                        atoms.len() as u32,
+                    )
+                    .into())
+                }
+            }
+        }
+
+        tokens.finish()?;
+        result.push(StereoGroup {
+            kind,
+            atoms: members,
+        })
+    }
+}
+
+// HILITE, HIDDEN and other collections without a model, kept verbatim
+fn collection(name: Token, tokens: Tokens) -> Result<Property, Error> {
+    let mut keywords = Vec::new();
+
+    for token in tokens {
+        let (key, value) = token.keyword()?;
+
+        keywords.push((
+            Text::from_printables(token.slice(0, key.len()).printables),
+            Text::from_printables(value.printables),
+        ))
+    }
+
+    Ok(Property::Collection {
+        name: Text::from_printables(name.printables),
+        keywords,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::{FixedInteger, FixedReal, Sequence},
+        text::{Digit, Printable},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn atom(parity: u32) -> Atom {
+        let zero =
+            || FixedReal::Integer(FixedInteger::Zero, vec![Digit::d0(); 4]);
+
+        Atom {
+            x: zero(),
+            y: zero(),
+            z: zero(),
+            symbol: Sequence::from_str("C  ").unwrap(),
+            mass_difference: FixedInteger::Zero,
+            charge: FixedCount::Zero,
+            stereo_parity: FixedCount::from_int(parity).unwrap(),
+            hydrogen_count: FixedCount::Zero,
+            stereo_care: FixedCount::Zero,
+            valence: FixedCount::Zero,
+            h0_designator: FixedCount::Zero,
+            mapping: FixedCount::Zero,
+            inversion: FixedCount::Zero,
+            exact_change: FixedCount::Zero,
+        }
+    }
+
+    fn read(lines: &[&str]) -> Result<Vec<StereoGroup>, Error> {
+        let mut bytes = lines.join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        collection_block(
+            &mut reader,
+            &[atom(1), atom(2), atom(0)],
+            &mut Vec::new(),
+        )
+    }
+
+    fn natural(value: u32) -> Natural {
+        Natural::from_int(value).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(read(&["M  V30 END COLLECTION", ""]), Ok(vec![]))
+    }
+
+    #[test]
+    fn unknown_collections() {
+        let mut bytes = [
+            "M  V30 MDLV30/HILITE ATOMS=(1 1) BONDS=(1 1)",
+            "M  V30 MDLV30/HIDDEN ATOMS=(1 3)",
+            "M  V30 MDLV30/STEABS ATOMS=(1 1)",
+            "M  V30 END COLLECTION",
+            "",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+        let mut collections = Vec::new();
+        let text = |str| Text::from_str(str).unwrap();
+
+        assert_eq!(
+            collection_block(&mut reader, &[atom(1)], &mut collections),
+            Ok(vec![StereoGroup {
+                kind: StereoGroupKind::Absolute,
+                atoms: vec![natural(1)]
+            }])
+        );
+        assert_eq!(
+            collections,
+            vec![
+                Property::Collection {
+                    name: text("MDLV30/HILITE"),
+                    keywords: vec![
+                        (text("ATOMS"), text("(1 1)")),
+                        (text("BONDS"), text("(1 1)"))
+                    ]
+                },
+                Property::Collection {
+                    name: text("MDLV30/HIDDEN"),
+                    keywords: vec![(text("ATOMS"), text("(1 3)"))]
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn unknown_collection_without_keyword() {
+        assert_eq!(
+            read(&["M  V30 MDLV30/HILITE ATOMS", ""]),
+            Err(ErrorKind::Keyword(0, 21).into())
+        )
+    }
+
+    #[test]
+    fn missing_group_number() {
+        assert_eq!(
+            read(&["M  V30 MDLV30/STERAC ATOMS=(1 1)", ""]),
+            Err(ErrorKind::End(0, 20).into())
+        )
+    }
+
+    #[test]
+    fn bad_group_number() {
+        assert_eq!(
+            read(&["M  V30 MDLV30/STEREL0 ATOMS=(1 1)", ""]),
+            Err(ErrorKind::Character(0, 20, Printable::non_zero_digits())
+                .into())
+        )
+    }
+
+    #[test]
+    fn missing_atom() {
+        assert_eq!(
+            read(&["M  V30 MDLV30/STEABS ATOMS=(2 1 4)", ""]),
+            Err(ErrorKind::Reference(0, 32, 4, 3).into())
+        )
+    }
+
+    #[test]
+    fn atom_without_cfg() {
+        assert_eq!(
+            read(&["M  V30 MDLV30/STEABS ATOMS=(1 3)", ""]),
+            Err(ErrorKind::Reference(0, 30, 3, 3).into())
+        )
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            read(&[
+                "M  V30 MDLV30/STEABS ATOMS=(1 1)",
+                "M  V30 MDLV30/STERAC1 ATOMS=(1 2)",
+                "M  V30 MDLV30/STEREL12 ATOMS=(2 1 2)",
+                "M  V30 END COLLECTION",
+                ""
+            ]),
+            Ok(vec![
+                StereoGroup {
+                    kind: StereoGroupKind::Absolute,
+                    atoms: vec![natural(1)]
+                },
+                StereoGroup {
+                    kind: StereoGroupKind::Racemic(natural(1)),
+                    atoms: vec![natural(2)]
+                },
+                StereoGroup {
+                    kind: StereoGroupKind::Relative(natural(12)),
+                    atoms: vec![natural(1), natural(2)]
+                }
+            ])
+        )
+    }
+}
@@ -0,0 +1,978 @@
+use crate::{
+    molfile::{
+        Atom, Bond, BondStereo, BondTopology, BondType, ChiralFlag, Entry,
+        Header, ListType, Molfile, Property, ReactingCenter,
+    },
+    primitive::{FixedCount, FixedInteger, Natural, Sequence, Text},
+    text::Printable,
+};
+
+use super::{
+    super::{path::At, Error, ErrorKind, Reader, Segment},
+    collection_block::collection_block,
+    line::{line, Tokens},
+    sgroup_block::sgroup_block,
+    token::Token,
+};
+
+pub fn ctab(reader: &mut Reader, header: Header) -> Result<Molfile, Error> {
+    begin(reader).at("ctab").at("v3000")?;
+
+    ctab_rest(reader, header)
+}
+
+// the rest of a CTAB whose BEGIN CTAB line has already been read
+pub fn ctab_rest(
+    reader: &mut Reader,
+    header: Header,
+) -> Result<Molfile, Error> {
+    body(reader, header).at("ctab").at("v3000")
+}
+
+fn begin(reader: &mut Reader) -> Result<(), Error> {
+    let mut tokens = line(reader)?;
+
+    tokens.next()?.expect("BEGIN")?;
+    tokens.next()?.expect("CTAB")?;
+    tokens.finish()
+}
+
+fn body(reader: &mut Reader, mut header: Header) -> Result<Molfile, Error> {
+    let mut properties = Properties::default();
+    let mut atoms = Vec::new();
+    let mut bonds = Vec::new();
+    let mut sgroups = Vec::new();
+    let mut stereo_groups = Vec::new();
+    let mut tokens = line(reader)?;
+
+    tokens.next()?.expect("COUNTS")?;
+
+    let atom_count = tokens.next()?.count()?;
+    let bond_count = tokens.next()?.count()?;
+
+    let sgroup_count = tokens.next()?.count()?;
+
+    // n3d
+    tokens.next()?.count()?;
+
+    header.counts.chiral = tokens.next()?.right(ChiralFlag::start(), 3)?;
+
+    for token in tokens {
+        let (key, value) = token.keyword()?;
+
+        match key.as_slice() {
+            b"REGNO" => {
+                properties.registry =
+                    Some(Property::Registry(value.build(Natural::start())?))
+            }
+            _ => return Err(ErrorKind::Keyword(token.row, token.column).into()),
+        }
+    }
+
+    loop {
+        let mut tokens = line(reader)?;
+        let first = tokens.next()?;
+
+        if first.is("END") {
+            if atoms.len() as u32 != atom_count
+                || bonds.len() as u32 != bond_count
+                || sgroups.len() as u32 != sgroup_count
+            {
+                first.expect("BEGIN")?;
+            }
+
+            tokens.next()?.expect("CTAB")?;
+            tokens.finish()?;
+
+            break;
+        }
+
+        first.expect("BEGIN")?;
+
+        let block = tokens.next()?;
+
+        tokens.finish()?;
+
+        if block.is("ATOM") {
+            for index in 1..=atom_count {
+                atoms.push(
+                    line(reader)
+                        .and_then(|tokens| atom(tokens, index, &mut properties))
+                        .at(index as usize - 1)
+                        .at("atoms")?,
+                );
+            }
+        } else if block.is("BOND") {
+            for index in 1..=bond_count {
+                bonds.push(
+                    line(reader)
+                        .and_then(|tokens| {
+                            bond(tokens, index, atom_count, &mut properties)
+                        })
+                        .at(index as usize - 1)
+                        .at("bonds")?,
+                );
+            }
+        } else if block.is("SGROUP") {
+            sgroups =
+                sgroup_block(reader, sgroup_count, atom_count, bond_count)
+                    .at("sgroups")?;
+        } else if block.is("COLLECTION") {
+            stereo_groups.append(
+                &mut collection_block(reader, &atoms, &mut properties.keywords)
+                    .at("stereo_groups")?,
+            );
+
+            continue;
+        } else {
+            return Err(ErrorKind::Keyword(block.row, block.column).into());
+        }
+
+        let mut tokens = line(reader)?;
+
+        tokens.next()?.expect("END")?;
+        tokens
+            .next()?
+            .expect(&String::from_utf8_lossy(&block.bytes()))?;
+        tokens.finish()?;
+    }
+
+    Ok(Molfile {
+        header,
+        atoms,
+        bonds,
+        atom_lists: vec![],
+        stext: vec![],
+        sgroups,
+        stereo_groups,
+        properties: properties.into_properties(),
+    })
+}
+
+#[derive(Default)]
+struct Properties {
+    charges: Vec<Entry<FixedInteger<3>>>,
+    radicals: Vec<Entry<FixedCount<3>>>,
+    isotopes: Vec<Entry<FixedCount<3>>>,
+    ring_bond_counts: Vec<Entry<FixedInteger<3>>>,
+    substitution_counts: Vec<Entry<FixedInteger<3>>>,
+    unsaturated: Vec<Entry<FixedCount<3>>>,
+    atom_lists: Vec<Property>,
+    attachment_points: Vec<Entry<FixedCount<3>>>,
+    attachment_orders: Vec<Property>,
+    rgroup_labels: Vec<Entry<Natural>>,
+    keywords: Vec<Property>,
+    registry: Option<Property>,
+}
+
+impl Properties {
+    fn into_properties(self) -> Vec<Property> {
+        let mut result = Vec::new();
+
+        if !self.charges.is_empty() {
+            result.push(Property::Charge(self.charges))
+        }
+
+        if !self.radicals.is_empty() {
+            result.push(Property::Radical(self.radicals))
+        }
+
+        if !self.isotopes.is_empty() {
+            result.push(Property::Isotope(self.isotopes))
+        }
+
+        if !self.ring_bond_counts.is_empty() {
+            result.push(Property::RingBondCount(self.ring_bond_counts))
+        }
+
+        if !self.substitution_counts.is_empty() {
+            result.push(Property::SubstitutionCount(self.substitution_counts))
+        }
+
+        if !self.unsaturated.is_empty() {
+            result.push(Property::Unsaturated(self.unsaturated))
+        }
+
+        result.extend(self.atom_lists);
+
+        if !self.attachment_points.is_empty() {
+            result.push(Property::AttachmentPoint(self.attachment_points))
+        }
+
+        result.extend(self.attachment_orders);
+
+        if !self.rgroup_labels.is_empty() {
+            result.push(Property::RgroupLabel(self.rgroup_labels))
+        }
+
+        result.extend(self.keywords);
+        result.extend(self.registry);
+
+        result
+    }
+}
+
+fn index(token: Token, expected: u32) -> Result<Natural, Error> {
+    let index = token.build(Natural::start())?;
+
+    if index.to_int() == expected {
+        Ok(index)
+    } else {
+        Err(ErrorKind::Index(token.row, token.column).into())
+    }
+}
+
+fn atom(
+    mut tokens: Tokens,
+    expected: u32,
+    properties: &mut Properties,
+) -> Result<Atom, Error> {
+    let index = index(tokens.next()?, expected)?;
+    let symbol = symbol(tokens.next()?, &index, properties).at("symbol")?;
+    let x = tokens.next()?.real().at("x")?;
+    let y = tokens.next()?.real().at("y")?;
+    let z = tokens.next()?.real().at("z")?;
+    let mapping = tokens.next()?.right(FixedCount::start(), 3).at("mapping")?;
+    let mut atom = Atom {
+        x,
+        y,
+        z,
+        symbol,
+        mass_difference: FixedInteger::Zero,
+        charge: FixedCount::Zero,
+        stereo_parity: FixedCount::Zero,
+        hydrogen_count: FixedCount::Zero,
+        stereo_care: FixedCount::Zero,
+        valence: FixedCount::Zero,
+        h0_designator: FixedCount::Zero,
+        mapping,
+        inversion: FixedCount::Zero,
+        exact_change: FixedCount::Zero,
+    };
+
+    for token in tokens {
+        let (key, value) = token.keyword()?;
+
+        atom_keyword(&mut atom, &index, &key, value, properties)
+            .at(keyword(&key))?;
+    }
+
+    Ok(atom)
+}
+
+// an element symbol, or an atom list recorded as a property
+fn symbol(
+    kind: Token,
+    index: &Natural,
+    properties: &mut Properties,
+) -> Result<Sequence<3>, Error> {
+    Ok(if kind.is_quoted() {
+        let list = kind.unquote()?;
+
+        list.slice(0, list.printables.len().min(4)).expect("NOT ")?;
+        properties.atom_lists.push(atom_list(
+            &list.slice(4, list.printables.len()),
+            index.clone(),
+            ListType::NotList,
+        )?);
+
+        Sequence::from_str("L  ").expect("symbol")
+    } else if kind.printables.first() == Some(&Printable::OpenBracket) {
+        properties.atom_lists.push(atom_list(
+            &kind,
+            index.clone(),
+            ListType::List,
+        )?);
+
+        Sequence::from_str("L  ").expect("symbol")
+    } else {
+        kind.left(Sequence::start(), 3)?
+    })
+}
+
+fn atom_keyword(
+    atom: &mut Atom,
+    index: &Natural,
+    key: &[u8],
+    value: Token,
+    properties: &mut Properties,
+) -> Result<(), Error> {
+    let atom_index = index.clone();
+
+    match key {
+        b"CHG" => properties.charges.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedInteger::start_range(-15, 15), 3)?,
+        }),
+        b"RAD" => properties.radicals.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedCount::start(), 3)?,
+        }),
+        b"MASS" => properties.isotopes.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedCount::start(), 3)?,
+        }),
+        b"RBCNT" => properties.ring_bond_counts.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedInteger::start(), 3)?,
+        }),
+        b"SUBST" => properties.substitution_counts.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedInteger::start(), 3)?,
+        }),
+        b"UNSAT" => properties.unsaturated.push(Entry {
+            atom: atom_index,
+            value: value.right(FixedCount::start(), 3)?,
+        }),
+        b"ATTCHPT" => properties.attachment_points.push(Entry {
+            atom: atom_index,
+            value: if value.is("-1") {
+                FixedCount::from_int(3).expect("both")
+            } else {
+                value.right(FixedCount::start(), 3)?
+            },
+        }),
+        b"RGROUPS" => {
+            for rgroup in value.list()? {
+                properties.rgroup_labels.push(Entry {
+                    atom: index.clone(),
+                    value: rgroup.build(Natural::start())?,
+                })
+            }
+        }
+        b"ATTCHORD" => {
+            let mut entries = Vec::new();
+            let mut items = value.list()?.into_iter();
+
+            while let Some(neighbor) = items.next() {
+                let order = match items.next() {
+                    Some(order) => order,
+                    None => {
+                        return Err(value.error(
+                            value.printables.len() - 1,
+                            vec![Printable::Space],
+                        ))
+                    }
+                };
+
+                entries.push(Entry {
+                    atom: neighbor.build(Natural::start())?,
+                    value: order.right(FixedCount::start(), 3)?,
+                })
+            }
+
+            properties
+                .attachment_orders
+                .push(Property::AttachmentOrder {
+                    atom: atom_index,
+                    entries,
+                })
+        }
+        b"CFG" => atom.stereo_parity = value.right(FixedCount::start(), 3)?,
+        b"VAL" => {
+            atom.valence = if value.is("-1") {
+                FixedCount::from_int(15).expect("zero valence")
+            } else {
+                value.right(FixedCount::start(), 3)?
+            }
+        }
+        b"HCOUNT" => {
+            atom.hydrogen_count = if value.is("-1") {
+                FixedCount::from_int(1).expect("zero hydrogens")
+            } else if value.is("0") {
+                FixedCount::Zero
+            } else {
+                let count = value.right(FixedCount::<3>::start(), 3)?;
+
+                match FixedCount::from_int(count.to_int() + 1) {
+                    Some(count) => count,
+                    None => {
+                        return Err(ErrorKind::Overflow(
+                            value.row,
+                            value.column,
+                        )
+                        .into())
+                    }
+                }
+            }
+        }
+        b"STBOX" => atom.stereo_care = value.right(FixedCount::start(), 3)?,
+        b"INVRET" => atom.inversion = value.right(FixedCount::start(), 3)?,
+        b"EXACHG" => atom.exact_change = value.right(FixedCount::start(), 3)?,
+        _ => properties.keywords.push(Property::AtomKeyword {
+            atom: atom_index,
+            keyword: text(key),
+            value: Text::from_printables(value.printables),
+        }),
+    }
+
+    Ok(())
+}
+
+fn keyword(key: &[u8]) -> Segment {
+    Segment::Keyword(String::from_utf8_lossy(key).into_owned())
+}
+
+fn text(bytes: &[u8]) -> Text {
+    Text::from_printables(
+        bytes
+            .iter()
+            .filter_map(|byte| Printable::from_byte(*byte))
+            .collect(),
+    )
+}
+
+fn atom_list(
+    token: &Token,
+    atom: Natural,
+    list_type: ListType,
+) -> Result<Property, Error> {
+    if token.printables.first() != Some(&Printable::OpenBracket) {
+        return Err(token.error(0, vec![Printable::OpenBracket]));
+    } else if token.printables.len() < 2
+        || token.printables.last() != Some(&Printable::CloseBracket)
+    {
+        return Err(
+            token.error(token.printables.len(), vec![Printable::CloseBracket])
+        );
+    }
+
+    let mut elements = Vec::new();
+
+    for element in token
+        .slice(1, token.printables.len() - 1)
+        .split(Printable::Comma)
+    {
+        elements.push(element.left(Sequence::start(), 4)?);
+    }
+
+    Ok(Property::AtomList {
+        atom,
+        list_type,
+        elements,
+    })
+}
+
+fn bond(
+    mut tokens: Tokens,
+    expected: u32,
+    atom_count: u32,
+    properties: &mut Properties,
+) -> Result<Bond, Error> {
+    let index = index(tokens.next()?, expected)?;
+
+    let kind = tokens.next()?;
+    let bond_type = if kind.is("9") {
+        BondType::Coordination
+    } else if kind.is("10") {
+        BondType::Hydrogen
+    } else {
+        kind.right(BondType::start(), 3).at("bond_type")?
+    };
+    let first = tokens
+        .next()?
+        .build(Natural::start_reference(atom_count))
+        .at("first")?;
+    let second = tokens
+        .next()?
+        .build(Natural::start_reference(atom_count))
+        .at("second")?;
+    let mut bond = Bond {
+        first,
+        second,
+        bond_type,
+        stereo: BondStereo::NotStereo,
+        topology: BondTopology::Either,
+        reacting_center: ReactingCenter::Unmarked,
+    };
+
+    for token in tokens {
+        let (key, value) = token.keyword()?;
+
+        bond_keyword(&mut bond, &index, &key, value, properties)
+            .at(keyword(&key))?;
+    }
+
+    Ok(bond)
+}
+
+fn bond_keyword(
+    bond: &mut Bond,
+    index: &Natural,
+    key: &[u8],
+    value: Token,
+    properties: &mut Properties,
+) -> Result<(), Error> {
+    match key {
+        b"CFG" => {
+            bond.stereo = match value.bytes().as_slice() {
+                b"0" => BondStereo::NotStereo,
+                b"1" => BondStereo::Up,
+                b"2" => match bond.bond_type {
+                    BondType::Double => BondStereo::CisTransEither,
+                    _ => BondStereo::Either,
+                },
+                b"3" => BondStereo::Down,
+                _ => {
+                    return Err(ErrorKind::Character(
+                        value.row,
+                        value.column,
+                        vec![
+                            Printable::D0,
+                            Printable::D1,
+                            Printable::D2,
+                            Printable::D3,
+                        ],
+                    )
+                    .into())
+                }
+            }
+        }
+        b"TOPO" => bond.topology = value.right(BondTopology::start(), 3)?,
+        b"RXCTR" => {
+            bond.reacting_center = value.right(ReactingCenter::start(), 3)?
+        }
+        _ => properties.keywords.push(Property::BondKeyword {
+            bond: index.clone(),
+            keyword: text(key),
+            value: Text::from_printables(value.printables),
+        }),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::path::field;
+    use crate::{
+        molfile::{Counts, MoleculeName, Version},
+        primitive::{FixedReal, Line},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn header() -> Header {
+        Header {
+            molecule_name: MoleculeName::from_str("").unwrap(),
+            parameters: None,
+            comment: Line::from_str("").unwrap(),
+            counts: Counts {
+                atoms: FixedCount::Zero,
+                bonds: FixedCount::Zero,
+                atom_lists: FixedCount::Zero,
+                chiral: ChiralFlag::NotChiral,
+                stext: Some(FixedCount::Zero),
+                version: Version::V3,
+            },
+        }
+    }
+
+    fn read(lines: &[&str]) -> Result<Molfile, Error> {
+        let mut bytes = lines.join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        ctab(&mut reader, header())
+    }
+
+    fn zero() -> Vec<Digit> {
+        vec![Digit::d0(); 4]
+    }
+
+    #[test]
+    fn eof() {
+        assert_eq!(read(&[]), Err(field("v3000.ctab", ErrorKind::Eof(0))))
+    }
+
+    #[test]
+    fn bad_prefix() {
+        assert_eq!(
+            read(&["M  V31 BEGIN CTAB", ""]),
+            Err(field(
+                "v3000.ctab",
+                ErrorKind::Character(0, 5, vec![Printable::D0])
+            ))
+        )
+    }
+
+    #[test]
+    fn missing_atom_block() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 END CTAB",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab",
+                ErrorKind::Character(2, 7, vec![Printable::B])
+            ))
+        )
+    }
+
+    #[test]
+    fn unknown_block() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 0 0 0 0 0",
+                "M  V30 BEGIN WHATEVER",
+                ""
+            ]),
+            Err(field("v3000.ctab", ErrorKind::Keyword(2, 13)))
+        )
+    }
+
+    #[test]
+    fn out_of_sequence_atom() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 2 C 0 0 0 0",
+                ""
+            ]),
+            Err(field("v3000.ctab.atoms[0]", ErrorKind::Index(3, 7)))
+        )
+    }
+
+    #[test]
+    fn too_few_atoms() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 2 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0",
+                "M  V30 END ATOM",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.atoms[1]",
+                ErrorKind::Character(4, 7, Printable::non_zero_digits())
+            ))
+        )
+    }
+
+    #[test]
+    fn bond_atom_out_of_range() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 2 1 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0",
+                "M  V30 2 C 0 0 0 0",
+                "M  V30 END ATOM",
+                "M  V30 BEGIN BOND",
+                "M  V30 1 1 1 3",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.bonds[0].second",
+                ErrorKind::Reference(7, 13, 3, 2)
+            ))
+        )
+    }
+
+    #[test]
+    fn unknown_keywords() {
+        let molfile = read(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 2 1 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 0 CLASS=AA SEQID=3",
+            "M  V30 2 C 0 0 0 0",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN BOND",
+            "M  V30 1 1 1 2 ENDPTS=(2 1 2) ATTACH=ALL",
+            "M  V30 END BOND",
+            "M  V30 END CTAB",
+            "",
+        ])
+        .unwrap();
+        let keyword =
+            |atom: u32, keyword: &str, value: &str| Property::AtomKeyword {
+                atom: Natural::from_int(atom).unwrap(),
+                keyword: Text::from_str(keyword).unwrap(),
+                value: Text::from_str(value).unwrap(),
+            };
+
+        assert_eq!(
+            molfile.properties,
+            vec![
+                keyword(1, "CLASS", "AA"),
+                keyword(1, "SEQID", "3"),
+                Property::BondKeyword {
+                    bond: Natural::from_int(1).unwrap(),
+                    keyword: Text::from_str("ENDPTS").unwrap(),
+                    value: Text::from_str("(2 1 2)").unwrap()
+                },
+                Property::BondKeyword {
+                    bond: Natural::from_int(1).unwrap(),
+                    keyword: Text::from_str("ATTACH").unwrap(),
+                    value: Text::from_str("ALL").unwrap()
+                }
+            ]
+        )
+    }
+
+    #[test]
+    fn unspecified_hydrogen_count() {
+        let molfile = read(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 2 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 0 HCOUNT=0",
+            "M  V30 2 C 0 0 0 0 HCOUNT=2",
+            "M  V30 END ATOM",
+            "M  V30 END CTAB",
+            "",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            (
+                &molfile.atoms[0].hydrogen_count,
+                &molfile.atoms[1].hydrogen_count
+            ),
+            (&FixedCount::Zero, &FixedCount::from_int(3).unwrap())
+        )
+    }
+
+    #[test]
+    fn long_coordinates() {
+        let molfile = read(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C -1.299038 -0.750000 0.000000 0",
+            "M  V30 END ATOM",
+            "M  V30 END CTAB",
+            "",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            molfile.atoms[0].x,
+            FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d2(), Digit::d9(), Digit::d9(), Digit::d0()]
+            )
+        )
+    }
+
+    #[test]
+    fn bad_bond_configuration() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 2 1 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0",
+                "M  V30 2 C 0 0 0 0",
+                "M  V30 END ATOM",
+                "M  V30 BEGIN BOND",
+                "M  V30 1 1 1 2 CFG=5",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.bonds[0].CFG",
+                ErrorKind::Character(
+                    7,
+                    19,
+                    vec![
+                        Printable::D0,
+                        Printable::D1,
+                        Printable::D2,
+                        Printable::D3
+                    ]
+                )
+            ))
+        )
+    }
+
+    #[test]
+    fn bad_charge() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0 CHG=+1",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.atoms[0].CHG",
+                ErrorKind::Character(3, 23, {
+                    let mut allowed = vec![Printable::Space, Printable::Minus];
+
+                    allowed.extend(Printable::non_zero_digits());
+
+                    allowed
+                })
+            ))
+        )
+    }
+
+    #[test]
+    fn charge_out_of_range() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0 CHG=16",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.atoms[0].CHG",
+                ErrorKind::Range(3, 24, -15, 15)
+            ))
+        )
+    }
+
+    #[test]
+    fn not_list_continued() {
+        let molfile = read(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 \"NOT [N,-",
+            "M  V30 O]\" 0 0 0 0",
+            "M  V30 END ATOM",
+            "M  V30 END CTAB",
+            "",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            molfile.properties,
+            vec![Property::AtomList {
+                atom: Natural::from_int(1).unwrap(),
+                list_type: ListType::NotList,
+                elements: vec![
+                    Sequence::from_str("N   ").unwrap(),
+                    Sequence::from_str("O   ").unwrap()
+                ]
+            }]
+        )
+    }
+
+    #[test]
+    fn bad_not_list() {
+        assert_eq!(
+            read(&[
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 \"NOR [N,O]\" 0 0 0 0",
+                ""
+            ]),
+            Err(field(
+                "v3000.ctab.atoms[0].symbol",
+                ErrorKind::Character(3, 12, vec![Printable::T])
+            ))
+        )
+    }
+
+    #[test]
+    fn valid() {
+        let molfile = read(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 3 2 0 0 1 REGNO=1234",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C -1.5 0 0 0 CFG=1",
+            "M  V30 2 N 0 0.25 0 3 CHG=-1 MASS=15 RGROUPS=(2 1 2)",
+            "M  V30 3 [C,N] 1 0 0 0 HCOUNT=-1 VAL=-1",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN BOND",
+            "M  V30 1 1 1 2 CFG=3",
+            "M  V30 2 2 2 3 CFG=2 TOPO=1 RXCTR=-1",
+            "M  V30 END BOND",
+            "M  V30 END CTAB",
+            "",
+        ])
+        .unwrap();
+
+        assert_eq!(molfile.header.counts.chiral, ChiralFlag::Chiral);
+        assert_eq!(
+            molfile.atoms[0].x,
+            FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()]
+            )
+        );
+        assert_eq!(
+            molfile.atoms[0].y,
+            FixedReal::Integer(FixedInteger::Zero, zero())
+        );
+        assert_eq!(
+            molfile.atoms[0].stereo_parity,
+            FixedCount::from_int(1).unwrap()
+        );
+        assert_eq!(molfile.atoms[1].mapping, FixedCount::from_int(3).unwrap());
+        assert_eq!(molfile.atoms[2].symbol, Sequence::from_str("L  ").unwrap());
+        assert_eq!(
+            molfile.atoms[2].hydrogen_count,
+            FixedCount::from_int(1).unwrap()
+        );
+        assert_eq!(molfile.atoms[2].valence, FixedCount::from_int(15).unwrap());
+        assert_eq!(
+            molfile.bonds,
+            vec![
+                Bond {
+                    first: Natural::from_int(1).unwrap(),
+                    second: Natural::from_int(2).unwrap(),
+                    bond_type: BondType::Single,
+                    stereo: BondStereo::Down,
+                    topology: BondTopology::Either,
+                    reacting_center: ReactingCenter::Unmarked
+                },
+                Bond {
+                    first: Natural::from_int(2).unwrap(),
+                    second: Natural::from_int(3).unwrap(),
+                    bond_type: BondType::Double,
+                    stereo: BondStereo::CisTransEither,
+                    topology: BondTopology::Ring,
+                    reacting_center: ReactingCenter::NotCenter
+                }
+            ]
+        );
+        assert_eq!(
+            molfile.properties,
+            vec![
+                Property::Charge(vec![Entry {
+                    atom: Natural::from_int(2).unwrap(),
+                    value: FixedInteger::from_int(-1).unwrap()
+                }]),
+                Property::Isotope(vec![Entry {
+                    atom: Natural::from_int(2).unwrap(),
+                    value: FixedCount::from_int(15).unwrap()
+                }]),
+                Property::AtomList {
+                    atom: Natural::from_int(3).unwrap(),
+                    list_type: ListType::List,
+                    elements: vec![
+                        Sequence::from_str("C   ").unwrap(),
+                        Sequence::from_str("N   ").unwrap()
+                    ]
+                },
+                Property::RgroupLabel(vec![
+                    Entry {
+                        atom: Natural::from_int(2).unwrap(),
+                        value: Natural::from_int(1).unwrap()
+                    },
+                    Entry {
+                        atom: Natural::from_int(2).unwrap(),
+                        value: Natural::from_int(2).unwrap()
+                    }
+                ]),
+                Property::Registry(Natural::from_int(1234).unwrap())
+            ]
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    build::Target,
+    primitive::{Line, Literal},
+    text::Printable,
+};
+
+use super::{
+    super::{Error, ErrorKind, Reader},
+    token::{tokenize, Token},
+};
+
+pub struct Tokens {
+    row: usize,
+    tokens: std::vec::IntoIter<Token>,
+}
+
+impl Tokens {
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Token, Error> {
+        self.tokens.next().ok_or(ErrorKind::Eol(self.row).into())
+    }
+
+    pub fn finish(mut self) -> Result<(), Error> {
+        match self.tokens.next() {
+            Some(token) => {
+                Err(ErrorKind::Overflow(token.row, token.column).into())
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl IntoIterator for Tokens {
+    type Item = Token;
+    type IntoIter = std::vec::IntoIter<Token>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tokens
+    }
+}
+
+pub fn line(reader: &mut Reader) -> Result<Tokens, Error> {
+    reader.read(Target::Builder(Literal::start(b"M  V30 ")))?;
+
+    line_rest(reader)
+}
+
+// the rest of a line whose "M  V30 " prefix has already been read
+pub fn line_rest(reader: &mut Reader) -> Result<Tokens, Error> {
+    let mut characters = Vec::new();
+
+    loop {
+        let row = reader.row;
+        let column = reader.column;
+        let content = reader.read_line(Target::Builder(Line::<73>::start()))?;
+
+        characters.extend(
+            content
+                .printables()
+                .iter()
+                .enumerate()
+                .map(|(index, printable)| (row, column + index, *printable)),
+        );
+
+        if characters.last().map(|(_, _, printable)| printable)
+            == Some(&Printable::Minus)
+        {
+            characters.pop();
+            reader.read(Target::Builder(Literal::start(b"M  V30 ")))?;
+        } else {
+            let end = (row, column + content.printables().len());
+
+            break Ok(Tokens {
+                row,
+                tokens: tokenize(&characters, end)?.into_iter(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Result<Vec<Vec<u8>>, Error> {
+        let mut bytes = lines.join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        Ok(line(&mut reader)?.into_iter().map(|t| t.bytes()).collect())
+    }
+
+    #[test]
+    fn eof() {
+        assert_eq!(read(&[]), Err(ErrorKind::Eof(0).into()))
+    }
+
+    #[test]
+    fn bad_prefix() {
+        assert_eq!(
+            read(&["M  V31 BEGIN CTAB", ""]),
+            Err(ErrorKind::Character(0, 5, vec![Printable::D0]).into())
+        )
+    }
+
+    #[test]
+    fn too_long() {
+        assert_eq!(
+            read(&[&format!("M  V30 {}", "X".repeat(74)), ""]),
+            Err(ErrorKind::Overflow(0, 80).into())
+        )
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            read(&["M  V30 1 C 0 0 0 0", ""]),
+            Ok(vec![
+                b"1".to_vec(),
+                b"C".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec()
+            ])
+        )
+    }
+
+    #[test]
+    fn continuation() {
+        assert_eq!(
+            read(&[
+                "M  V30 1 C 0 0 0 0 CHG=-",
+                "M  V30 1 MASS=1-",
+                "M  V30 3",
+                ""
+            ]),
+            Ok(vec![
+                b"1".to_vec(),
+                b"C".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec(),
+                b"0".to_vec(),
+                b"CHG=1".to_vec(),
+                b"MASS=13".to_vec()
+            ])
+        )
+    }
+
+    #[test]
+    fn continuation_without_next() {
+        assert_eq!(
+            read(&["M  V30 1 C-", "M  END", ""]),
+            Err(ErrorKind::Character(1, 3, vec![Printable::V]).into())
+        )
+    }
+
+    #[test]
+    fn continuation_position() {
+        let mut bytes = ["M  V30 1 C 0 0 0 0 CHG=-", "M  V30 x", ""]
+            .join("\n")
+            .into_bytes()
+            .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+        let mut tokens = line(&mut reader).unwrap().into_iter();
+        let (_, value) = tokens.nth(6).unwrap().keyword().unwrap();
+
+        assert_eq!((value.row, value.column), (1, 7))
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert_eq!(
+            read(&["M  V30 1 \"NOT [N,O]", ""]),
+            Err(ErrorKind::Character(0, 19, vec![Printable::DoubleQuote])
+                .into())
+        )
+    }
+}
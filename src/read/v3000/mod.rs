@@ -0,0 +1,9 @@
+mod collection_block;
+mod ctab;
+mod line;
+mod sgroup_block;
+mod token;
+
+pub use ctab::{ctab, ctab_rest};
+pub use line::{line, line_rest, Tokens};
+pub use token::Token;
@@ -0,0 +1,406 @@
+use crate::{
+    molfile::{
+        Bracket, BracketStyle, Connectivity, CrossingVector, Sgroup,
+        SgroupAttachment, SgroupSubtype, SgroupType,
+    },
+    primitive::{FixedReal, Natural, Sequence},
+    text::Printable,
+};
+
+use super::{
+    super::{parents::parents, Error, ErrorKind, Reader},
+    line::{line, Tokens},
+    token::Token,
+};
+
+pub fn sgroup_block(
+    reader: &mut Reader,
+    sgroup_count: u32,
+    atom_count: u32,
+    bond_count: u32,
+) -> Result<Vec<Sgroup>, Error> {
+    let mut result: Vec<Sgroup> = Vec::new();
+    let mut references = Vec::new();
+
+    for _ in 0..sgroup_count {
+        let mut tokens = line(reader)?;
+        let index = tokens.next()?;
+        let (sgroup, parent) = sgroup(
+            tokens,
+            index.build(Natural::start())?,
+            atom_count,
+            bond_count,
+        )?;
+
+        if result.iter().any(|other| other.index == sgroup.index) {
+            return Err(ErrorKind::Index(index.row, index.column).into());
+        }
+
+        if let Some(parent) = parent {
+            references.push((result.len(), parent.row, parent.column))
+        }
+
+        result.push(sgroup);
+    }
+
+    parents(&result, &references)?;
+
+    Ok(result)
+}
+
+fn sgroup(
+    mut tokens: Tokens,
+    index: Natural,
+    atom_count: u32,
+    bond_count: u32,
+) -> Result<(Sgroup, Option<Token>), Error> {
+    let kind = tokens.next()?;
+    let sgroup_type = match kind.bytes().as_slice() {
+        b"SUP" => SgroupType::Superatom,
+        b"MUL" => SgroupType::Multiple,
+        b"SRU" => SgroupType::StructureRepeatingUnit,
+        b"MON" => SgroupType::Monomer,
+        b"COP" => SgroupType::Copolymer,
+        b"DAT" => SgroupType::Data,
+        b"GEN" => SgroupType::Generic,
+        b"FOR" => SgroupType::Formulation,
+        b"ANY" => SgroupType::AnyPolymer,
+        b"COM" => SgroupType::Component,
+        b"MIX" => SgroupType::Mixture,
+        _ => return Err(ErrorKind::Keyword(kind.row, kind.column).into()),
+    };
+    let mut sgroup = Sgroup::new(index, sgroup_type);
+    let mut parent = None;
+
+    sgroup.external_index = optional(&tokens.next()?)?;
+
+    for token in tokens {
+        let (key, value) = token.keyword()?;
+
+        match key.as_slice() {
+            b"ATOMS" => sgroup.atoms = references(&value, atom_count)?,
+            b"XBONDS" => {
+                sgroup.crossing_bonds = references(&value, bond_count)?
+            }
+            b"CBONDS" => {
+                sgroup.containment_bonds = references(&value, bond_count)?
+            }
+            b"PATOMS" => {
+                sgroup.paradigmatic_atoms = references(&value, atom_count)?
+            }
+            b"XBHEAD" => sgroup.head_bonds = references(&value, bond_count)?,
+            b"XBCORR" => {
+                sgroup.bond_correspondence = references(&value, bond_count)?
+            }
+            b"SUBTYPE" => {
+                sgroup.subtype = Some(match value.bytes().as_slice() {
+                    b"ALT" => SgroupSubtype::Alternating,
+                    b"RAN" => SgroupSubtype::Random,
+                    b"BLO" => SgroupSubtype::Block,
+                    _ => {
+                        return Err(
+                            ErrorKind::Keyword(value.row, value.column).into()
+                        )
+                    }
+                })
+            }
+            b"CONNECT" => {
+                sgroup.connectivity = Some(match value.bytes().as_slice() {
+                    b"HH" => Connectivity::HeadToHead,
+                    b"HT" => Connectivity::HeadToTail,
+                    b"EU" => Connectivity::EitherUnknown,
+                    _ => {
+                        return Err(
+                            ErrorKind::Keyword(value.row, value.column).into()
+                        )
+                    }
+                })
+            }
+            b"MULT" => sgroup.multiplier = Some(value.build(Natural::start())?),
+            b"PARENT" => {
+                sgroup.parent = Some(value.build(Natural::start())?);
+                parent = Some(value)
+            }
+            b"COMPNO" => {
+                sgroup.component = Some(value.build(Natural::start())?)
+            }
+            b"LABEL" => sgroup.label = Some(value.text()?),
+            b"BRKXYZ" => {
+                let [x1, y1, _, x2, y2, _, _, _, _]: [FixedReal<5, 4>; 9] =
+                    reals(&sized(&value, 9)?)?
+                        .try_into()
+                        .expect("nine coordinates");
+
+                sgroup.brackets.push(Bracket { x1, y1, x2, y2 })
+            }
+            b"BRKTYP" => {
+                sgroup.bracket_style = Some(match value.bytes().as_slice() {
+                    b"BRACKET" => BracketStyle::Bracket,
+                    b"PAREN" => BracketStyle::Parenthesis,
+                    _ => {
+                        return Err(
+                            ErrorKind::Keyword(value.row, value.column).into()
+                        )
+                    }
+                })
+            }
+            b"ESTATE" => {
+                value.expect("E")?;
+
+                sgroup.expanded = true
+            }
+            b"CSTATE" => {
+                let items = sized(&value, 4)?;
+                let [x, y, _]: [FixedReal<5, 4>; 3] =
+                    reals(&items[1..])?.try_into().expect("three coordinates");
+
+                sgroup.crossing_vectors.push(CrossingVector {
+                    bond: reference(&items[0], bond_count)?,
+                    x,
+                    y,
+                })
+            }
+            b"SAP" => {
+                let items = sized(&value, 3)?;
+                let leaving_atom = if items[1].is("0") {
+                    None
+                } else {
+                    Some(reference(&items[1], atom_count)?)
+                };
+                let id = if items[2].is_quoted() {
+                    items[2].unquote()?
+                } else {
+                    items[2].clone()
+                };
+
+                sgroup.attachments.push(SgroupAttachment {
+                    atom: reference(&items[0], atom_count)?,
+                    leaving_atom,
+                    id: id.left(Sequence::start(), 2)?,
+                })
+            }
+            b"CLASS" => sgroup.class = Some(value.text()?),
+            b"FIELDNAME" => sgroup.field_name = Some(value.text()?),
+            b"FIELDINFO" => sgroup.field_info = Some(value.text()?),
+            b"FIELDDISP" => sgroup.field_display = Some(value.text()?),
+            b"QUERYTYPE" => sgroup.query_type = Some(value.text()?),
+            b"QUERYOP" => sgroup.query_operator = Some(value.text()?),
+            b"FIELDDATA" => sgroup.field_data.push(value.text()?),
+            _ => return Err(ErrorKind::Keyword(token.row, token.column).into()),
+        }
+    }
+
+    Ok((sgroup, parent))
+}
+
+fn optional(token: &Token) -> Result<Option<Natural>, Error> {
+    if token.is("0") {
+        Ok(None)
+    } else {
+        Ok(Some(token.build(Natural::start())?))
+    }
+}
+
+fn reference(token: &Token, count: u32) -> Result<Natural, Error> {
+    token.build(Natural::start_reference(count))
+}
+
+fn references(token: &Token, count: u32) -> Result<Vec<Natural>, Error> {
+    token
+        .list()?
+        .iter()
+        .map(|item| reference(item, count))
+        .collect()
+}
+
+fn reals(tokens: &[Token]) -> Result<Vec<FixedReal<5, 4>>, Error> {
+    tokens.iter().map(Token::real).collect()
+}
+
+fn sized(token: &Token, size: usize) -> Result<Vec<Token>, Error> {
+    let result = token.list()?;
+
+    if result.len() == size {
+        Ok(result)
+    } else {
+        Err(token.error(
+            1,
+            Printable::from_byte(b'0' + size as u8)
+                .into_iter()
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::Molfile,
+        primitive::{FixedInteger, Text},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Result<Vec<Sgroup>, Error> {
+        let mut bytes = lines.join("\n").into_bytes().into_iter();
+        let mut reader = Reader::new(&mut bytes);
+
+        let count = lines
+            .iter()
+            .filter(|line| !line.is_empty() && !line.ends_with('-'))
+            .count();
+
+        sgroup_block(&mut reader, count as u32, 3, 2)
+    }
+
+    fn natural(value: u32) -> Natural {
+        Natural::from_int(value).unwrap()
+    }
+
+    #[test]
+    fn unknown_type() {
+        assert_eq!(
+            read(&["M  V30 1 XYZ 0", ""]),
+            Err(ErrorKind::Keyword(0, 9).into())
+        )
+    }
+
+    #[test]
+    fn unknown_keyword() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0 FOO=1", ""]),
+            Err(ErrorKind::Keyword(0, 15).into())
+        )
+    }
+
+    #[test]
+    fn atom_out_of_range() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0 ATOMS=(2 1 4)", ""]),
+            Err(ErrorKind::Reference(0, 26, 4, 3).into())
+        )
+    }
+
+    #[test]
+    fn bond_out_of_range() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0 XBONDS=(1 3)", ""]),
+            Err(ErrorKind::Reference(0, 25, 3, 2).into())
+        )
+    }
+
+    #[test]
+    fn duplicate_index() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0", "M  V30 1 DAT 0", ""]),
+            Err(ErrorKind::Index(1, 7).into())
+        )
+    }
+
+    #[test]
+    fn missing_parent() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0", "M  V30 2 DAT 0 PARENT=3", ""]),
+            Err(ErrorKind::Index(1, 22).into())
+        )
+    }
+
+    #[test]
+    fn cyclic_parent() {
+        assert_eq!(
+            read(&["M  V30 1 SUP 0 PARENT=2", "M  V30 2 DAT 0 PARENT=1", ""]),
+            Err(ErrorKind::Index(0, 22).into())
+        )
+    }
+
+    #[test]
+    fn short_bracket() {
+        assert_eq!(
+            read(&["M  V30 1 SRU 0 BRKXYZ=(3 0 0 0)", ""]),
+            Err(ErrorKind::Character(0, 23, vec![Printable::D9]).into())
+        )
+    }
+
+    #[test]
+    fn valid() {
+        let sgroups = read(&[
+            "M  V30 1 SUP 1 ATOMS=(2 2 3) XBONDS=(1 1) LABEL=\"C O2\" -",
+            "M  V30 SAP=(3 2 1 1) ESTATE=E CSTATE=(4 1 -1.5 0 0) CLASS=AA",
+            "M  V30 2 DAT 0 ATOMS=(1 1) FIELDNAME=pKa FIELDDATA=\"4.7 \"\"est\"\"\" -",
+            "M  V30 PARENT=1 BRKXYZ=(9 1 2 0 1 3 0 0 0 0) BRKTYP=PAREN",
+            "",
+        ])
+        .unwrap();
+        let mut superatom = Sgroup::new(natural(1), SgroupType::Superatom);
+        let mut data = Sgroup::new(natural(2), SgroupType::Data);
+        let zero = || vec![Digit::d0(); 4];
+        let real = |value| {
+            FixedReal::Integer(FixedInteger::from_int(value).unwrap(), zero())
+        };
+
+        superatom.external_index = Some(natural(1));
+        superatom.atoms = vec![natural(2), natural(3)];
+        superatom.crossing_bonds = vec![natural(1)];
+        superatom.label = Text::from_str("C O2");
+        superatom.attachments = vec![SgroupAttachment {
+            atom: natural(2),
+            leaving_atom: Some(natural(1)),
+            id: Sequence::from_str("1 ").unwrap(),
+        }];
+        superatom.expanded = true;
+        superatom.crossing_vectors = vec![CrossingVector {
+            bond: natural(1),
+            x: FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()],
+            ),
+            y: real(0),
+        }];
+        superatom.class = Text::from_str("AA");
+        data.atoms = vec![natural(1)];
+        data.field_name = Text::from_str("pKa");
+        data.field_data = vec![Text::from_str("4.7 \"est\"").unwrap()];
+        data.parent = Some(natural(1));
+        data.brackets = vec![Bracket {
+            x1: real(1),
+            y1: real(2),
+            x2: real(1),
+            y2: real(3),
+        }];
+        data.bracket_style = Some(BracketStyle::Parenthesis);
+
+        assert_eq!(sgroups, vec![superatom, data])
+    }
+
+    #[test]
+    fn parent() {
+        let mut bytes = [
+            "",
+            "",
+            "",
+            "  0  0  0     0  0            999 V3000",
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 2 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 0",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN SGROUP",
+            "M  V30 1 SUP 0 ATOMS=(1 1)",
+            "M  V30 2 DAT 0 PARENT=1",
+            "M  V30 END SGROUP",
+            "M  V30 END CTAB",
+            "M  END",
+        ]
+        .join("\n")
+        .into_bytes()
+        .into_iter();
+        let mut reader = Reader::new(&mut bytes);
+        let molfile: Molfile = crate::read::molfile(&mut reader).unwrap();
+
+        assert_eq!(
+            molfile.parent(&molfile.sgroups[1]),
+            Some(&molfile.sgroups[0])
+        )
+    }
+}
@@ -0,0 +1,758 @@
+use crate::{
+    build::{self, Builder, Target},
+    primitive::{FixedReal, Natural, Text},
+    text::Printable,
+};
+
+use super::super::{Error, ErrorKind};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Token {
+    pub row: usize,
+    pub column: usize,
+    pub printables: Vec<Printable>,
+    positions: Vec<(usize, usize)>,
+    end: (usize, usize),
+}
+
+impl Token {
+    pub fn bytes(&self) -> Vec<u8> {
+        self.printables.iter().map(Printable::to_byte).collect()
+    }
+
+    pub fn is(&self, str: &str) -> bool {
+        self.bytes() == str.as_bytes()
+    }
+
+    pub fn at(&self, index: usize) -> (usize, usize) {
+        match self.positions.get(index) {
+            Some(position) => *position,
+            None => self.end,
+        }
+    }
+
+    pub fn end(&self) -> (usize, usize) {
+        self.end
+    }
+
+    pub fn expect(&self, str: &str) -> Result<(), Error> {
+        for (index, byte) in str.bytes().enumerate() {
+            match self.printables.get(index) {
+                Some(printable) if printable.to_byte() == byte => (),
+                _ => {
+                    return Err(self.error(
+                        index,
+                        Printable::from_byte(byte).into_iter().collect(),
+                    ))
+                }
+            }
+        }
+
+        if self.printables.len() > str.len() {
+            Err(self.error(str.len(), vec![Printable::Space]))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn error(&self, index: usize, allowed: Vec<Printable>) -> Error {
+        let (row, column) = self.at(index);
+
+        ErrorKind::Character(row, column, allowed).into()
+    }
+
+    pub fn slice(&self, start: usize, end: usize) -> Token {
+        let (row, column) = self.at(start);
+
+        Token {
+            row,
+            column,
+            printables: self.printables[start..end].to_vec(),
+            positions: self.positions[start..end].to_vec(),
+            end: self.at(end),
+        }
+    }
+
+    pub fn split(&self, separator: Printable) -> Vec<Token> {
+        let mut result = Vec::new();
+        let mut start = 0;
+
+        for (index, printable) in self.printables.iter().enumerate() {
+            if *printable == separator {
+                result.push(self.slice(start, index));
+
+                start = index + 1;
+            }
+        }
+
+        result.push(self.slice(start, self.printables.len()));
+
+        result
+    }
+
+    pub fn keyword(&self) -> Result<(Vec<u8>, Token), Error> {
+        match self.printables.iter().position(|p| p == &Printable::Equal) {
+            Some(index) => Ok((
+                self.slice(0, index).bytes(),
+                self.slice(index + 1, self.printables.len()),
+            )),
+            None => Err(ErrorKind::Keyword(self.row, self.column).into()),
+        }
+    }
+
+    pub fn is_quoted(&self) -> bool {
+        self.printables.first() == Some(&Printable::DoubleQuote)
+    }
+
+    pub fn unquote(&self) -> Result<Token, Error> {
+        if !self.is_quoted() {
+            return Err(self.error(0, vec![Printable::DoubleQuote]));
+        }
+
+        let mut printables = Vec::new();
+        let mut positions = Vec::new();
+        let mut index = 1;
+
+        loop {
+            match self.printables.get(index) {
+                Some(Printable::DoubleQuote) => {
+                    if self.printables.get(index + 1)
+                        == Some(&Printable::DoubleQuote)
+                    {
+                        printables.push(Printable::DoubleQuote);
+                        positions.push(self.at(index));
+
+                        index += 2;
+                    } else if index + 1 == self.printables.len() {
+                        break;
+                    } else {
+                        return Err(
+                            self.error(index + 1, vec![Printable::DoubleQuote])
+                        );
+                    }
+                }
+                Some(printable) => {
+                    printables.push(*printable);
+                    positions.push(self.at(index));
+
+                    index += 1;
+                }
+                None => {
+                    return Err(self.error(index, vec![Printable::DoubleQuote]))
+                }
+            }
+        }
+
+        let (row, column) = self.at(1);
+
+        Ok(Token {
+            row,
+            column,
+            printables,
+            positions,
+            end: self.at(index),
+        })
+    }
+
+    pub fn text(&self) -> Result<Text, Error> {
+        if self.is_quoted() {
+            self.unquote()?.build(Text::start())
+        } else {
+            self.build(Text::start())
+        }
+    }
+
+    pub fn build<P, B: Builder<Product = P>>(
+        &self,
+        builder: B,
+    ) -> Result<P, Error> {
+        let items = self.items(0, self.printables.len());
+
+        feed(self.end, builder, items)
+    }
+
+    pub fn right<P, B: Builder<Product = P>>(
+        &self,
+        builder: B,
+        width: usize,
+    ) -> Result<P, Error> {
+        if self.printables.len() > width {
+            let (row, column) = self.at(width);
+
+            return Err(ErrorKind::Overflow(row, column).into());
+        }
+
+        let mut items = vec![
+            (self.row, self.column, Printable::Space);
+            width - self.printables.len()
+        ];
+
+        items.append(&mut self.items(0, self.printables.len()));
+
+        feed(self.end, builder, items)
+    }
+
+    pub fn left<P, B: Builder<Product = P>>(
+        &self,
+        builder: B,
+        width: usize,
+    ) -> Result<P, Error> {
+        if self.printables.len() > width {
+            let (row, column) = self.at(width);
+
+            return Err(ErrorKind::Overflow(row, column).into());
+        }
+
+        let mut items = self.items(0, self.printables.len());
+
+        items.append(&mut vec![
+            (self.end.0, self.end.1, Printable::Space);
+            width - self.printables.len()
+        ]);
+
+        feed(self.end, builder, items)
+    }
+
+    pub fn real<const I: usize, const F: usize>(
+        &self,
+    ) -> Result<FixedReal<I, F>, Error> {
+        let mut items = self.items(0, self.printables.len());
+        let mut dot = items
+            .iter()
+            .position(|(_, _, printable)| printable == &Printable::Dot)
+            .unwrap_or(items.len());
+
+        // digits past the model's precision round half away from zero
+        if items.len() > dot + 1 + F {
+            let rest = items.split_off(dot + 1 + F);
+
+            for (row, column, printable) in &rest {
+                if !Printable::digits().contains(printable) {
+                    return Err(ErrorKind::Character(
+                        *row,
+                        *column,
+                        Printable::digits(),
+                    )
+                    .into());
+                }
+            }
+
+            if rest[0].2.to_byte() >= b'5' && round_up(&mut items) {
+                dot += 1;
+            }
+        }
+
+        let fraction = items.len() - (dot + 1).min(items.len());
+
+        if dot > I {
+            let (row, column) = self.at(I);
+
+            return Err(ErrorKind::Overflow(row, column).into());
+        }
+
+        let (row, column) = self.end;
+        let mut padded =
+            vec![(self.row, self.column, Printable::Space); I - dot];
+
+        if dot == items.len() {
+            padded.append(&mut items);
+            padded.push((row, column, Printable::Dot));
+        } else {
+            padded.append(&mut items);
+        }
+
+        padded.append(&mut vec![(row, column, Printable::D0); F - fraction]);
+
+        feed(self.end, FixedReal::start(), padded)
+    }
+
+    pub fn list(&self) -> Result<Vec<Token>, Error> {
+        if self.printables.first() != Some(&Printable::OpenParen) {
+            return Err(self.error(0, vec![Printable::OpenParen]));
+        } else if self.printables.len() < 2
+            || self.printables.last() != Some(&Printable::CloseParen)
+        {
+            return Err(
+                self.error(self.printables.len(), vec![Printable::CloseParen])
+            );
+        }
+
+        let inner = self.slice(1, self.printables.len() - 1);
+        let mut items = inner
+            .split(Printable::Space)
+            .into_iter()
+            .filter(|token| !token.printables.is_empty());
+        let count = match items.next() {
+            Some(token) => token.count()?,
+            None => return Err(inner.error(0, Printable::digits())),
+        };
+        let result = items.collect::<Vec<_>>();
+
+        if result.len() < count as usize {
+            let (row, column) = inner.at(inner.printables.len());
+
+            Err(Error::from_build(
+                row,
+                column,
+                build::Error::Length(count as usize),
+            ))
+        } else if result.len() > count as usize {
+            let extra = &result[count as usize];
+
+            Err(Error::from_build(
+                extra.row,
+                extra.column,
+                build::Error::Length(count as usize),
+            ))
+        } else {
+            Ok(result)
+        }
+    }
+
+    pub fn count(&self) -> Result<u32, Error> {
+        if self.is("0") {
+            Ok(0)
+        } else {
+            Ok(self.build(Natural::start())?.to_int())
+        }
+    }
+
+    fn items(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Vec<(usize, usize, Printable)> {
+        (start..end)
+            .map(|index| {
+                let (row, column) = self.at(index);
+
+                (row, column, self.printables[index])
+            })
+            .collect()
+    }
+}
+
+pub fn tokenize(
+    characters: &[(usize, usize, Printable)],
+    end: (usize, usize),
+) -> Result<Vec<Token>, Error> {
+    let mut result = Vec::new();
+    let mut current: Option<Token> = None;
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for (row, column, printable) in characters {
+        if *printable == Printable::Space && depth == 0 && !quoted {
+            if let Some(mut token) = current.take() {
+                token.end = (*row, *column);
+
+                result.push(token)
+            }
+
+            continue;
+        }
+
+        match printable {
+            Printable::DoubleQuote => quoted = !quoted,
+            Printable::OpenParen if !quoted => depth += 1,
+            Printable::CloseParen if !quoted && depth > 0 => depth -= 1,
+            _ => (),
+        }
+
+        let token = current.get_or_insert_with(|| Token {
+            row: *row,
+            column: *column,
+            printables: Vec::new(),
+            positions: Vec::new(),
+            end,
+        });
+
+        token.printables.push(*printable);
+        token.positions.push((*row, *column));
+    }
+
+    if quoted {
+        return Err(ErrorKind::Character(
+            end.0,
+            end.1,
+            vec![Printable::DoubleQuote],
+        )
+        .into());
+    }
+
+    result.extend(current);
+
+    Ok(result)
+}
+
+// adds one in the last place, returning true if a digit was prepended
+fn round_up(items: &mut Vec<(usize, usize, Printable)>) -> bool {
+    for (_, _, printable) in items.iter_mut().rev() {
+        match printable {
+            Printable::D9 => *printable = Printable::D0,
+            Printable::Dot => (),
+            Printable::Minus => break,
+            digit => {
+                let digits = Printable::digits();
+
+                // nine was handled above, so a digit always has a successor
+                if let Some(index) = digits.iter().position(|d| d == digit) {
+                    *digit = digits[index + 1]
+                }
+
+                return false;
+            }
+        }
+    }
+
+    let index = match items.first() {
+        Some((_, _, Printable::Minus)) => 1,
+        _ => 0,
+    };
+    let (row, column, _) = items[index.min(items.len() - 1)];
+
+    items.insert(index, (row, column, Printable::D1));
+
+    true
+}
+
+fn feed<P, B: Builder<Product = P>>(
+    end: (usize, usize),
+    mut builder: B,
+    items: Vec<(usize, usize, Printable)>,
+) -> Result<P, Error> {
+    let mut items = items.into_iter().peekable();
+
+    while let Some((row, column, printable)) = items.next() {
+        builder = match builder.push(printable) {
+            Ok(Target::Builder(builder)) => builder,
+            Ok(Target::Product(product)) => {
+                return match items.peek() {
+                    Some((row, column, _)) => {
+                        Err(ErrorKind::Overflow(*row, *column).into())
+                    }
+                    None => Ok(product),
+                }
+            }
+            Err(error) => return Err(Error::from_build(row, column, error)),
+        }
+    }
+
+    builder
+        .finish()
+        .map_err(|error| Error::from_build(end.0, end.1, error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::{FixedCount, FixedInteger, Sequence},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn characters(
+        row: usize,
+        column: usize,
+        str: &str,
+    ) -> Vec<(usize, usize, Printable)> {
+        str.bytes()
+            .enumerate()
+            .map(|(i, b)| (row, column + i, Printable::from_byte(b).unwrap()))
+            .collect()
+    }
+
+    fn tokens(str: &str) -> Result<Vec<Token>, Error> {
+        tokenize(&characters(0, 7, str), (0, 7 + str.len()))
+    }
+
+    fn token(str: &str) -> Token {
+        tokens(str).unwrap().pop().unwrap()
+    }
+
+    fn strings(tokens: &[Token]) -> Vec<String> {
+        tokens
+            .iter()
+            .map(|token| String::from_utf8(token.bytes()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tokenize_spaces() {
+        let tokens = tokens("1  C").unwrap();
+
+        assert_eq!(strings(&tokens), vec!["1", "C"]);
+        assert_eq!(
+            tokens.iter().map(|t| t.column).collect::<Vec<_>>(),
+            vec![7, 10]
+        )
+    }
+
+    #[test]
+    fn tokenize_parens() {
+        let tokens = tokens("RGROUPS=(2 1 3) X").unwrap();
+
+        assert_eq!(strings(&tokens), vec!["RGROUPS=(2 1 3)", "X"]);
+        assert_eq!(tokens[1].column, 23)
+    }
+
+    #[test]
+    fn tokenize_quotes() {
+        assert_eq!(
+            strings(&tokens(r#"1 "NOT [N,O]" FIELDDATA="a ""(b""""#).unwrap()),
+            vec!["1", r#""NOT [N,O]""#, r#"FIELDDATA="a ""(b""""#]
+        )
+    }
+
+    #[test]
+    fn tokenize_unterminated_quote() {
+        assert_eq!(
+            tokens(r#"1 "NOT [N,O]"#),
+            Err(ErrorKind::Character(0, 19, vec![Printable::DoubleQuote])
+                .into())
+        )
+    }
+
+    #[test]
+    fn tokenize_across_rows() {
+        let mut characters = characters(0, 77, "1 C");
+
+        characters.append(&mut self::characters(1, 7, "l 0"));
+
+        let tokens = tokenize(&characters, (1, 10)).unwrap();
+
+        assert_eq!(strings(&tokens), vec!["1", "Cl", "0"]);
+        assert_eq!(tokens[1].at(1), (1, 7));
+        assert_eq!(
+            tokens[1].right(FixedCount::<3>::start(), 3),
+            Err(ErrorKind::Character(0, 79, Printable::digits()).into())
+        )
+    }
+
+    #[test]
+    fn expect_mismatch() {
+        assert_eq!(
+            token("BEGIM").expect("BEGIN"),
+            Err(ErrorKind::Character(0, 11, vec![Printable::N]).into())
+        )
+    }
+
+    #[test]
+    fn expect_long() {
+        assert_eq!(
+            token("BEGINS").expect("BEGIN"),
+            Err(ErrorKind::Character(0, 12, vec![Printable::Space]).into())
+        )
+    }
+
+    #[test]
+    fn keyword_missing_equal() {
+        assert_eq!(token("CHG").keyword(), Err(ErrorKind::Keyword(0, 7).into()))
+    }
+
+    #[test]
+    fn keyword() {
+        let (key, value) = token("CHG=-1").keyword().unwrap();
+
+        assert_eq!(
+            (key, value.bytes(), value.column),
+            (b"CHG".to_vec(), b"-1".to_vec(), 11)
+        )
+    }
+
+    #[test]
+    fn unquote() {
+        let token = token(r#""a ""b""""#).unquote().unwrap();
+
+        assert_eq!(
+            (token.bytes(), token.column, token.at(3)),
+            (br#"a "b""#.to_vec(), 8, (0, 12))
+        )
+    }
+
+    #[test]
+    fn unquote_empty() {
+        assert_eq!(token(r#""""#).unquote().unwrap().bytes(), b"".to_vec())
+    }
+
+    #[test]
+    fn unquote_bare() {
+        assert_eq!(
+            token("abc").unquote(),
+            Err(ErrorKind::Character(0, 7, vec![Printable::DoubleQuote]).into())
+        )
+    }
+
+    #[test]
+    fn unquote_trailing() {
+        assert_eq!(
+            token(r#""a"b"#).unquote(),
+            Err(ErrorKind::Character(0, 10, vec![Printable::DoubleQuote])
+                .into())
+        )
+    }
+
+    #[test]
+    fn text_quoted() {
+        assert_eq!(token(r#""a b""#).text(), Ok(Text::from_str("a b").unwrap()))
+    }
+
+    #[test]
+    fn text_bare() {
+        assert_eq!(token("ab").text(), Ok(Text::from_str("ab").unwrap()))
+    }
+
+    #[test]
+    fn right_integer() {
+        assert_eq!(
+            token("-1").right(FixedInteger::<3>::start(), 3),
+            Ok(FixedInteger::from_int(-1).unwrap())
+        )
+    }
+
+    #[test]
+    fn right_too_wide() {
+        assert_eq!(
+            token("1000").right(FixedCount::<3>::start(), 3),
+            Err(ErrorKind::Overflow(0, 10).into())
+        )
+    }
+
+    #[test]
+    fn right_bad_character() {
+        assert_eq!(
+            token("1x").right(FixedCount::<3>::start(), 3),
+            Err(ErrorKind::Character(0, 8, Printable::digits()).into())
+        )
+    }
+
+    #[test]
+    fn left_symbol() {
+        assert_eq!(
+            token("Cl").left(Sequence::<3>::start(), 3),
+            Ok(Sequence::from_str("Cl ").unwrap())
+        )
+    }
+
+    #[test]
+    fn real_short_fraction() {
+        assert_eq!(
+            token("-1.5").real::<5, 4>(),
+            Ok(FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()]
+            ))
+        )
+    }
+
+    #[test]
+    fn real_no_dot() {
+        assert_eq!(
+            token("2").real::<5, 4>(),
+            Ok(FixedReal::Integer(
+                FixedInteger::from_int(2).unwrap(),
+                vec![Digit::d0(); 4]
+            ))
+        )
+    }
+
+    #[test]
+    fn real_negative_zero() {
+        assert_eq!(
+            token("-0.25").real::<5, 4>(),
+            Ok(FixedReal::NegativeZero(vec![
+                Digit::d2(),
+                Digit::d5(),
+                Digit::d0(),
+                Digit::d0()
+            ]))
+        )
+    }
+
+    #[test]
+    fn real_long_fraction() {
+        assert_eq!(
+            token("-1.299038").real::<5, 4>(),
+            Ok(FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d2(), Digit::d9(), Digit::d9(), Digit::d0()]
+            ))
+        )
+    }
+
+    #[test]
+    fn real_long_fraction_round_up() {
+        assert_eq!(
+            token("1.23456").real::<5, 4>(),
+            Ok(FixedReal::Integer(
+                FixedInteger::from_int(1).unwrap(),
+                vec![Digit::d2(), Digit::d3(), Digit::d4(), Digit::d6()]
+            ))
+        )
+    }
+
+    #[test]
+    fn real_long_fraction_carry() {
+        assert_eq!(
+            token("-9.99995").real::<5, 4>(),
+            Ok(FixedReal::Integer(
+                FixedInteger::from_int(-10).unwrap(),
+                vec![Digit::d0(); 4]
+            ))
+        )
+    }
+
+    #[test]
+    fn real_long_fraction_carry_overflow() {
+        assert_eq!(
+            token("99999.99995").real::<5, 4>(),
+            Err(ErrorKind::Overflow(0, 12).into())
+        )
+    }
+
+    #[test]
+    fn real_long_fraction_bad_character() {
+        assert_eq!(
+            token("1.23456x").real::<5, 4>(),
+            Err(ErrorKind::Character(0, 14, Printable::digits()).into())
+        )
+    }
+
+    #[test]
+    fn real_bad_character() {
+        assert_eq!(
+            token("1.2e4").real::<5, 4>(),
+            Err(ErrorKind::Character(0, 10, Printable::digits()).into())
+        )
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            token("(2 1 3)")
+                .list()
+                .unwrap()
+                .iter()
+                .map(|token| token.build(Natural::start()).unwrap().to_int())
+                .collect::<Vec<_>>(),
+            vec![1, 3]
+        )
+    }
+
+    #[test]
+    fn list_short() {
+        assert_eq!(
+            token("(3 1 3)").list(),
+            Err(ErrorKind::Length(0, 13, 3).into())
+        )
+    }
+
+    #[test]
+    fn list_long() {
+        assert_eq!(
+            token("(1 1 3)").list(),
+            Err(ErrorKind::Length(0, 12, 1).into())
+        )
+    }
+}
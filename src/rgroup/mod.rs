@@ -0,0 +1,8 @@
+mod occurrence;
+#[allow(clippy::module_inception)]
+mod rgroup;
+mod rgroup_query;
+
+pub use occurrence::Occurrence;
+pub use rgroup::Rgroup;
+pub use rgroup_query::RgroupQuery;
@@ -0,0 +1,263 @@
+use crate::{
+    build::{Builder, Error, Target},
+    text::{Digit, Printable},
+};
+
+// 1-3,5,>2
+#[derive(Debug, PartialEq, Clone)]
+pub enum Occurrence {
+    Exactly(u32),
+    Range(u32, u32),
+    GreaterThan(u32),
+    LessThan(u32),
+}
+
+impl Occurrence {
+    pub fn start_list() -> impl Builder<Product = Vec<Occurrence>> {
+        OccurrenceBuilder::Item(vec![])
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Bound {
+    Exactly,
+    Range(u32),
+    GreaterThan,
+    LessThan,
+}
+
+impl Bound {
+    fn finish(self, value: u32) -> Occurrence {
+        match self {
+            Self::Exactly => Occurrence::Exactly(value),
+            Self::Range(low) => Occurrence::Range(low, value),
+            Self::GreaterThan => Occurrence::GreaterThan(value),
+            Self::LessThan => Occurrence::LessThan(value),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum OccurrenceBuilder {
+    Item(Vec<Occurrence>),
+    Bound(Vec<Occurrence>, Bound),
+    Number(Vec<Occurrence>, Bound, u32),
+    After(Vec<Occurrence>),
+}
+
+impl Builder for OccurrenceBuilder {
+    type Product = Vec<Occurrence>;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self {
+            Self::Item(list) => match printable {
+                Printable::Space => Ok(Target::Builder(Self::Item(list))),
+                Printable::GreaterThan => {
+                    Ok(Target::Builder(Self::Bound(list, Bound::GreaterThan)))
+                }
+                Printable::LessThan => {
+                    Ok(Target::Builder(Self::Bound(list, Bound::LessThan)))
+                }
+                _ => match Digit::from_printable(printable) {
+                    Some(digit) => Ok(Target::Builder(Self::Number(
+                        list,
+                        Bound::Exactly,
+                        digit.to_int(),
+                    ))),
+                    None => {
+                        let mut allowed = Printable::digits();
+
+                        allowed.push(Printable::LessThan);
+                        allowed.push(Printable::GreaterThan);
+
+                        Err(Error::Character(allowed))
+                    }
+                },
+            },
+            Self::Bound(list, bound) => {
+                match Digit::from_printable(printable) {
+                    Some(digit) => Ok(Target::Builder(Self::Number(
+                        list,
+                        bound,
+                        digit.to_int(),
+                    ))),
+                    None => Err(Error::digit()),
+                }
+            }
+            Self::Number(mut list, bound, value) => match printable {
+                Printable::Minus if bound == Bound::Exactly => {
+                    Ok(Target::Builder(Self::Bound(list, Bound::Range(value))))
+                }
+                Printable::Comma => {
+                    list.push(bound.finish(value));
+
+                    Ok(Target::Builder(Self::Item(list)))
+                }
+                Printable::Space => {
+                    list.push(bound.finish(value));
+
+                    Ok(Target::Builder(Self::After(list)))
+                }
+                _ => match Digit::from_printable(printable).and_then(|digit| {
+                    value.checked_mul(10)?.checked_add(digit.to_int())
+                }) {
+                    Some(value) => {
+                        Ok(Target::Builder(Self::Number(list, bound, value)))
+                    }
+                    None => {
+                        let mut allowed = Printable::digits();
+
+                        if bound == Bound::Exactly {
+                            allowed.push(Printable::Minus);
+                        }
+
+                        allowed.push(Printable::Comma);
+
+                        Err(Error::Character(allowed))
+                    }
+                },
+            },
+            Self::After(list) => match printable {
+                Printable::Space => Ok(Target::Builder(Self::After(list))),
+                Printable::Comma => Ok(Target::Builder(Self::Item(list))),
+                _ => Err(Error::Character(vec![Printable::Comma])),
+            },
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        match self {
+            Self::Item(list) => {
+                if list.is_empty() {
+                    Some(list)
+                } else {
+                    None
+                }
+            }
+            Self::Bound(..) => None,
+            Self::Number(mut list, bound, value) => {
+                list.push(bound.finish(value));
+
+                Some(list)
+            }
+            Self::After(list) => Some(list),
+        }
+    }
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn item_given_comma() {
+        let builder = OccurrenceBuilder::Item(vec![]);
+        let mut allowed = Printable::digits();
+
+        allowed.push(Printable::LessThan);
+        allowed.push(Printable::GreaterThan);
+
+        assert_eq!(
+            builder.push(Printable::Comma),
+            Err(Error::Character(allowed))
+        )
+    }
+
+    #[test]
+    fn bound_given_minus() {
+        let builder = OccurrenceBuilder::Bound(vec![], Bound::GreaterThan);
+
+        assert_eq!(builder.push(Printable::Minus), Err(Error::digit()))
+    }
+
+    #[test]
+    fn range_given_minus() {
+        let builder = OccurrenceBuilder::Number(vec![], Bound::Range(1), 3);
+        let mut allowed = Printable::digits();
+
+        allowed.push(Printable::Comma);
+
+        assert_eq!(
+            builder.push(Printable::Minus),
+            Err(Error::Character(allowed))
+        )
+    }
+
+    #[test]
+    fn number_given_overflow() {
+        let builder =
+            OccurrenceBuilder::Number(vec![], Bound::Exactly, u32::MAX);
+        let mut allowed = Printable::digits();
+
+        allowed.push(Printable::Minus);
+        allowed.push(Printable::Comma);
+
+        assert_eq!(builder.push(Printable::D1), Err(Error::Character(allowed)))
+    }
+
+    #[test]
+    fn after_given_digit() {
+        let builder = OccurrenceBuilder::After(vec![]);
+
+        assert_eq!(
+            builder.push(Printable::D1),
+            Err(Error::Character(vec![Printable::Comma]))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn build(str: &str) -> Option<Vec<Occurrence>> {
+        let mut builder = OccurrenceBuilder::Item(vec![]);
+
+        for byte in str.bytes() {
+            builder = match builder.push(Printable::from_byte(byte)?) {
+                Ok(Target::Builder(builder)) => builder,
+                _ => return None,
+            }
+        }
+
+        builder.done()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(build(""), Some(vec![]))
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_eq!(build("1,"), None)
+    }
+
+    #[test]
+    fn open_range() {
+        assert_eq!(build("1-"), None)
+    }
+
+    #[test]
+    fn exactly() {
+        assert_eq!(build("12"), Some(vec![Occurrence::Exactly(12)]))
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            build("1-3, 5 ,>0,<4"),
+            Some(vec![
+                Occurrence::Range(1, 3),
+                Occurrence::Exactly(5),
+                Occurrence::GreaterThan(0),
+                Occurrence::LessThan(4)
+            ])
+        )
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{molfile::Molfile, primitive::Natural};
+
+use super::Occurrence;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Rgroup {
+    pub number: Natural,
+    pub members: Vec<Molfile>,
+    pub occurrence: Vec<Occurrence>,
+    pub rest_h: bool,
+    pub if_then: Option<Natural>,
+}
@@ -0,0 +1,9 @@
+use crate::molfile::Molfile;
+
+use super::Rgroup;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RgroupQuery {
+    pub root: Molfile,
+    pub rgroups: Vec<Rgroup>,
+}
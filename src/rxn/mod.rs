@@ -0,0 +1,5 @@
+mod parameters;
+mod reaction;
+
+pub use parameters::Parameters;
+pub use reaction::Reaction;
@@ -0,0 +1,62 @@
+use crate::{
+    primitive::{Line, Sequence},
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Parameters {
+    pub user_initials: Sequence<6>,
+    pub program_name: Sequence<9>,
+    // MMDDYYYYHHmm
+    pub timestamp: Sequence<12>,
+    pub registry_number: Option<Line<7>>,
+}
+
+impl Emit for Parameters {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.write(&self.user_initials)?;
+        writer.write(&self.program_name)?;
+        writer.write(&self.timestamp)?;
+
+        if let Some(registry_number) = &self.registry_number {
+            writer.pad(1)?;
+            writer.write(registry_number)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    fn parameters(registry_number: Option<&str>) -> Parameters {
+        Parameters {
+            user_initials: Sequence::from_str("CTUSER").unwrap(),
+            program_name: Sequence::from_str("CTCORE   ").unwrap(),
+            timestamp: Sequence::from_str("010120231234").unwrap(),
+            registry_number: registry_number.map(|registry_number| {
+                Line::from_str(registry_number).unwrap()
+            }),
+        }
+    }
+
+    #[test]
+    fn registry_number() {
+        assert_eq!(
+            emit(&parameters(Some("1234567"))),
+            Ok("CTUSERCTCORE   010120231234 1234567".to_string())
+        )
+    }
+
+    #[test]
+    fn no_registry_number() {
+        assert_eq!(
+            emit(&parameters(None)),
+            Ok("CTUSERCTCORE   010120231234".to_string())
+        )
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{molfile::Molfile, primitive::Line};
+
+use super::Parameters;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Reaction {
+    pub name: Line<80>,
+    pub parameters: Option<Parameters>,
+    pub comment: Line<80>,
+    pub reactants: Vec<Molfile>,
+    pub products: Vec<Molfile>,
+    pub agents: Vec<Molfile>,
+}
@@ -0,0 +1,438 @@
+use crate::{
+    build::{Builder, Error, Target},
+    primitive::{Natural, Text},
+    text::{Digit, NonZeroDigit, Printable},
+    write::{self, Emit, Writer},
+};
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct DataHeader {
+    pub number: Option<Natural>,
+    pub tag: Option<Text>,
+    pub external_registry: Option<Text>,
+    // DTn
+    pub field: Option<Natural>,
+}
+
+impl DataHeader {
+    pub fn start() -> impl Builder<Product = DataHeader> {
+        DataHeaderBuilder::Start
+    }
+}
+
+impl Emit for DataHeader {
+    fn emit(&self, writer: &mut Writer) -> Result<(), write::Error> {
+        writer.push(Printable::GreaterThan)?;
+
+        if let Some(number) = &self.number {
+            writer.pad(1)?;
+            writer.write(number)?;
+        }
+
+        if let Some(tag) = &self.tag {
+            delimited(
+                writer,
+                tag,
+                Printable::LessThan,
+                Printable::GreaterThan,
+            )?;
+        }
+
+        if let Some(registry) = &self.external_registry {
+            delimited(
+                writer,
+                registry,
+                Printable::OpenParen,
+                Printable::CloseParen,
+            )?;
+        }
+
+        if let Some(field) = &self.field {
+            writer.literal(b" DT")?;
+            writer.write(field)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn delimited(
+    writer: &mut Writer,
+    text: &Text,
+    open: Printable,
+    close: Printable,
+) -> Result<(), write::Error> {
+    if let Some(index) = text
+        .printables()
+        .iter()
+        .position(|printable| printable == &close)
+    {
+        return Err(write::Error::Unsupported(
+            writer.row,
+            writer.column + index + 2,
+        ));
+    }
+
+    writer.pad(1)?;
+    writer.push(open)?;
+    writer.write(text)?;
+    writer.push(close)
+}
+
+#[derive(Debug, PartialEq)]
+enum DataHeaderBuilder {
+    Start,
+    Gap(DataHeader),
+    Number(DataHeader, Natural),
+    Tag(DataHeader, Vec<Printable>),
+    Registry(DataHeader, Vec<Printable>),
+    D(DataHeader),
+    Field(DataHeader, Option<Natural>),
+}
+
+impl Builder for DataHeaderBuilder {
+    type Product = DataHeader;
+
+    fn push(
+        self,
+        printable: Printable,
+    ) -> Result<Target<Self::Product, Self>, Error> {
+        match self {
+            Self::Start => {
+                if printable == Printable::GreaterThan {
+                    Ok(Target::Builder(Self::Gap(DataHeader::default())))
+                } else {
+                    Err(Error::Character(vec![Printable::GreaterThan]))
+                }
+            }
+            Self::Gap(header) => match printable {
+                Printable::Space => Ok(Target::Builder(Self::Gap(header))),
+                Printable::LessThan if header.tag.is_none() => {
+                    Ok(Target::Builder(Self::Tag(header, vec![])))
+                }
+                Printable::OpenParen if header.external_registry.is_none() => {
+                    Ok(Target::Builder(Self::Registry(header, vec![])))
+                }
+                Printable::D if header.field.is_none() => {
+                    Ok(Target::Builder(Self::D(header)))
+                }
+                _ => match NonZeroDigit::from_printable(printable) {
+                    Some(digit) if header.number.is_none() => {
+                        Ok(Target::Builder(Self::Number(
+                            header,
+                            Natural::new(digit),
+                        )))
+                    }
+                    _ => Err(Error::Character(allowed(&header))),
+                },
+            },
+            Self::Number(mut header, mut number) => {
+                if printable == Printable::Space {
+                    header.number = Some(number);
+
+                    Ok(Target::Builder(Self::Gap(header)))
+                } else {
+                    match Digit::from_printable(printable) {
+                        Some(digit) => {
+                            number.push(digit);
+
+                            Ok(Target::Builder(Self::Number(header, number)))
+                        }
+                        None => Err(Error::Character(digits_or_space())),
+                    }
+                }
+            }
+            Self::Tag(mut header, mut tag) => {
+                if printable == Printable::GreaterThan {
+                    header.tag = Some(Text::from_printables(tag));
+
+                    Ok(Target::Builder(Self::Gap(header)))
+                } else {
+                    tag.push(printable);
+
+                    Ok(Target::Builder(Self::Tag(header, tag)))
+                }
+            }
+            Self::Registry(mut header, mut registry) => {
+                if printable == Printable::CloseParen {
+                    header.external_registry =
+                        Some(Text::from_printables(registry));
+
+                    Ok(Target::Builder(Self::Gap(header)))
+                } else {
+                    registry.push(printable);
+
+                    Ok(Target::Builder(Self::Registry(header, registry)))
+                }
+            }
+            Self::D(header) => {
+                if printable == Printable::T {
+                    Ok(Target::Builder(Self::Field(header, None)))
+                } else {
+                    Err(Error::Character(vec![Printable::T]))
+                }
+            }
+            Self::Field(header, None) => {
+                match NonZeroDigit::from_printable(printable) {
+                    Some(digit) => Ok(Target::Builder(Self::Field(
+                        header,
+                        Some(Natural::new(digit)),
+                    ))),
+                    None => Err(Error::non_zero_digit()),
+                }
+            }
+            Self::Field(mut header, Some(mut field)) => {
+                if printable == Printable::Space {
+                    header.field = Some(field);
+
+                    Ok(Target::Builder(Self::Gap(header)))
+                } else {
+                    match Digit::from_printable(printable) {
+                        Some(digit) => {
+                            field.push(digit);
+
+                            Ok(Target::Builder(Self::Field(
+                                header,
+                                Some(field),
+                            )))
+                        }
+                        None => Err(Error::Character(digits_or_space())),
+                    }
+                }
+            }
+        }
+    }
+
+    fn done(self) -> Option<Self::Product> {
+        let header = match self {
+            Self::Gap(header) => header,
+            Self::Number(mut header, number) => {
+                header.number = Some(number);
+
+                header
+            }
+            Self::Field(mut header, Some(field)) => {
+                header.field = Some(field);
+
+                header
+            }
+            _ => return None,
+        };
+
+        if header.number.is_none()
+            && header.tag.is_none()
+            && header.field.is_none()
+        {
+            None
+        } else {
+            Some(header)
+        }
+    }
+}
+
+fn allowed(header: &DataHeader) -> Vec<Printable> {
+    let mut result = vec![Printable::Space];
+
+    if header.tag.is_none() {
+        result.push(Printable::LessThan)
+    }
+
+    if header.external_registry.is_none() {
+        result.push(Printable::OpenParen)
+    }
+
+    if header.field.is_none() {
+        result.push(Printable::D)
+    }
+
+    if header.number.is_none() {
+        result.extend(Printable::non_zero_digits())
+    }
+
+    result
+}
+
+fn digits_or_space() -> Vec<Printable> {
+    let mut result = Printable::digits();
+
+    result.push(Printable::Space);
+
+    result
+}
+
+#[cfg(test)]
+mod builder_push {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn tagged() -> DataHeader {
+        DataHeader {
+            tag: Text::from_str("MP"),
+            ..DataHeader::default()
+        }
+    }
+
+    #[test]
+    fn start_letter() {
+        assert_eq!(
+            DataHeaderBuilder::Start.push(Printable::A),
+            Err(Error::Character(vec![Printable::GreaterThan]))
+        )
+    }
+
+    #[test]
+    fn start_greater_than() {
+        assert_eq!(
+            DataHeaderBuilder::Start.push(Printable::GreaterThan),
+            Ok(Target::Builder(DataHeaderBuilder::Gap(
+                DataHeader::default()
+            )))
+        )
+    }
+
+    #[test]
+    fn gap_second_tag() {
+        let builder = DataHeaderBuilder::Gap(tagged());
+        let mut allowed =
+            vec![Printable::Space, Printable::OpenParen, Printable::D];
+
+        allowed.extend(Printable::non_zero_digits());
+
+        assert_eq!(
+            builder.push(Printable::LessThan),
+            Err(Error::Character(allowed))
+        )
+    }
+
+    #[test]
+    fn tag_greater_than() {
+        let builder = DataHeaderBuilder::Tag(
+            DataHeader::default(),
+            vec![Printable::M, Printable::P],
+        );
+
+        assert_eq!(
+            builder.push(Printable::GreaterThan),
+            Ok(Target::Builder(DataHeaderBuilder::Gap(tagged())))
+        )
+    }
+
+    #[test]
+    fn tag_space() {
+        let builder = DataHeaderBuilder::Tag(DataHeader::default(), vec![]);
+
+        assert_eq!(
+            builder.push(Printable::Space),
+            Ok(Target::Builder(DataHeaderBuilder::Tag(
+                DataHeader::default(),
+                vec![Printable::Space]
+            )))
+        )
+    }
+
+    #[test]
+    fn d_letter() {
+        let builder = DataHeaderBuilder::D(DataHeader::default());
+
+        assert_eq!(
+            builder.push(Printable::X),
+            Err(Error::Character(vec![Printable::T]))
+        )
+    }
+
+    #[test]
+    fn field_zero() {
+        let builder = DataHeaderBuilder::Field(DataHeader::default(), None);
+
+        assert_eq!(builder.push(Printable::D0), Err(Error::non_zero_digit()))
+    }
+
+    #[test]
+    fn number_letter() {
+        let builder = DataHeaderBuilder::Number(
+            DataHeader::default(),
+            Natural::from_int(2).unwrap(),
+        );
+
+        assert_eq!(
+            builder.push(Printable::LessThan),
+            Err(Error::Character(digits_or_space()))
+        )
+    }
+}
+
+#[cfg(test)]
+mod builder_done {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty() {
+        assert_eq!(DataHeaderBuilder::Gap(DataHeader::default()).done(), None)
+    }
+
+    #[test]
+    fn open_tag() {
+        let builder = DataHeaderBuilder::Tag(DataHeader::default(), vec![]);
+
+        assert_eq!(builder.done(), None)
+    }
+
+    #[test]
+    fn field() {
+        let builder = DataHeaderBuilder::Field(
+            DataHeader::default(),
+            Some(Natural::from_int(13).unwrap()),
+        );
+
+        assert_eq!(
+            builder.done(),
+            Some(DataHeader {
+                field: Some(Natural::from_int(13).unwrap()),
+                ..DataHeader::default()
+            })
+        )
+    }
+}
+
+#[cfg(test)]
+mod emit {
+    use super::*;
+    use crate::write::emit;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn tag() {
+        let header = DataHeader {
+            tag: Text::from_str("NAME"),
+            ..DataHeader::default()
+        };
+
+        assert_eq!(emit(&header), Ok("> <NAME>".to_string()))
+    }
+
+    #[test]
+    fn every_field() {
+        let header = DataHeader {
+            number: Natural::from_int(25),
+            tag: Text::from_str("MELTING.POINT"),
+            external_registry: Text::from_str("MD-08974"),
+            field: Natural::from_int(13),
+        };
+
+        assert_eq!(
+            emit(&header),
+            Ok("> 25 <MELTING.POINT> (MD-08974) DT13".to_string())
+        )
+    }
+
+    #[test]
+    fn closed_tag() {
+        let header = DataHeader {
+            tag: Text::from_str("A>B"),
+            ..DataHeader::default()
+        };
+
+        assert_eq!(emit(&header), Err(write::Error::Unsupported(0, 4)))
+    }
+}
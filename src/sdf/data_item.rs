@@ -0,0 +1,9 @@
+use crate::primitive::Text;
+
+use super::DataHeader;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataItem {
+    pub header: DataHeader,
+    pub value: Vec<Text>,
+}
@@ -0,0 +1,7 @@
+mod data_header;
+mod data_item;
+mod sd_record;
+
+pub use data_header::DataHeader;
+pub use data_item::DataItem;
+pub use sd_record::SdRecord;
@@ -0,0 +1,9 @@
+use crate::molfile::Molfile;
+
+use super::DataItem;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SdRecord {
+    pub molfile: Molfile,
+    pub data: Vec<DataItem>,
+}
@@ -39,9 +39,6 @@ impl Character {
     }
 
     pub fn is_lf(&self) -> bool {
-        match self {
-            Self::Eol(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Eol(_))
     }
 }
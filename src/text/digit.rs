@@ -63,6 +63,20 @@ impl Digit {
         }
     }
 
+    pub fn to_printable(&self) -> Printable {
+        match self {
+            Self::Zero => Printable::D0,
+            Self::NonZero(non_zero) => non_zero.to_printable(),
+        }
+    }
+
+    pub fn to_int(&self) -> u32 {
+        match self {
+            Self::Zero => 0,
+            Self::NonZero(non_zero) => non_zero.to_int(),
+        }
+    }
+
     pub fn split(mut int: u32) -> Vec<Self> {
         if int == 0 {
             return vec![Digit::Zero];
@@ -114,3 +128,19 @@ mod split {
         assert_eq!(Digit::split(42), vec![Digit::d4(), Digit::d2()])
     }
 }
+
+#[cfg(test)]
+mod to_int {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zero() {
+        assert_eq!(Digit::d0().to_int(), 0)
+    }
+
+    #[test]
+    fn nine() {
+        assert_eq!(Digit::d9().to_int(), 9)
+    }
+}
@@ -28,4 +28,32 @@ impl NonZeroDigit {
             _ => None,
         }
     }
+
+    pub fn to_printable(&self) -> Printable {
+        match self {
+            NonZeroDigit::D1 => Printable::D1,
+            NonZeroDigit::D2 => Printable::D2,
+            NonZeroDigit::D3 => Printable::D3,
+            NonZeroDigit::D4 => Printable::D4,
+            NonZeroDigit::D5 => Printable::D5,
+            NonZeroDigit::D6 => Printable::D6,
+            NonZeroDigit::D7 => Printable::D7,
+            NonZeroDigit::D8 => Printable::D8,
+            NonZeroDigit::D9 => Printable::D9,
+        }
+    }
+
+    pub fn to_int(&self) -> u32 {
+        match self {
+            NonZeroDigit::D1 => 1,
+            NonZeroDigit::D2 => 2,
+            NonZeroDigit::D3 => 3,
+            NonZeroDigit::D4 => 4,
+            NonZeroDigit::D5 => 5,
+            NonZeroDigit::D6 => 6,
+            NonZeroDigit::D7 => 7,
+            NonZeroDigit::D8 => 8,
+            NonZeroDigit::D9 => 9,
+        }
+    }
 }
@@ -1,4 +1,4 @@
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Printable {
     Space,
     Exclamation,
@@ -227,4 +227,117 @@ impl Printable {
             _ => None,
         }
     }
+
+    pub fn to_byte(&self) -> u8 {
+        match self {
+            Self::Space => 0x20,
+            Self::Exclamation => 0x21,
+            Self::DoubleQuote => 0x22,
+            Self::Octothorpe => 0x23,
+            Self::Dollar => 0x24,
+            Self::Percent => 0x25,
+            Self::Ampersand => 0x26,
+            Self::SingleQuote => 0x27,
+            Self::OpenParen => 0x28,
+            Self::CloseParen => 0x29,
+            Self::Asterisk => 0x2a,
+            Self::Plus => 0x2b,
+            Self::Comma => 0x2c,
+            Self::Minus => 0x2d,
+            Self::Dot => 0x2e,
+            Self::Slash => 0x2f,
+            Self::D0 => 0x30,
+            Self::D1 => 0x31,
+            Self::D2 => 0x32,
+            Self::D3 => 0x33,
+            Self::D4 => 0x34,
+            Self::D5 => 0x35,
+            Self::D6 => 0x36,
+            Self::D7 => 0x37,
+            Self::D8 => 0x38,
+            Self::D9 => 0x39,
+            Self::Colon => 0x3a,
+            Self::Semicolon => 0x3b,
+            Self::LessThan => 0x3c,
+            Self::Equal => 0x3d,
+            Self::GreaterThan => 0x3e,
+            Self::Question => 0x3f,
+            Self::At => 0x40,
+            Self::A => 0x41,
+            Self::B => 0x42,
+            Self::C => 0x43,
+            Self::D => 0x44,
+            Self::E => 0x45,
+            Self::F => 0x46,
+            Self::G => 0x47,
+            Self::H => 0x48,
+            Self::I => 0x49,
+            Self::J => 0x4a,
+            Self::K => 0x4b,
+            Self::L => 0x4c,
+            Self::M => 0x4d,
+            Self::N => 0x4e,
+            Self::O => 0x4f,
+            Self::P => 0x50,
+            Self::Q => 0x51,
+            Self::R => 0x52,
+            Self::S => 0x53,
+            Self::T => 0x54,
+            Self::U => 0x55,
+            Self::V => 0x56,
+            Self::W => 0x57,
+            Self::X => 0x58,
+            Self::Y => 0x59,
+            Self::Z => 0x5a,
+            Self::OpenBracket => 0x5b,
+            Self::Backslash => 0x5c,
+            Self::CloseBracket => 0x5d,
+            Self::Caret => 0x5e,
+            Self::Underscore => 0x5f,
+            Self::Backtick => 0x60,
+            Self::LowerA => 0x61,
+            Self::LowerB => 0x62,
+            Self::LowerC => 0x63,
+            Self::LowerD => 0x64,
+            Self::LowerE => 0x65,
+            Self::LowerF => 0x66,
+            Self::LowerG => 0x67,
+            Self::LowerH => 0x68,
+            Self::LowerI => 0x69,
+            Self::LowerJ => 0x6a,
+            Self::LowerK => 0x6b,
+            Self::LowerL => 0x6c,
+            Self::LowerM => 0x6d,
+            Self::LowerN => 0x6e,
+            Self::LowerO => 0x6f,
+            Self::LowerP => 0x70,
+            Self::LowerQ => 0x71,
+            Self::LowerR => 0x72,
+            Self::LowerS => 0x73,
+            Self::LowerT => 0x74,
+            Self::LowerU => 0x75,
+            Self::LowerV => 0x76,
+            Self::LowerW => 0x77,
+            Self::LowerX => 0x78,
+            Self::LowerY => 0x79,
+            Self::LowerZ => 0x7a,
+            Self::OpenBrace => 0x7b,
+            Self::Bar => 0x7c,
+            Self::CloseBrace => 0x7d,
+            Self::Tilde => 0x7e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod to_byte {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn round_trip() {
+        for byte in 0x20..=0x7e {
+            assert_eq!(Printable::from_byte(byte).unwrap().to_byte(), byte)
+        }
+    }
 }
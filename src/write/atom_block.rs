@@ -0,0 +1,99 @@
+use crate::molfile::Atom;
+
+use super::{Error, Writer};
+
+pub fn atom_block(writer: &mut Writer, atoms: &[Atom]) -> Result<(), Error> {
+    for atom in atoms {
+        self::atom(writer, atom)?;
+    }
+
+    Ok(())
+}
+
+fn atom(writer: &mut Writer, atom: &Atom) -> Result<(), Error> {
+    writer.write(&atom.x)?;
+    writer.write(&atom.y)?;
+    writer.write(&atom.z)?;
+    writer.pad(1)?;
+    writer.write(&atom.symbol)?;
+    writer.write(&atom.mass_difference)?;
+    writer.write(&atom.charge)?;
+    writer.write(&atom.stereo_parity)?;
+    writer.write(&atom.hydrogen_count)?;
+    writer.write(&atom.stereo_care)?;
+    writer.write(&atom.valence)?;
+    writer.write(&atom.h0_designator)?;
+
+    // rrr, iii
+    writer.literal(b"  0  0")?;
+
+    writer.write(&atom.mapping)?;
+    writer.write(&atom.inversion)?;
+    writer.write_line(&atom.exact_change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::{FixedCount, FixedInteger, FixedReal, Sequence},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn write(atoms: &[Atom]) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        atom_block(&mut writer, atoms)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn zero_atoms() {
+        assert_eq!(write(&[]), Ok("".to_string()))
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn valid() {
+        let atom = Atom {
+            x: FixedReal::NegativeZero(vec![
+                Digit::d5(),
+                Digit::d0(),
+                Digit::d0(),
+                Digit::d0(),
+            ]),
+            y: FixedReal::Integer(
+                FixedInteger::from_int(1).unwrap(),
+                vec![Digit::d2(), Digit::d5(), Digit::d0(), Digit::d0()],
+            ),
+            z: FixedReal::Integer(
+                FixedInteger::Zero,
+                vec![Digit::d0(), Digit::d0(), Digit::d0(), Digit::d0()],
+            ),
+            symbol: Sequence::from_str("N  ").unwrap(),
+            mass_difference: FixedInteger::from_int(-1).unwrap(),
+            charge: FixedCount::from_int(3).unwrap(),
+            stereo_parity: FixedCount::Zero,
+            hydrogen_count: FixedCount::from_int(1).unwrap(),
+            stereo_care: FixedCount::Zero,
+            valence: FixedCount::Zero,
+            h0_designator: FixedCount::Zero,
+            mapping: FixedCount::from_int(7).unwrap(),
+            inversion: FixedCount::Zero,
+            exact_change: FixedCount::Zero,
+        };
+
+        assert_eq!(
+            write(&[atom]),
+            Ok([
+               //xxxxx.xxxxyyyyy.yyyyzzzzz.zzzz aaaddcccssshhhbbbvvvHHHrrriiimmmnnneee
+                "   -0.5000    1.2500    0.0000 N  -1  3  0  1  0  0  0  0  0  7  0  0",
+                "",
+            ]
+            .join("\n"))
+        )
+    }
+}
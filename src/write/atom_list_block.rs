@@ -0,0 +1,73 @@
+use crate::{molfile::AtomList, primitive::FixedCount};
+
+use super::{Error, Writer};
+
+pub fn atom_list_block(
+    writer: &mut Writer,
+    atom_lists: &[AtomList],
+) -> Result<(), Error> {
+    for atom_list in atom_lists {
+        self::atom_list(writer, atom_list)?;
+    }
+
+    Ok(())
+}
+
+fn atom_list(writer: &mut Writer, atom_list: &AtomList) -> Result<(), Error> {
+    writer.right(&atom_list.atom.printables(), 3)?;
+    writer.pad(1)?;
+    writer.write(&atom_list.list_type)?;
+    writer.pad(4)?;
+
+    match FixedCount::<1>::from_int(atom_list.elements.len() as u32) {
+        Some(count) => writer.write(&count)?,
+        None => return Err(Error::Overflow(writer.row, writer.column)),
+    }
+
+    for element in &atom_list.elements {
+        writer.pad(1)?;
+        writer.right(&element.printables(), 3)?;
+    }
+
+    writer.end_line()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{molfile::ListType, primitive::Natural};
+    use pretty_assertions::assert_eq;
+
+    fn write(atom_lists: &[AtomList]) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        atom_list_block(&mut writer, atom_lists)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    fn atom_list(elements: &[u32]) -> AtomList {
+        AtomList {
+            atom: Natural::from_int(3).unwrap(),
+            list_type: ListType::NotList,
+            elements: elements
+                .iter()
+                .map(|element| Natural::from_int(*element).unwrap())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            write(&[atom_list(&[7, 8])]),
+            Ok("  3 T    2   7   8\n".to_string())
+        )
+    }
+
+    #[test]
+    fn too_many_elements() {
+        assert_eq!(write(&[atom_list(&[6; 10])]), Err(Error::Overflow(0, 9)))
+    }
+}
@@ -0,0 +1,78 @@
+use crate::molfile::Bond;
+
+use super::{Error, Writer};
+
+pub fn bond_block(writer: &mut Writer, bonds: &[Bond]) -> Result<(), Error> {
+    for bond in bonds {
+        self::bond(writer, bond)?;
+    }
+
+    Ok(())
+}
+
+fn bond(writer: &mut Writer, bond: &Bond) -> Result<(), Error> {
+    writer.right(&bond.first.printables(), 3)?;
+    writer.right(&bond.second.printables(), 3)?;
+    writer.write(&bond.bond_type)?;
+    writer.write(&bond.stereo)?;
+
+    // xxx
+    writer.literal(b"  0")?;
+
+    writer.write(&bond.topology)?;
+    writer.write_line(&bond.reacting_center)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::{BondStereo, BondTopology, BondType, ReactingCenter},
+        primitive::Natural,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn write(bonds: &[Bond]) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        bond_block(&mut writer, bonds)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    fn bond(first: u32, bond_type: BondType) -> Bond {
+        Bond {
+            first: Natural::from_int(first).unwrap(),
+            second: Natural::from_int(2).unwrap(),
+            bond_type,
+            stereo: BondStereo::Up,
+            topology: BondTopology::Ring,
+            reacting_center: ReactingCenter::NotCenter,
+        }
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(
+            write(&[bond(1, BondType::Double)]),
+            Ok("  1  2  2  1  0  1 -1\n".to_string())
+        )
+    }
+
+    #[test]
+    fn atom_overflow() {
+        assert_eq!(
+            write(&[bond(1000, BondType::Single)]),
+            Err(Error::Overflow(0, 0))
+        )
+    }
+
+    #[test]
+    fn coordination() {
+        assert_eq!(
+            write(&[bond(1, BondType::Coordination)]),
+            Err(Error::Unsupported(0, 6))
+        )
+    }
+}
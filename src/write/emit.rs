@@ -0,0 +1,5 @@
+use super::{Error, Writer};
+
+pub trait Emit {
+    fn emit(&self, writer: &mut Writer) -> Result<(), Error>;
+}
@@ -0,0 +1,22 @@
+use std::io;
+
+use crate::molfile::Unsupported;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    // features the target encoding cannot hold, found before writing
+    Conversion(Vec<Unsupported>),
+    // the same for an RXNfile component, by block and index
+    Component(&'static str, usize, Vec<Unsupported>),
+    Io(io::ErrorKind),
+    Overflow(usize, usize),
+    Unsupported(usize, usize),
+    // SDfile data value line that would end the item or record
+    Value(usize, usize),
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error.kind())
+    }
+}
@@ -0,0 +1,33 @@
+mod atom_block;
+mod atom_list_block;
+mod bond_block;
+mod emit;
+mod error;
+mod molfile_v2000;
+mod molfile_v3000;
+mod properties;
+mod rxnfile_v2000;
+mod rxnfile_v3000;
+mod sdf_writer;
+mod stext_block;
+pub mod v3000;
+mod value_policy;
+mod writer;
+
+pub use atom_block::atom_block;
+pub use atom_list_block::atom_list_block;
+pub use bond_block::bond_block;
+pub use emit::Emit;
+pub use error::Error;
+pub use molfile_v2000::molfile_v2000;
+pub use molfile_v3000::molfile_v3000;
+pub use properties::properties;
+pub use rxnfile_v2000::rxnfile_v2000;
+pub use rxnfile_v3000::rxnfile_v3000;
+pub use sdf_writer::SdfWriter;
+pub use stext_block::stext_block;
+pub use value_policy::ValuePolicy;
+pub use writer::Writer;
+
+#[cfg(test)]
+pub use writer::emit;
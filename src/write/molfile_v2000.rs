@@ -0,0 +1,161 @@
+use crate::molfile::Molfile;
+
+use super::{
+    atom_block, atom_list_block, bond_block, properties, stext_block, Error,
+    Writer,
+};
+
+pub fn molfile_v2000(
+    writer: &mut Writer,
+    molfile: &Molfile,
+) -> Result<(), Error> {
+    // counts are derived from the blocks
+    let molfile = &molfile.to_v2000().map_err(Error::Conversion)?;
+    let header = &molfile.header;
+
+    writer.write_line(&header.molecule_name)?;
+
+    if let Some(parameters) = &header.parameters {
+        writer.write(parameters)?;
+    }
+
+    writer.end_line()?;
+    writer.write_line(&header.comment)?;
+    writer.write_line(&header.counts)?;
+
+    atom_block(writer, &molfile.atoms)?;
+    bond_block(writer, &molfile.bonds)?;
+    atom_list_block(writer, &molfile.atom_lists)?;
+    stext_block(writer, &molfile.stext)?;
+    properties(writer, &molfile.properties, &molfile.sgroups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::{
+            Atom, ChiralFlag, Counts, Header, MoleculeName, Property,
+            StereoGroup, StereoGroupKind, Unsupported, Version,
+        },
+        primitive::{
+            FixedCount, FixedInteger, FixedReal, Line, Natural, Sequence, Text,
+        },
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn molfile(atoms: usize) -> Molfile {
+        let zero =
+            || FixedReal::Integer(FixedInteger::Zero, vec![Digit::d0(); 4]);
+
+        Molfile {
+            header: Header {
+                molecule_name: MoleculeName::from_str("").unwrap(),
+                parameters: None,
+                comment: Line::from_str("").unwrap(),
+                counts: Counts {
+                    atoms: FixedCount::Zero,
+                    bonds: FixedCount::Zero,
+                    atom_lists: FixedCount::Zero,
+                    chiral: ChiralFlag::Chiral,
+                    stext: None,
+                    version: Version::V3,
+                },
+            },
+            atoms: (0..atoms)
+                .map(|_| Atom {
+                    x: zero(),
+                    y: zero(),
+                    z: zero(),
+                    symbol: Sequence::from_str("C  ").unwrap(),
+                    mass_difference: FixedInteger::Zero,
+                    charge: FixedCount::Zero,
+                    stereo_parity: FixedCount::Zero,
+                    hydrogen_count: FixedCount::Zero,
+                    stereo_care: FixedCount::Zero,
+                    valence: FixedCount::Zero,
+                    h0_designator: FixedCount::Zero,
+                    mapping: FixedCount::Zero,
+                    inversion: FixedCount::Zero,
+                    exact_change: FixedCount::Zero,
+                })
+                .collect(),
+            bonds: vec![],
+            atom_lists: vec![],
+            stext: vec![],
+            sgroups: vec![],
+            stereo_groups: vec![],
+            properties: vec![],
+        }
+    }
+
+    fn write(molfile: &Molfile) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        molfile_v2000(&mut writer, molfile)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn counts_from_blocks() {
+        assert_eq!(
+            write(&molfile(1)),
+            Ok([
+                "",
+                "",
+                "",
+                "  1  0  0  0  1               999 V2000",
+                "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+                "M  END",
+                "",
+            ]
+            .join("\n"))
+        )
+    }
+
+    #[test]
+    fn too_many_atoms() {
+        assert_eq!(
+            write(&molfile(1000)),
+            Err(Error::Conversion(vec![Unsupported::Atoms(1000)]))
+        )
+    }
+
+    #[test]
+    fn v3000_keyword_writes_nothing() {
+        let mut molfile = molfile(1);
+        let mut bytes = Vec::new();
+
+        molfile.properties.push(Property::AtomKeyword {
+            atom: Natural::from_int(1).unwrap(),
+            keyword: Text::from_str("CLASS").unwrap(),
+            value: Text::from_str("AA").unwrap(),
+        });
+
+        assert_eq!(
+            molfile_v2000(&mut Writer::new(&mut bytes), &molfile),
+            Err(Error::Conversion(vec![Unsupported::AtomKeyword(
+                Natural::from_int(1).unwrap()
+            )]))
+        );
+        assert_eq!(bytes, Vec::<u8>::new())
+    }
+
+    #[test]
+    fn stereo_groups() {
+        let mut molfile = molfile(1);
+
+        molfile.stereo_groups.push(StereoGroup {
+            kind: StereoGroupKind::Absolute,
+            atoms: vec![Natural::from_int(1).unwrap()],
+        });
+
+        assert_eq!(
+            write(&molfile),
+            Err(Error::Conversion(vec![Unsupported::StereoGroups]))
+        )
+    }
+}
@@ -0,0 +1,176 @@
+use crate::molfile::{Molfile, Property};
+
+use super::{properties::property, v3000, Error, Writer};
+
+pub fn molfile_v3000(
+    writer: &mut Writer,
+    molfile: &Molfile,
+) -> Result<(), Error> {
+    // atom block charges, mass differences and atom lists become properties
+    let v3000 = molfile.to_v3000().map_err(Error::Conversion)?;
+
+    writer.warnings.extend(molfile.lost_in_v3000());
+
+    let molfile = &v3000;
+    let header = &molfile.header;
+
+    writer.write_line(&header.molecule_name)?;
+
+    if let Some(parameters) = &header.parameters {
+        writer.write(parameters)?;
+    }
+
+    writer.end_line()?;
+    writer.write_line(&header.comment)?;
+    writer.literal(b"  0  0  0     0  0            999 V3000")?;
+    writer.end_line()?;
+
+    v3000::ctab(writer, molfile)?;
+
+    for property in &molfile.properties {
+        match property {
+            Property::Charge(_)
+            | Property::Radical(_)
+            | Property::Isotope(_)
+            | Property::RingBondCount(_)
+            | Property::SubstitutionCount(_)
+            | Property::Unsaturated(_)
+            | Property::AtomList { .. }
+            | Property::AttachmentPoint(_)
+            | Property::AttachmentOrder { .. }
+            | Property::RgroupLabel(_)
+            | Property::AtomKeyword { .. }
+            | Property::BondKeyword { .. }
+            | Property::Collection { .. }
+            | Property::Registry(_) => (),
+            property => self::property(writer, property)?,
+        }
+    }
+
+    writer.literal(b"M  END")?;
+    writer.end_line()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::Unsupported,
+        primitive::Natural,
+        read::{molfile, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn read(lines: &[&str]) -> Molfile {
+        let text = lines.join("\n");
+        let mut bytes = text.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        molfile(&mut reader).unwrap()
+    }
+
+    #[test]
+    fn v2000_charge() {
+        let text = [
+            "",
+            "",
+            "",
+            "  1  0  0  0  0  0            999 V2000",
+            "    0.0000    0.0000    0.0000 N   0  3  0  0  0  0  0  0  0  0  0  0",
+            "M  END",
+            "",
+        ]
+        .join("\n");
+        let mut bytes = text.bytes();
+        let v2000 = molfile(&mut Reader::new(&mut bytes)).unwrap();
+        let mut output = Vec::new();
+
+        molfile_v3000(&mut Writer::new(&mut output), &v2000).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            [
+                "",
+                "",
+                "",
+                "  0  0  0     0  0            999 V3000",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 0 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 N 0 0 0 0 CHG=1",
+                "M  V30 END ATOM",
+                "M  V30 END CTAB",
+                "M  END",
+                "",
+            ]
+            .join("\n")
+        )
+    }
+
+    #[test]
+    fn unknown_charge_code() {
+        let v2000 = read(&[
+            "",
+            "",
+            "",
+            "  1  0  0  0  0  0            999 V2000",
+            "    0.0000    0.0000    0.0000 N   0  9  0  0  0  0  0  0  0  0  0  0",
+            "M  END",
+            "",
+        ]);
+        let mut output = Vec::new();
+
+        assert_eq!(
+            molfile_v3000(&mut Writer::new(&mut output), &v2000),
+            Err(Error::Conversion(vec![Unsupported::Charge(
+                Natural::from_int(1).unwrap()
+            )]))
+        );
+        assert_eq!(output, Vec::<u8>::new())
+    }
+
+    #[test]
+    fn data_sgroup_field_type() {
+        let v2000 = read(&[
+            "",
+            "",
+            "",
+            "  1  0  0  0  0  0            999 V2000",
+            "    0.0000    0.0000    0.0000 C   0  0  0  0  0  0  0  0  0  0  0  0",
+            "M  STY  1   1 DAT",
+            "M  SDT   1 comment                       T",
+            "M  END",
+            "",
+        ]);
+        let mut output = Vec::new();
+        let mut writer = Writer::new(&mut output);
+
+        molfile_v3000(&mut writer, &v2000).unwrap();
+
+        assert_eq!(
+            writer.warnings,
+            vec![Unsupported::FieldType(Natural::from_int(1).unwrap())]
+        );
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            [
+                "",
+                "",
+                "",
+                "  0  0  0     0  0            999 V3000",
+                "M  V30 BEGIN CTAB",
+                "M  V30 COUNTS 1 0 1 0 0",
+                "M  V30 BEGIN ATOM",
+                "M  V30 1 C 0 0 0 0",
+                "M  V30 END ATOM",
+                "M  V30 BEGIN SGROUP",
+                "M  V30 1 DAT 0 FIELDNAME=comment",
+                "M  V30 END SGROUP",
+                "M  V30 END CTAB",
+                "M  END",
+                "",
+            ]
+            .join("\n")
+        )
+    }
+}
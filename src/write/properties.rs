@@ -0,0 +1,671 @@
+use crate::{
+    molfile::{
+        Connectivity, Entry, Property, Sgroup, SgroupSubtype, SgroupType,
+    },
+    primitive::{FixedCount, Natural, Text},
+    text::Printable,
+};
+
+use super::{Emit, Error, Writer};
+
+pub fn properties(
+    writer: &mut Writer,
+    properties: &[Property],
+    sgroups: &[Sgroup],
+) -> Result<(), Error> {
+    for property in properties {
+        self::property(writer, property)?;
+    }
+
+    self::sgroups(writer, sgroups)?;
+
+    writer.literal(b"M  END")?;
+    writer.end_line()
+}
+
+pub fn property(writer: &mut Writer, property: &Property) -> Result<(), Error> {
+    match property {
+        Property::Charge(entries) => emit_entries(writer, b"M  CHG", entries),
+        Property::Radical(entries) => emit_entries(writer, b"M  RAD", entries),
+        Property::Isotope(entries) => emit_entries(writer, b"M  ISO", entries),
+        Property::RingBondCount(entries) => {
+            emit_entries(writer, b"M  RBC", entries)
+        }
+        Property::SubstitutionCount(entries) => {
+            emit_entries(writer, b"M  SUB", entries)
+        }
+        Property::Unsaturated(entries) => {
+            emit_entries(writer, b"M  UNS", entries)
+        }
+        Property::AtomList {
+            atom,
+            list_type,
+            elements,
+        } => {
+            writer.literal(b"M  ALS")?;
+            self::atom(writer, atom)?;
+            count(writer, elements.len())?;
+            writer.pad(1)?;
+            writer.write(list_type)?;
+            writer.pad(1)?;
+
+            for element in elements {
+                writer.write(element)?;
+            }
+
+            writer.end_line()
+        }
+        Property::AttachmentPoint(entries) => {
+            emit_entries(writer, b"M  APO", entries)
+        }
+        Property::AttachmentOrder { atom, entries } => {
+            writer.literal(b"M  AAL")?;
+            self::atom(writer, atom)?;
+            count(writer, entries.len())?;
+
+            for entry in entries {
+                self::atom(writer, &entry.atom)?;
+                writer.pad(1)?;
+                writer.write(&entry.value)?;
+            }
+
+            writer.end_line()
+        }
+        Property::RgroupLabel(entries) => {
+            self::entries(writer, b"M  RGP", entries, |writer, rgroup| {
+                writer.right(&rgroup.printables(), 3)
+            })
+        }
+        Property::RgroupLogic {
+            rgroup,
+            if_then,
+            rest_h,
+            occurrence,
+        } => {
+            writer.literal(b"M  LOG  1")?;
+            atom(writer, rgroup)?;
+            writer.pad(1)?;
+            writer.write(if_then)?;
+            writer.pad(1)?;
+            writer.write(rest_h)?;
+            writer.pad(1)?;
+            writer.write_line(occurrence)
+        }
+        Property::Link(links) => {
+            for chunk in links.chunks(8) {
+                writer.literal(b"M  LIN")?;
+                count(writer, chunk.len())?;
+
+                for link in chunk {
+                    atom(writer, &link.atom)?;
+                    writer.pad(1)?;
+                    writer.write(&link.repetitions)?;
+                    atom(writer, &link.first)?;
+                    atom(writer, &link.second)?;
+                }
+
+                writer.end_line()?;
+            }
+
+            Ok(())
+        }
+        Property::ZeroOrderCharge(entries) => {
+            emit_entries(writer, b"M  ZCH", entries)
+        }
+        Property::AtomLabel { atom, label } => {
+            writer.literal(b"M  ZZC")?;
+            self::atom(writer, atom)?;
+            writer.pad(1)?;
+            writer.write_line(label)
+        }
+        Property::Registry(registry) => {
+            writer.literal(b"M  REG")?;
+            writer.pad(1)?;
+            writer.write_line(registry)
+        }
+        Property::AtomAlias { atom, alias } => {
+            writer.literal(b"A  ")?;
+            writer.right(&atom.printables(), 3)?;
+            writer.end_line()?;
+            writer.write_line(alias)
+        }
+        Property::AtomValue { atom, value } => {
+            writer.literal(b"V  ")?;
+            writer.right(&atom.printables(), 3)?;
+            writer.pad(1)?;
+            writer.write_line(value)
+        }
+        Property::GroupAbbreviation {
+            atom,
+            matching,
+            label,
+        } => {
+            writer.literal(b"G  ")?;
+            writer.right(&atom.printables(), 3)?;
+            writer.right(&matching.printables(), 3)?;
+            writer.end_line()?;
+            writer.write_line(label)
+        }
+        // V3000 only
+        Property::AtomKeyword { .. }
+        | Property::BondKeyword { .. }
+        | Property::Collection { .. } => {
+            Err(Error::Unsupported(writer.row, writer.column))
+        }
+        Property::Unknown(line) => writer.write_line(line),
+        Property::End => Ok(()),
+    }
+}
+
+fn sgroups(writer: &mut Writer, sgroups: &[Sgroup]) -> Result<(), Error> {
+    for sgroup in sgroups {
+        if !sgroup.containment_bonds.is_empty()
+            || !sgroup.head_bonds.is_empty()
+            || !sgroup.bond_correspondence.is_empty()
+            || sgroup.bracket_style.is_some()
+        {
+            return Err(Error::Unsupported(writer.row, writer.column));
+        }
+    }
+
+    pairs(writer, b"M  STY", sgroups, |sgroup| {
+        Some(keyword(sgroup_type(&sgroup.sgroup_type)))
+    })?;
+    pairs(writer, b"M  SST", sgroups, |sgroup| {
+        sgroup
+            .subtype
+            .as_ref()
+            .map(|subtype| keyword(self::subtype(subtype)))
+    })?;
+    pairs(writer, b"M  SLB", sgroups, |sgroup| {
+        sgroup.external_index.as_ref().map(Natural::printables)
+    })?;
+    pairs(writer, b"M  SCN", sgroups, |sgroup| {
+        sgroup
+            .connectivity
+            .as_ref()
+            .map(|connectivity| keyword(self::connectivity(connectivity)))
+    })?;
+
+    let expanded = sgroups
+        .iter()
+        .filter(|sgroup| sgroup.expanded)
+        .collect::<Vec<_>>();
+
+    for chunk in expanded.chunks(15) {
+        writer.literal(b"M  SDS EXP")?;
+        count(writer, chunk.len())?;
+
+        for sgroup in chunk {
+            atom(writer, &sgroup.index)?;
+        }
+
+        writer.end_line()?;
+    }
+
+    for sgroup in sgroups {
+        list(writer, b"M  SAL", sgroup, &sgroup.atoms)?;
+        list(writer, b"M  SBL", sgroup, &sgroup.crossing_bonds)?;
+        list(writer, b"M  SPA", sgroup, &sgroup.paradigmatic_atoms)?;
+
+        match (&sgroup.sgroup_type, &sgroup.multiplier, &sgroup.label) {
+            (SgroupType::Multiple, Some(multiplier), None) => {
+                start(writer, b"M  SMT", sgroup)?;
+                writer.pad(1)?;
+                writer.write_line(multiplier)?;
+            }
+            (SgroupType::Multiple, None, None) => (),
+            (SgroupType::Multiple, _, Some(_)) => {
+                return Err(Error::Unsupported(writer.row, writer.column))
+            }
+            (_, Some(_), _) => {
+                return Err(Error::Unsupported(writer.row, writer.column))
+            }
+            (_, None, Some(label)) => {
+                start(writer, b"M  SMT", sgroup)?;
+                writer.pad(1)?;
+                writer.write_line(label)?;
+            }
+            (_, None, None) => (),
+        }
+
+        for bracket in &sgroup.brackets {
+            start(writer, b"M  SDI", sgroup)?;
+            writer.literal(b"  4")?;
+            writer.write(&bracket.x1)?;
+            writer.write(&bracket.y1)?;
+            writer.write(&bracket.x2)?;
+            writer.write_line(&bracket.y2)?;
+        }
+
+        for vector in &sgroup.crossing_vectors {
+            start(writer, b"M  SBV", sgroup)?;
+            atom(writer, &vector.bond)?;
+            writer.write(&vector.x)?;
+            writer.write_line(&vector.y)?;
+        }
+    }
+
+    pairs(writer, b"M  SPL", sgroups, |sgroup| {
+        sgroup.parent.as_ref().map(Natural::printables)
+    })?;
+    pairs(writer, b"M  SNC", sgroups, |sgroup| {
+        sgroup.component.as_ref().map(Natural::printables)
+    })?;
+
+    for sgroup in sgroups {
+        for chunk in sgroup.attachments.chunks(6) {
+            start(writer, b"M  SAP", sgroup)?;
+            count(writer, chunk.len())?;
+
+            for attachment in chunk {
+                atom(writer, &attachment.atom)?;
+                writer.pad(1)?;
+
+                match &attachment.leaving_atom {
+                    Some(leaving_atom) => {
+                        writer.right(&leaving_atom.printables(), 3)?
+                    }
+                    None => writer.literal(b"  0")?,
+                }
+
+                writer.pad(1)?;
+                writer.write(&attachment.id)?;
+            }
+
+            writer.end_line()?;
+        }
+
+        if let Some(class) = &sgroup.class {
+            start(writer, b"M  SCL", sgroup)?;
+            writer.pad(1)?;
+            writer.write_line(class)?;
+        }
+
+        field(writer, sgroup)?;
+
+        if let Some(display) = &sgroup.field_display {
+            start(writer, b"M  SDD", sgroup)?;
+            writer.pad(1)?;
+            writer.write_line(display)?;
+        }
+
+        for data in &sgroup.field_data {
+            let printables = data.printables();
+            let mut chunks = printables.chunks(69).peekable();
+
+            if chunks.peek().is_none() {
+                start(writer, b"M  SED", sgroup)?;
+                writer.pad(1)?;
+                writer.end_line()?;
+            }
+
+            while let Some(chunk) = chunks.next() {
+                if chunks.peek().is_some() {
+                    start(writer, b"M  SCD", sgroup)?;
+                } else {
+                    start(writer, b"M  SED", sgroup)?;
+                }
+
+                writer.pad(1)?;
+                writer.push_all(chunk)?;
+                writer.end_line()?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn field(writer: &mut Writer, sgroup: &Sgroup) -> Result<(), Error> {
+    let fields = [
+        (&sgroup.field_name, 30),
+        (&sgroup.field_type, 2),
+        (&sgroup.field_info, 20),
+        (&sgroup.query_type, 2),
+        (&sgroup.query_operator, 15),
+    ];
+
+    if fields.iter().all(|(field, _)| field.is_none()) {
+        return Ok(());
+    }
+
+    start(writer, b"M  SDT", sgroup)?;
+    writer.pad(1)?;
+
+    let mut printables = Vec::new();
+
+    for (field, width) in fields {
+        let field = field.as_ref().map_or(&[][..], Text::printables);
+
+        if field.len() > width {
+            return Err(Error::Overflow(
+                writer.row,
+                writer.column + printables.len(),
+            ));
+        }
+
+        printables.extend(field);
+        printables
+            .resize(printables.len() + width - field.len(), Printable::Space);
+    }
+
+    let end = printables
+        .iter()
+        .rposition(|printable| printable != &Printable::Space)
+        .map_or(0, |index| index + 1);
+
+    writer.push_all(&printables[..end])?;
+    writer.end_line()
+}
+
+fn pairs(
+    writer: &mut Writer,
+    key: &'static [u8],
+    sgroups: &[Sgroup],
+    value: impl Fn(&Sgroup) -> Option<Vec<Printable>>,
+) -> Result<(), Error> {
+    let pairs = sgroups
+        .iter()
+        .filter_map(|sgroup| Some((&sgroup.index, value(sgroup)?)))
+        .collect::<Vec<_>>();
+
+    for chunk in pairs.chunks(8) {
+        writer.literal(key)?;
+        count(writer, chunk.len())?;
+
+        for (index, value) in chunk {
+            atom(writer, index)?;
+            writer.pad(1)?;
+            writer.right(value, 3)?;
+        }
+
+        writer.end_line()?;
+    }
+
+    Ok(())
+}
+
+fn list(
+    writer: &mut Writer,
+    key: &'static [u8],
+    sgroup: &Sgroup,
+    items: &[Natural],
+) -> Result<(), Error> {
+    for chunk in items.chunks(15) {
+        start(writer, key, sgroup)?;
+        count(writer, chunk.len())?;
+
+        for item in chunk {
+            atom(writer, item)?;
+        }
+
+        writer.end_line()?;
+    }
+
+    Ok(())
+}
+
+fn start(
+    writer: &mut Writer,
+    key: &'static [u8],
+    sgroup: &Sgroup,
+) -> Result<(), Error> {
+    writer.literal(key)?;
+    atom(writer, &sgroup.index)
+}
+
+fn emit_entries<V: Emit>(
+    writer: &mut Writer,
+    key: &'static [u8],
+    entries: &[Entry<V>],
+) -> Result<(), Error> {
+    self::entries(writer, key, entries, |writer, value| writer.write(value))
+}
+
+fn entries<V>(
+    writer: &mut Writer,
+    key: &'static [u8],
+    entries: &[Entry<V>],
+    value: impl Fn(&mut Writer, &V) -> Result<(), Error>,
+) -> Result<(), Error> {
+    for chunk in entries.chunks(8) {
+        writer.literal(key)?;
+        count(writer, chunk.len())?;
+
+        for entry in chunk {
+            atom(writer, &entry.atom)?;
+            writer.pad(1)?;
+            value(writer, &entry.value)?;
+        }
+
+        writer.end_line()?;
+    }
+
+    Ok(())
+}
+
+fn count(writer: &mut Writer, count: usize) -> Result<(), Error> {
+    match FixedCount::<3>::from_int(count as u32) {
+        Some(count) => writer.write(&count),
+        None => Err(Error::Overflow(writer.row, writer.column)),
+    }
+}
+
+fn atom(writer: &mut Writer, atom: &Natural) -> Result<(), Error> {
+    writer.pad(1)?;
+    writer.right(&atom.printables(), 3)
+}
+
+fn keyword(bytes: &[u8]) -> Vec<Printable> {
+    bytes
+        .iter()
+        .filter_map(|byte| Printable::from_byte(*byte))
+        .collect()
+}
+
+fn sgroup_type(sgroup_type: &SgroupType) -> &'static [u8] {
+    match sgroup_type {
+        SgroupType::Superatom => b"SUP",
+        SgroupType::Multiple => b"MUL",
+        SgroupType::StructureRepeatingUnit => b"SRU",
+        SgroupType::Monomer => b"MON",
+        SgroupType::Copolymer => b"COP",
+        SgroupType::Data => b"DAT",
+        SgroupType::Generic => b"GEN",
+        SgroupType::Formulation => b"FOR",
+        SgroupType::AnyPolymer => b"ANY",
+        SgroupType::Component => b"COM",
+        SgroupType::Mixture => b"MIX",
+    }
+}
+
+fn subtype(subtype: &SgroupSubtype) -> &'static [u8] {
+    match subtype {
+        SgroupSubtype::Alternating => b"ALT",
+        SgroupSubtype::Random => b"RAN",
+        SgroupSubtype::Block => b"BLO",
+    }
+}
+
+fn connectivity(connectivity: &Connectivity) -> &'static [u8] {
+    match connectivity {
+        Connectivity::HeadToHead => b"HH ",
+        Connectivity::HeadToTail => b"HT ",
+        Connectivity::EitherUnknown => b"EU ",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::FixedInteger,
+        read::{self, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn write(
+        properties: &[Property],
+        sgroups: &[Sgroup],
+    ) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        super::properties(&mut writer, properties, sgroups)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    fn round_trip(lines: &[&str]) {
+        let text = lines.join("\n") + "\n";
+        let mut bytes = text.bytes();
+        let (properties, sgroups) =
+            read::properties(&mut Reader::new(&mut bytes)).unwrap();
+
+        assert_eq!(write(&properties, &sgroups), Ok(text))
+    }
+
+    #[test]
+    fn end() {
+        assert_eq!(write(&[], &[]), Ok("M  END\n".to_string()))
+    }
+
+    #[test]
+    fn charge_chunks() {
+        let entries = (1..=9)
+            .map(|atom| Entry {
+                atom: Natural::from_int(atom).unwrap(),
+                value: FixedInteger::from_int(-1).unwrap(),
+            })
+            .collect();
+
+        assert_eq!(
+            write(&[Property::Charge(entries), Property::End], &[]),
+            Ok([
+                "M  CHG  8   1  -1   2  -1   3  -1   4  -1   5  -1   6  -1   7  -1   8  -1",
+                "M  CHG  1   9  -1",
+                "M  END",
+                "",
+            ]
+            .join("\n"))
+        )
+    }
+
+    #[test]
+    fn atom_overflow() {
+        let entries = vec![Entry {
+            atom: Natural::from_int(1000).unwrap(),
+            value: FixedCount::from_int(13).unwrap(),
+        }];
+
+        assert_eq!(
+            write(&[Property::Isotope(entries)], &[]),
+            Err(Error::Overflow(0, 10))
+        )
+    }
+
+    #[test]
+    fn query_properties() {
+        round_trip(&[
+            "M  RAD  1   2   2",
+            "M  ALS   4  2 F C   N   ",
+            "M  AAL   1  1   2   1",
+            "M  RGP  1   3   2",
+            "M  LOG  1   2   0   0 >0",
+            "M  LIN  1   1   2   2   3",
+            "M  ZZC   1 label",
+            "M  REG 1234",
+            "M  XYZ unknown",
+            "M  END",
+        ])
+    }
+
+    #[test]
+    fn legacy() {
+        round_trip(&[
+            "A    1",
+            "OH",
+            "V    2 value",
+            "G    3  2",
+            "Ph",
+            "M  END",
+        ])
+    }
+
+    #[test]
+    fn superatom() {
+        round_trip(&[
+            "M  STY  1   1 SUP",
+            "M  SLB  1   1   1",
+            "M  SDS EXP  1   1",
+            "M  SAL   1  2   2   3",
+            "M  SBL   1  1   1",
+            "M  SMT   1 CO2H",
+            "M  SBV   1   1   -1.5000    0.0000",
+            "M  SAP   1  1   2   1 1 ",
+            "M  SCL   1 AA",
+            "M  END",
+        ])
+    }
+
+    #[test]
+    fn polymer() {
+        round_trip(&[
+            "M  STY  2   1 SRU   2 MUL",
+            "M  SST  1   1 ALT",
+            "M  SCN  1   1 HT ",
+            "M  SMT   1 n",
+            "M  SDI   1  4    1.0000    2.0000    1.0000    3.0000",
+            "M  SPA   2  1   4",
+            "M  SMT   2 3",
+            "M  SPL  1   2   1",
+            "M  SNC  1   2   5",
+            "M  END",
+        ])
+    }
+
+    #[test]
+    fn data() {
+        round_trip(&[
+            "M  STY  1   1 DAT",
+            "M  SAL   1  1   1",
+            "M  SDT   1 pKa                           N                       <",
+            "M  SDD   1     0.0000    0.0000    DA    ALL  1       5",
+            "M  SED   1 first part, second part",
+            "M  SED   1 another",
+            "M  END",
+        ])
+    }
+
+    #[test]
+    fn long_data() {
+        let mut sgroup =
+            Sgroup::new(Natural::from_int(1).unwrap(), SgroupType::Data);
+        let data = "x".repeat(70);
+
+        sgroup.field_data = vec![Text::from_str(&data).unwrap()];
+
+        assert_eq!(
+            write(&[], &[sgroup]),
+            Ok([
+                "M  STY  1   1 DAT".to_string(),
+                format!("M  SCD   1 {}", "x".repeat(69)),
+                "M  SED   1 x".to_string(),
+                "M  END".to_string(),
+                "".to_string(),
+            ]
+            .join("\n"))
+        )
+    }
+
+    #[test]
+    fn head_bonds() {
+        let mut sgroup =
+            Sgroup::new(Natural::from_int(1).unwrap(), SgroupType::Superatom);
+
+        sgroup.head_bonds = vec![Natural::from_int(1).unwrap()];
+
+        assert_eq!(write(&[], &[sgroup]), Err(Error::Unsupported(0, 0)))
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{primitive::FixedCount, rxn::Reaction};
+
+use super::{molfile_v2000, Error, Writer};
+
+pub fn rxnfile_v2000(
+    writer: &mut Writer,
+    reaction: &Reaction,
+) -> Result<(), Error> {
+    // every component is checked before anything is written
+    for (block, molfiles) in [
+        ("reactants", &reaction.reactants),
+        ("products", &reaction.products),
+        ("agents", &reaction.agents),
+    ] {
+        for (index, molfile) in molfiles.iter().enumerate() {
+            molfile.to_v2000().map_err(|unsupported| {
+                Error::Component(block, index, unsupported)
+            })?;
+        }
+    }
+
+    writer.literal(b"$RXN")?;
+    writer.end_line()?;
+    header(writer, reaction)?;
+    count(writer, reaction.reactants.len())?;
+    count(writer, reaction.products.len())?;
+
+    if !reaction.agents.is_empty() {
+        count(writer, reaction.agents.len())?;
+    }
+
+    writer.end_line()?;
+
+    for molfile in reaction
+        .reactants
+        .iter()
+        .chain(&reaction.products)
+        .chain(&reaction.agents)
+    {
+        writer.literal(b"$MOL")?;
+        writer.end_line()?;
+        molfile_v2000(writer, molfile)?;
+    }
+
+    Ok(())
+}
+
+pub fn header(writer: &mut Writer, reaction: &Reaction) -> Result<(), Error> {
+    writer.write_line(&reaction.name)?;
+
+    if let Some(parameters) = &reaction.parameters {
+        writer.write(parameters)?;
+    }
+
+    writer.end_line()?;
+    writer.write_line(&reaction.comment)
+}
+
+fn count(writer: &mut Writer, count: usize) -> Result<(), Error> {
+    match FixedCount::<3>::from_int(count as u32) {
+        Some(count) => writer.write(&count),
+        None => Err(Error::Overflow(writer.row, writer.column)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::{StereoGroup, StereoGroupKind, Unsupported},
+        primitive::Line,
+        read::{molfile, rxnfile, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    const MOLFILE: &str =
+        "\n\n\n  0  0  0  0  0  0            999 V2000\nM  END\n";
+
+    fn reaction(reactants: usize, products: usize, agents: usize) -> Reaction {
+        let component = || {
+            let mut bytes = MOLFILE.bytes();
+            let mut reader = Reader::new(&mut bytes);
+
+            molfile(&mut reader).unwrap()
+        };
+
+        Reaction {
+            name: Line::from_str("name").unwrap(),
+            parameters: None,
+            comment: Line::from_str("").unwrap(),
+            reactants: (0..reactants).map(|_| component()).collect(),
+            products: (0..products).map(|_| component()).collect(),
+            agents: (0..agents).map(|_| component()).collect(),
+        }
+    }
+
+    fn write(reaction: &Reaction) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+
+        rxnfile_v2000(&mut Writer::new(&mut bytes), reaction)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn counts() {
+        assert_eq!(
+            write(&reaction(1, 0, 0)),
+            Ok(format!("$RXN\nname\n\n\n  1  0\n$MOL\n{}", MOLFILE))
+        )
+    }
+
+    #[test]
+    fn agents() {
+        assert_eq!(
+            write(&reaction(0, 0, 1)),
+            Ok(format!("$RXN\nname\n\n\n  0  0  1\n$MOL\n{}", MOLFILE))
+        )
+    }
+
+    #[test]
+    fn unsupported_component() {
+        let mut reaction = reaction(1, 2, 0);
+        let mut bytes = Vec::new();
+
+        reaction.products[1].stereo_groups.push(StereoGroup {
+            kind: StereoGroupKind::Absolute,
+            atoms: vec![],
+        });
+
+        assert_eq!(
+            rxnfile_v2000(&mut Writer::new(&mut bytes), &reaction),
+            Err(Error::Component(
+                "products",
+                1,
+                vec![Unsupported::StereoGroups]
+            ))
+        );
+        assert_eq!(bytes, Vec::<u8>::new())
+    }
+
+    #[test]
+    fn round_trip() {
+        let reaction = reaction(2, 1, 1);
+        let text = write(&reaction).unwrap();
+        let mut bytes = text.bytes();
+
+        assert_eq!(rxnfile(&mut Reader::new(&mut bytes)), Ok(reaction))
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    molfile::{Molfile, Property},
+    rxn::Reaction,
+};
+
+use super::{
+    rxnfile_v2000::header,
+    v3000::{ctab, line, Tokens},
+    Error, Writer,
+};
+
+pub fn rxnfile_v3000(
+    writer: &mut Writer,
+    reaction: &Reaction,
+) -> Result<(), Error> {
+    let reactants = convert(writer, "reactants", &reaction.reactants)?;
+    let products = convert(writer, "products", &reaction.products)?;
+    let agents = convert(writer, "agents", &reaction.agents)?;
+
+    writer.literal(b"$RXN V3000")?;
+    writer.end_line()?;
+    header(writer, reaction)?;
+
+    let mut tokens = Tokens::default();
+
+    tokens.literal(b"COUNTS");
+    tokens.literal(reaction.reactants.len().to_string().as_bytes());
+    tokens.literal(reaction.products.len().to_string().as_bytes());
+
+    if !reaction.agents.is_empty() {
+        tokens.literal(reaction.agents.len().to_string().as_bytes());
+    }
+
+    line(writer, tokens)?;
+    ctabs(writer, b"REACTANT", &reactants)?;
+    ctabs(writer, b"PRODUCT", &products)?;
+
+    if !agents.is_empty() {
+        ctabs(writer, b"AGENT", &agents)?;
+    }
+
+    writer.literal(b"M  END")?;
+    writer.end_line()
+}
+
+fn ctabs(
+    writer: &mut Writer,
+    block: &[u8],
+    molfiles: &[Molfile],
+) -> Result<(), Error> {
+    let mut tokens = Tokens::default();
+
+    tokens.literal(b"BEGIN");
+    tokens.literal(block);
+    line(writer, tokens)?;
+
+    for molfile in molfiles {
+        // only the CTAB is written, so nothing may follow END CTAB
+        for property in &molfile.properties {
+            if let Property::RgroupLogic { .. }
+            | Property::Link(_)
+            | Property::ZeroOrderCharge(_)
+            | Property::AtomLabel { .. }
+            | Property::AtomAlias { .. }
+            | Property::AtomValue { .. }
+            | Property::GroupAbbreviation { .. }
+            | Property::Unknown(_) = property
+            {
+                return Err(Error::Unsupported(writer.row, writer.column));
+            }
+        }
+
+        ctab(writer, molfile)?;
+    }
+
+    let mut tokens = Tokens::default();
+
+    tokens.literal(b"END");
+    tokens.literal(block);
+    line(writer, tokens)
+}
+
+// converts every component before anything is written
+fn convert(
+    writer: &mut Writer,
+    block: &'static str,
+    molfiles: &[Molfile],
+) -> Result<Vec<Molfile>, Error> {
+    let mut result = Vec::new();
+
+    for (index, molfile) in molfiles.iter().enumerate() {
+        result.push(molfile.to_v3000().map_err(|unsupported| {
+            Error::Component(block, index, unsupported)
+        })?);
+        writer.warnings.extend(molfile.lost_in_v3000());
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::Unsupported,
+        primitive::{FixedCount, Line, Natural},
+        read::{molfile, rxnfile, Reader},
+    };
+    use pretty_assertions::assert_eq;
+
+    fn reaction(agents: usize, properties: &str) -> Reaction {
+        let component = || {
+            let text = format!(
+                "\n\n\n  1  0  0  0  0  0            999 V2000\n    0.0000    0.0000    0.0000 C   0  3  0  0  0  0  0  0  0  1  0  0\n{}M  END\n",
+                properties
+            );
+            let mut bytes = text.bytes();
+            let mut reader = Reader::new(&mut bytes);
+
+            molfile(&mut reader).unwrap()
+        };
+
+        Reaction {
+            name: Line::from_str("name").unwrap(),
+            parameters: None,
+            comment: Line::from_str("comment").unwrap(),
+            reactants: vec![component()],
+            products: vec![component()],
+            agents: (0..agents).map(|_| component()).collect(),
+        }
+    }
+
+    fn write(reaction: &Reaction) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+
+        rxnfile_v3000(&mut Writer::new(&mut bytes), reaction)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn blocks() {
+        let ctab = [
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 1 CHG=1",
+            "M  V30 END ATOM",
+            "M  V30 END CTAB",
+        ];
+
+        assert_eq!(
+            write(&reaction(1, "")),
+            Ok(
+                [
+                    &[
+                        "$RXN V3000",
+                        "name",
+                        "",
+                        "comment",
+                        "M  V30 COUNTS 1 1 1"
+                    ][..],
+                    &["M  V30 BEGIN REACTANT"],
+                    &ctab,
+                    &["M  V30 END REACTANT", "M  V30 BEGIN PRODUCT"],
+                    &ctab,
+                    &["M  V30 END PRODUCT", "M  V30 BEGIN AGENT"],
+                    &ctab,
+                    &["M  V30 END AGENT", "M  END", ""],
+                ]
+                .concat()
+                .join("\n")
+            )
+        )
+    }
+
+    #[test]
+    fn unsupported_component() {
+        let mut reaction = reaction(1, "");
+        let mut bytes = Vec::new();
+
+        reaction.agents[0].atoms[0].charge = FixedCount::from_int(9).unwrap();
+
+        assert_eq!(
+            rxnfile_v3000(&mut Writer::new(&mut bytes), &reaction),
+            Err(Error::Component(
+                "agents",
+                0,
+                vec![Unsupported::Charge(Natural::from_int(1).unwrap())]
+            ))
+        );
+        assert_eq!(bytes, Vec::<u8>::new())
+    }
+
+    #[test]
+    fn trailing_property() {
+        assert_eq!(
+            write(&reaction(0, "M  ZZC   1 label\n")),
+            Err(Error::Unsupported(6, 0))
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let reaction = reaction(1, "");
+        let text = write(&reaction).unwrap();
+        let mut bytes = text.bytes();
+        let read = rxnfile(&mut Reader::new(&mut bytes)).unwrap();
+        let mut expected = reaction.clone();
+
+        for molfile in expected
+            .reactants
+            .iter_mut()
+            .chain(&mut expected.products)
+            .chain(&mut expected.agents)
+        {
+            *molfile = molfile.to_v3000().unwrap();
+            molfile
+                .properties
+                .retain(|property| property != &Property::End);
+        }
+
+        assert_eq!(read, expected)
+    }
+}
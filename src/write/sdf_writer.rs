@@ -0,0 +1,235 @@
+use std::io;
+
+use crate::{
+    molfile::{Unsupported, Version},
+    primitive::Text,
+    sdf::{DataItem, SdRecord},
+    text::Printable,
+};
+
+use super::{molfile_v2000, molfile_v3000, Error, ValuePolicy, Writer};
+
+pub struct SdfWriter<'a> {
+    inner: &'a mut dyn io::Write,
+    row: usize,
+    warnings: Vec<Unsupported>,
+    pub policy: ValuePolicy,
+}
+
+impl<'a> SdfWriter<'a> {
+    pub fn new(inner: &'a mut dyn io::Write) -> Self {
+        Self {
+            inner,
+            row: 0,
+            warnings: Vec::new(),
+            policy: ValuePolicy::default(),
+        }
+    }
+}
+
+impl SdfWriter<'_> {
+    // features dropped from the records written so far
+    pub fn warnings(&self) -> &[Unsupported] {
+        &self.warnings
+    }
+
+    // a record that fails leaves the output unchanged
+    pub fn write(&mut self, record: &SdRecord) -> Result<(), Error> {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::new(&mut buffer);
+
+        writer.row = self.row;
+        self::record(&mut writer, self.policy, record)?;
+        self.row = writer.row;
+        self.warnings.append(&mut writer.warnings);
+        self.inner.write_all(&buffer)?;
+
+        Ok(())
+    }
+}
+
+fn record(
+    writer: &mut Writer,
+    policy: ValuePolicy,
+    record: &SdRecord,
+) -> Result<(), Error> {
+    match record.molfile.header.counts.version {
+        Version::V2 => molfile_v2000(writer, &record.molfile)?,
+        Version::V3 => molfile_v3000(writer, &record.molfile)?,
+    }
+
+    for item in &record.data {
+        data_item(writer, policy, item)?;
+    }
+
+    writer.literal(b"$$$$")?;
+    writer.end_line()
+}
+
+fn data_item(
+    writer: &mut Writer,
+    policy: ValuePolicy,
+    item: &DataItem,
+) -> Result<(), Error> {
+    let invalid = item.value.iter().position(is_invalid);
+
+    if let Some(index) = invalid {
+        match policy {
+            ValuePolicy::Reject => {
+                return Err(Error::Value(writer.row + index + 1, 0))
+            }
+            ValuePolicy::Drop => return Ok(()),
+            ValuePolicy::Escape => (),
+        }
+    }
+
+    writer.write_line(&item.header)?;
+
+    for line in &item.value {
+        if is_invalid(line) {
+            writer.pad(1)?;
+        }
+
+        writer.write_line(line)?;
+    }
+
+    writer.end_line()
+}
+
+fn is_invalid(line: &Text) -> bool {
+    let printables = line.printables();
+
+    printables.is_empty() || printables.starts_with(&[Printable::Dollar; 4])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        read::{molfile, Reader, SdfReader},
+        sdf::DataHeader,
+    };
+    use pretty_assertions::assert_eq;
+
+    const MOLFILE: &str =
+        "\n\n\n  0  0  0  0  0  0            999 V2000\nM  END\n";
+
+    fn record(value: &[&str]) -> SdRecord {
+        let mut bytes = MOLFILE.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        SdRecord {
+            molfile: molfile(&mut reader).unwrap(),
+            data: vec![DataItem {
+                header: DataHeader {
+                    tag: Text::from_str("NAME"),
+                    ..DataHeader::default()
+                },
+                value: value
+                    .iter()
+                    .map(|line| Text::from_str(line).unwrap())
+                    .collect(),
+            }],
+        }
+    }
+
+    fn write(
+        policy: ValuePolicy,
+        records: &[SdRecord],
+    ) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+        let mut writer = SdfWriter::new(&mut bytes);
+
+        writer.policy = policy;
+
+        for record in records {
+            writer.write(record)?;
+        }
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    #[test]
+    fn records() {
+        assert_eq!(
+            write(
+                ValuePolicy::Reject,
+                &[record(&["first", "second"]), record(&[])]
+            ),
+            Ok(format!(
+                "{}> <NAME>\nfirst\nsecond\n\n$$$$\n{}> <NAME>\n\n$$$$\n",
+                MOLFILE, MOLFILE
+            ))
+        )
+    }
+
+    #[test]
+    fn round_trip() {
+        let records = vec![record(&["first", "  second"]), record(&["third"])];
+        let text = write(ValuePolicy::Reject, &records).unwrap();
+        let mut bytes = text.bytes();
+
+        assert_eq!(
+            SdfReader::new(&mut bytes).collect::<Result<Vec<_>, _>>(),
+            Ok(records)
+        )
+    }
+
+    #[test]
+    fn reject_blank_line() {
+        assert_eq!(
+            write(
+                ValuePolicy::Reject,
+                &[record(&["ok"]), record(&["first", "", "third"])]
+            ),
+            Err(Error::Value(16, 0))
+        )
+    }
+
+    #[test]
+    fn reject_leaves_output() {
+        let mut bytes = Vec::new();
+        let mut writer = SdfWriter::new(&mut bytes);
+
+        writer.write(&record(&["ok"])).unwrap();
+
+        assert_eq!(
+            writer.write(&record(&["first", "", "third"])),
+            Err(Error::Value(16, 0))
+        );
+
+        writer.write(&record(&["next"])).unwrap();
+
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            format!(
+                "{}> <NAME>\nok\n\n$$$$\n{}> <NAME>\nnext\n\n$$$$\n",
+                MOLFILE, MOLFILE
+            )
+        )
+    }
+
+    #[test]
+    fn reject_separator() {
+        assert_eq!(
+            write(ValuePolicy::Reject, &[record(&["$$$$ and more"])]),
+            Err(Error::Value(6, 0))
+        )
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            write(ValuePolicy::Escape, &[record(&["first", "", "$$$$"])]),
+            Ok(format!("{}> <NAME>\nfirst\n \n $$$$\n\n$$$$\n", MOLFILE))
+        )
+    }
+
+    #[test]
+    fn drop() {
+        assert_eq!(
+            write(ValuePolicy::Drop, &[record(&["first", ""])]),
+            Ok(format!("{}$$$$\n", MOLFILE))
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use crate::molfile::Stext;
+
+use super::{Error, Writer};
+
+pub fn stext_block(writer: &mut Writer, stext: &[Stext]) -> Result<(), Error> {
+    for stext in stext {
+        writer.write(&stext.x)?;
+        writer.write_line(&stext.y)?;
+        writer.write_line(&stext.text)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::{FixedInteger, FixedReal, Line},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn valid() {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+        let stext = Stext {
+            x: FixedReal::Integer(
+                FixedInteger::from_int(1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()],
+            ),
+            y: FixedReal::Integer(FixedInteger::Zero, vec![Digit::d0(); 4]),
+            text: Line::from_str("hello").unwrap(),
+        };
+
+        stext_block(&mut writer, &[stext]).unwrap();
+
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            "    1.5000    0.0000\nhello\n"
+        )
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    molfile::{StereoGroup, StereoGroupKind},
+    primitive::{Natural, Text},
+    text::Printable,
+    write::{Error, Writer},
+};
+
+use super::{
+    line::{line, Tokens},
+    token::{list, printables},
+};
+
+pub fn collection_block(
+    writer: &mut Writer,
+    stereo_groups: &[StereoGroup],
+    collections: &[(&Text, &[(Text, Text)])],
+) -> Result<(), Error> {
+    for stereo_group in stereo_groups {
+        let mut tokens = Tokens::default();
+
+        tokens.push(match &stereo_group.kind {
+            StereoGroupKind::Absolute => printables(b"MDLV30/STEABS"),
+            StereoGroupKind::Racemic(group) => {
+                let mut result = printables(b"MDLV30/STERAC");
+
+                result.extend(group.printables());
+
+                result
+            }
+            StereoGroupKind::Relative(group) => {
+                let mut result = printables(b"MDLV30/STEREL");
+
+                result.extend(group.printables());
+
+                result
+            }
+        });
+        tokens.keyword(
+            b"ATOMS",
+            list(stereo_group.atoms.iter().map(Natural::printables).collect()),
+        );
+
+        line(writer, tokens)?;
+    }
+
+    for (name, keywords) in collections {
+        let mut tokens = Tokens::default();
+
+        tokens.push(name.printables().to_vec());
+
+        for (keyword, value) in keywords.iter() {
+            tokens.push(
+                [
+                    keyword.printables(),
+                    &[Printable::Equal],
+                    value.printables(),
+                ]
+                .concat(),
+            )
+        }
+
+        line(writer, tokens)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn groups() {
+        let mut bytes = Vec::new();
+        let natural = |value| Natural::from_int(value).unwrap();
+
+        collection_block(
+            &mut Writer::new(&mut bytes),
+            &[
+                StereoGroup {
+                    kind: StereoGroupKind::Absolute,
+                    atoms: vec![natural(1)],
+                },
+                StereoGroup {
+                    kind: StereoGroupKind::Racemic(natural(12)),
+                    atoms: vec![natural(2), natural(3)],
+                },
+            ],
+            &[(
+                &Text::from_str("MDLV30/HILITE").unwrap(),
+                &[(
+                    Text::from_str("ATOMS").unwrap(),
+                    Text::from_str("(1 1)").unwrap(),
+                )],
+            )],
+        )
+        .unwrap();
+
+        assert_eq!(
+            String::from_utf8(bytes).unwrap(),
+            [
+                "M  V30 MDLV30/STEABS ATOMS=(1 1)",
+                "M  V30 MDLV30/STERAC12 ATOMS=(2 2 3)",
+                "M  V30 MDLV30/HILITE ATOMS=(1 1)",
+                "",
+            ]
+            .join("\n")
+        )
+    }
+}
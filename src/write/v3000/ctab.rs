@@ -0,0 +1,453 @@
+use crate::{
+    molfile::{
+        Atom, Bond, BondStereo, BondTopology, BondType, ChiralFlag, ListType,
+        Molfile, Property, ReactingCenter,
+    },
+    primitive::{FixedCount, FixedInteger},
+    text::Printable,
+    write::{Error, Writer},
+};
+
+use super::{
+    collection_block::collection_block,
+    line::{line, Tokens},
+    sgroup_block::sgroup_block,
+    token::{list, number, printables, real, trim_end, trimmed},
+};
+
+pub fn ctab(writer: &mut Writer, molfile: &Molfile) -> Result<(), Error> {
+    let mut atoms = molfile
+        .atoms
+        .iter()
+        .map(|_| AtomProperties::default())
+        .collect::<Vec<_>>();
+    let mut bonds =
+        molfile.bonds.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    let mut registry = None;
+    let mut collections = Vec::new();
+
+    for property in &molfile.properties {
+        match property {
+            Property::Charge(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"CHG", trimmed(&entry.value)?))
+                }
+            }
+            Property::Radical(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"RAD", trimmed(&entry.value)?))
+                }
+            }
+            Property::Isotope(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"MASS", trimmed(&entry.value)?))
+                }
+            }
+            Property::RingBondCount(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"RBCNT", trimmed(&entry.value)?))
+                }
+            }
+            Property::SubstitutionCount(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"SUBST", trimmed(&entry.value)?))
+                }
+            }
+            Property::Unsaturated(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"UNSAT", trimmed(&entry.value)?))
+                }
+            }
+            Property::AtomList {
+                atom,
+                list_type,
+                elements,
+            } => {
+                let mut kind = match list_type {
+                    ListType::List => vec![Printable::OpenBracket],
+                    ListType::NotList => printables(b"\"NOT ["),
+                };
+
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        kind.push(Printable::Comma)
+                    }
+
+                    kind.extend(trim_end(element.printables()));
+                }
+
+                kind.push(Printable::CloseBracket);
+
+                if list_type == &ListType::NotList {
+                    kind.push(Printable::DoubleQuote)
+                }
+
+                let found = find(writer, &mut atoms, atom.to_int())?;
+
+                if found.kind.is_some() {
+                    return Err(Error::Unsupported(writer.row, writer.column));
+                }
+
+                found.kind = Some(kind)
+            }
+            Property::AttachmentPoint(entries) => {
+                for entry in entries {
+                    let value = if entry.value.to_int() == 3 {
+                        printables(b"-1")
+                    } else {
+                        trimmed(&entry.value)?
+                    };
+
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .keywords
+                        .push((b"ATTCHPT", value))
+                }
+            }
+            Property::AttachmentOrder { atom, entries } => {
+                let mut items = Vec::new();
+
+                for entry in entries {
+                    items.push(entry.atom.printables());
+                    items.push(trimmed(&entry.value)?);
+                }
+
+                find(writer, &mut atoms, atom.to_int())?
+                    .keywords
+                    .push((b"ATTCHORD", list(items)))
+            }
+            Property::RgroupLabel(entries) => {
+                for entry in entries {
+                    find(writer, &mut atoms, entry.atom.to_int())?
+                        .rgroups
+                        .push(entry.value.printables())
+                }
+            }
+            Property::AtomKeyword {
+                atom,
+                keyword,
+                value,
+            } => find(writer, &mut atoms, atom.to_int())?
+                .unknown
+                .push((keyword.printables(), value.printables())),
+            Property::BondKeyword {
+                bond,
+                keyword,
+                value,
+            } => match bonds.get_mut(bond.to_int() as usize - 1) {
+                Some(keywords) => {
+                    keywords.push((keyword.printables(), value.printables()))
+                }
+                None => {
+                    return Err(Error::Unsupported(writer.row, writer.column))
+                }
+            },
+            Property::Registry(registry_number) => {
+                registry = Some(registry_number.printables())
+            }
+            Property::Collection { name, keywords } => {
+                collections.push((name, keywords.as_slice()))
+            }
+            _ => (),
+        }
+    }
+
+    let mut tokens = Tokens::default();
+
+    tokens.literal(b"BEGIN");
+    tokens.literal(b"CTAB");
+    line(writer, tokens)?;
+
+    let mut tokens = Tokens::default();
+
+    tokens.literal(b"COUNTS");
+    tokens.push(number(molfile.atoms.len()));
+    tokens.push(number(molfile.bonds.len()));
+    tokens.push(number(molfile.sgroups.len()));
+    tokens.literal(b"0");
+    tokens.literal(match molfile.header.counts.chiral {
+        ChiralFlag::Chiral => b"1",
+        ChiralFlag::NotChiral => b"0",
+    });
+
+    if let Some(registry) = registry {
+        tokens.keyword(b"REGNO", registry)
+    }
+
+    line(writer, tokens)?;
+    block(writer, b"BEGIN", b"ATOM")?;
+
+    for (index, (atom, properties)) in
+        molfile.atoms.iter().zip(atoms).enumerate()
+    {
+        self::atom(writer, index + 1, atom, properties)?;
+    }
+
+    block(writer, b"END", b"ATOM")?;
+
+    if !molfile.bonds.is_empty() {
+        block(writer, b"BEGIN", b"BOND")?;
+
+        for (index, (bond, keywords)) in
+            molfile.bonds.iter().zip(bonds).enumerate()
+        {
+            self::bond(writer, index + 1, bond, keywords)?;
+        }
+
+        block(writer, b"END", b"BOND")?;
+    }
+
+    if !molfile.sgroups.is_empty() {
+        block(writer, b"BEGIN", b"SGROUP")?;
+        sgroup_block(writer, &molfile.sgroups)?;
+        block(writer, b"END", b"SGROUP")?;
+    }
+
+    if !molfile.stereo_groups.is_empty() || !collections.is_empty() {
+        block(writer, b"BEGIN", b"COLLECTION")?;
+        collection_block(writer, &molfile.stereo_groups, &collections)?;
+        block(writer, b"END", b"COLLECTION")?;
+    }
+
+    block(writer, b"END", b"CTAB")
+}
+
+#[derive(Default)]
+struct AtomProperties<'a> {
+    kind: Option<Vec<Printable>>,
+    keywords: Vec<(&'static [u8], Vec<Printable>)>,
+    rgroups: Vec<Vec<Printable>>,
+    unknown: Vec<(&'a [Printable], &'a [Printable])>,
+}
+
+fn find<'a, 'b>(
+    writer: &Writer,
+    atoms: &'a mut [AtomProperties<'b>],
+    atom: u32,
+) -> Result<&'a mut AtomProperties<'b>, Error> {
+    match atoms.get_mut(atom as usize - 1) {
+        Some(properties) => Ok(properties),
+        None => Err(Error::Unsupported(writer.row, writer.column)),
+    }
+}
+
+fn block(
+    writer: &mut Writer,
+    keyword: &[u8],
+    name: &[u8],
+) -> Result<(), Error> {
+    let mut tokens = Tokens::default();
+
+    tokens.literal(keyword);
+    tokens.literal(name);
+
+    line(writer, tokens)
+}
+
+fn atom(
+    writer: &mut Writer,
+    index: usize,
+    atom: &Atom,
+    properties: AtomProperties,
+) -> Result<(), Error> {
+    if atom.mass_difference != FixedInteger::Zero
+        || atom.charge != FixedCount::Zero
+        || atom.h0_designator != FixedCount::Zero
+    {
+        return Err(Error::Unsupported(writer.row, writer.column));
+    }
+
+    let mut tokens = Tokens::default();
+
+    tokens.push(number(index));
+    tokens.push(match properties.kind {
+        Some(kind) => kind,
+        None => trim_end(atom.symbol.printables()),
+    });
+    tokens.push(real(&atom.x)?);
+    tokens.push(real(&atom.y)?);
+    tokens.push(real(&atom.z)?);
+    tokens.push(trimmed(&atom.mapping)?);
+
+    for (key, value) in properties.keywords {
+        tokens.keyword(key, value)
+    }
+
+    if !properties.rgroups.is_empty() {
+        tokens.keyword(b"RGROUPS", list(properties.rgroups))
+    }
+
+    if atom.stereo_parity != FixedCount::Zero {
+        tokens.keyword(b"CFG", trimmed(&atom.stereo_parity)?)
+    }
+
+    match atom.valence.to_int() {
+        0 => (),
+        15 => tokens.keyword(b"VAL", printables(b"-1")),
+        _ => tokens.keyword(b"VAL", trimmed(&atom.valence)?),
+    }
+
+    match atom.hydrogen_count.to_int() {
+        0 => (),
+        1 => tokens.keyword(b"HCOUNT", printables(b"-1")),
+        count => tokens.keyword(b"HCOUNT", number(count as usize - 1)),
+    }
+
+    if atom.stereo_care != FixedCount::Zero {
+        tokens.keyword(b"STBOX", trimmed(&atom.stereo_care)?)
+    }
+
+    if atom.inversion != FixedCount::Zero {
+        tokens.keyword(b"INVRET", trimmed(&atom.inversion)?)
+    }
+
+    if atom.exact_change != FixedCount::Zero {
+        tokens.keyword(b"EXACHG", trimmed(&atom.exact_change)?)
+    }
+
+    for (keyword, value) in properties.unknown {
+        tokens.push([keyword, &[Printable::Equal], value].concat())
+    }
+
+    line(writer, tokens)
+}
+
+fn bond(
+    writer: &mut Writer,
+    index: usize,
+    bond: &Bond,
+    keywords: Vec<(&[Printable], &[Printable])>,
+) -> Result<(), Error> {
+    let mut tokens = Tokens::default();
+
+    tokens.push(number(index));
+    tokens.push(match bond.bond_type {
+        BondType::Coordination => printables(b"9"),
+        BondType::Hydrogen => printables(b"10"),
+        _ => trimmed(&bond.bond_type)?,
+    });
+    tokens.push(bond.first.printables());
+    tokens.push(bond.second.printables());
+
+    match bond.stereo {
+        BondStereo::NotStereo => (),
+        BondStereo::Up => tokens.keyword(b"CFG", printables(b"1")),
+        BondStereo::Either | BondStereo::CisTransEither => {
+            tokens.keyword(b"CFG", printables(b"2"))
+        }
+        BondStereo::Down => tokens.keyword(b"CFG", printables(b"3")),
+    }
+
+    if bond.topology != BondTopology::Either {
+        tokens.keyword(b"TOPO", trimmed(&bond.topology)?)
+    }
+
+    if bond.reacting_center != ReactingCenter::Unmarked {
+        tokens.keyword(b"RXCTR", trimmed(&bond.reacting_center)?)
+    }
+
+    for (keyword, value) in keywords {
+        tokens.push([keyword, &[Printable::Equal], value].concat())
+    }
+
+    line(writer, tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::{self, Reader};
+    use pretty_assertions::assert_eq;
+
+    fn round_trip(lines: &[&str]) {
+        let header = "\n\n\n  0  0  0     0  0            999 V3000\n";
+        let text = lines.join("\n") + "\n";
+        let source = format!("{}{}", header, text);
+        let mut bytes = source.bytes();
+        let mut reader = Reader::new(&mut bytes);
+        let header = read::header(&mut reader).unwrap();
+        let molfile = read::v3000::ctab(&mut reader, header).unwrap();
+        let mut output = Vec::new();
+
+        ctab(&mut Writer::new(&mut output), &molfile).unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), text)
+    }
+
+    #[test]
+    fn atom_properties() {
+        round_trip(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 3 2 0 0 1 REGNO=42",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 2 CHG=1 RAD=2 MASS=13 CFG=1 VAL=-1 HCOUNT=-1 CLASS=AA",
+            "M  V30 2 [C,N] 1.5 0 0 0 RBCNT=2 SUBST=-2 UNSAT=1 HCOUNT=3",
+            "M  V30 3 \"NOT [O,S]\" -1.25 0 0 0 ATTCHPT=-1 RGROUPS=(2 1 2) STBOX=1",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN BOND",
+            "M  V30 1 2 1 2 CFG=2 TOPO=1 RXCTR=-1 ENDPTS=(2 1 3) ATTACH=ALL",
+            "M  V30 2 9 2 3 CFG=3",
+            "M  V30 END BOND",
+            "M  V30 END CTAB",
+        ])
+    }
+
+    #[test]
+    fn stereo_groups() {
+        round_trip(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 0 CFG=1",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN COLLECTION",
+            "M  V30 MDLV30/STEREL1 ATOMS=(1 1)",
+            "M  V30 END COLLECTION",
+            "M  V30 END CTAB",
+        ])
+    }
+
+    #[test]
+    fn unknown_collections() {
+        round_trip(&[
+            "M  V30 BEGIN CTAB",
+            "M  V30 COUNTS 1 0 0 0 0",
+            "M  V30 BEGIN ATOM",
+            "M  V30 1 C 0 0 0 0 CFG=1",
+            "M  V30 END ATOM",
+            "M  V30 BEGIN COLLECTION",
+            "M  V30 MDLV30/STEABS ATOMS=(1 1)",
+            "M  V30 MDLV30/HILITE ATOMS=(1 1)",
+            "M  V30 END COLLECTION",
+            "M  V30 END CTAB",
+        ])
+    }
+
+    #[test]
+    fn atom_charge_column() {
+        let source =
+            "\n\n\n  0  0  0     0  0            999 V3000\nM  V30 BEGIN CTAB\nM  V30 COUNTS 1 0 0 0 0\nM  V30 BEGIN ATOM\nM  V30 1 C 0 0 0 0\nM  V30 END ATOM\nM  V30 END CTAB\n";
+        let mut bytes = source.bytes();
+        let mut reader = Reader::new(&mut bytes);
+        let header = read::header(&mut reader).unwrap();
+        let mut molfile = read::v3000::ctab(&mut reader, header).unwrap();
+
+        molfile.atoms[0].charge = FixedCount::from_int(3).unwrap();
+
+        assert_eq!(
+            ctab(&mut Writer::new(&mut Vec::new()), &molfile),
+            Err(Error::Unsupported(3, 0))
+        )
+    }
+}
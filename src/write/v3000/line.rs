@@ -0,0 +1,136 @@
+use crate::{
+    text::Printable,
+    write::{Error, Writer},
+};
+
+use super::token::printables;
+
+#[derive(Default)]
+pub struct Tokens(Vec<Vec<Printable>>);
+
+impl Tokens {
+    pub fn push(&mut self, token: Vec<Printable>) {
+        self.0.push(token)
+    }
+
+    pub fn literal(&mut self, bytes: &[u8]) {
+        self.0.push(printables(bytes))
+    }
+
+    pub fn keyword(&mut self, key: &[u8], value: Vec<Printable>) {
+        let mut token = printables(key);
+
+        token.push(Printable::Equal);
+        token.extend(value);
+
+        self.0.push(token)
+    }
+}
+
+pub fn line(writer: &mut Writer, tokens: Tokens) -> Result<(), Error> {
+    let mut content = tokens.0.join(&Printable::Space);
+
+    // a trailing minus would read as a continuation
+    if content.last() == Some(&Printable::Minus) {
+        content.push(Printable::Space);
+    }
+
+    let mut rest = content.as_slice();
+
+    while rest.len() > 73 {
+        writer.literal(b"M  V30 ")?;
+        writer.push_all(&rest[..72])?;
+        writer.push(Printable::Minus)?;
+        writer.end_line()?;
+
+        rest = &rest[72..];
+    }
+
+    writer.literal(b"M  V30 ")?;
+    writer.push_all(rest)?;
+    writer.end_line()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read::{self, Reader};
+    use pretty_assertions::assert_eq;
+
+    fn write(tokens: &[&str]) -> String {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+        let mut line = Tokens::default();
+
+        for token in tokens {
+            line.literal(token.as_bytes());
+        }
+
+        self::line(&mut writer, line).unwrap();
+
+        String::from_utf8(bytes).unwrap()
+    }
+
+    fn read(text: &str) -> Vec<Vec<u8>> {
+        let mut bytes = text.bytes();
+        let mut reader = Reader::new(&mut bytes);
+
+        read::v3000::line(&mut reader)
+            .unwrap()
+            .into_iter()
+            .map(|token| token.bytes())
+            .collect()
+    }
+
+    #[test]
+    fn single() {
+        assert_eq!(
+            write(&["1", "C", "0", "0", "0", "0"]),
+            "M  V30 1 C 0 0 0 0\n"
+        )
+    }
+
+    #[test]
+    fn keyword() {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+        let mut tokens = Tokens::default();
+
+        tokens.literal(b"3");
+        tokens.keyword(b"CHG", printables(b"-1"));
+        line(&mut writer, tokens).unwrap();
+
+        assert_eq!(bytes, b"M  V30 3 CHG=-1\n".to_vec())
+    }
+
+    #[test]
+    fn wrapped() {
+        let long = "X".repeat(80);
+        let text = write(&["1", &long]);
+
+        assert_eq!(
+            text,
+            format!(
+                "M  V30 1 {}-\nM  V30 {}\n",
+                "X".repeat(70),
+                "X".repeat(10)
+            )
+        );
+        assert_eq!(read(&text), vec![b"1".to_vec(), long.into_bytes()])
+    }
+
+    #[test]
+    fn exact_width() {
+        let long = "X".repeat(73);
+
+        assert_eq!(write(&[&long]), format!("M  V30 {}\n", long))
+    }
+
+    #[test]
+    fn trailing_minus() {
+        let text = write(&["A-"]);
+
+        assert_eq!(text, "M  V30 A- \n");
+        assert_eq!(read(&text), vec![b"A-".to_vec()])
+    }
+}
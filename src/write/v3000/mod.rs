@@ -0,0 +1,8 @@
+mod collection_block;
+mod ctab;
+mod line;
+mod sgroup_block;
+mod token;
+
+pub use ctab::ctab;
+pub use line::{line, Tokens};
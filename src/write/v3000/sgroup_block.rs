@@ -0,0 +1,258 @@
+use crate::{
+    molfile::{BracketStyle, Connectivity, Sgroup, SgroupSubtype, SgroupType},
+    primitive::{Natural, Text},
+    write::{Error, Writer},
+};
+
+use super::{
+    line::{line, Tokens},
+    token::{list, printables, real, text, trim_end},
+};
+
+pub fn sgroup_block(
+    writer: &mut Writer,
+    sgroups: &[Sgroup],
+) -> Result<(), Error> {
+    for sgroup in sgroups {
+        self::sgroup(writer, sgroup)?;
+    }
+
+    Ok(())
+}
+
+fn sgroup(writer: &mut Writer, sgroup: &Sgroup) -> Result<(), Error> {
+    if sgroup.field_type.is_some() {
+        return Err(Error::Unsupported(writer.row, writer.column));
+    }
+
+    let mut tokens = Tokens::default();
+
+    tokens.push(sgroup.index.printables());
+    tokens.literal(match sgroup.sgroup_type {
+        SgroupType::Superatom => b"SUP",
+        SgroupType::Multiple => b"MUL",
+        SgroupType::StructureRepeatingUnit => b"SRU",
+        SgroupType::Monomer => b"MON",
+        SgroupType::Copolymer => b"COP",
+        SgroupType::Data => b"DAT",
+        SgroupType::Generic => b"GEN",
+        SgroupType::Formulation => b"FOR",
+        SgroupType::AnyPolymer => b"ANY",
+        SgroupType::Component => b"COM",
+        SgroupType::Mixture => b"MIX",
+    });
+    tokens.push(match &sgroup.external_index {
+        Some(index) => index.printables(),
+        None => printables(b"0"),
+    });
+
+    references(&mut tokens, b"ATOMS", &sgroup.atoms);
+    references(&mut tokens, b"XBONDS", &sgroup.crossing_bonds);
+    references(&mut tokens, b"CBONDS", &sgroup.containment_bonds);
+    references(&mut tokens, b"PATOMS", &sgroup.paradigmatic_atoms);
+    references(&mut tokens, b"XBHEAD", &sgroup.head_bonds);
+    references(&mut tokens, b"XBCORR", &sgroup.bond_correspondence);
+
+    if let Some(subtype) = &sgroup.subtype {
+        tokens.keyword(
+            b"SUBTYPE",
+            printables(match subtype {
+                SgroupSubtype::Alternating => b"ALT",
+                SgroupSubtype::Random => b"RAN",
+                SgroupSubtype::Block => b"BLO",
+            }),
+        )
+    }
+
+    if let Some(connectivity) = &sgroup.connectivity {
+        tokens.keyword(
+            b"CONNECT",
+            printables(match connectivity {
+                Connectivity::HeadToHead => b"HH",
+                Connectivity::HeadToTail => b"HT",
+                Connectivity::EitherUnknown => b"EU",
+            }),
+        )
+    }
+
+    optional(&mut tokens, b"MULT", &sgroup.multiplier);
+    optional(&mut tokens, b"PARENT", &sgroup.parent);
+    optional(&mut tokens, b"COMPNO", &sgroup.component);
+    texts(&mut tokens, b"LABEL", &sgroup.label);
+
+    for bracket in &sgroup.brackets {
+        let zero = || printables(b"0");
+
+        tokens.keyword(
+            b"BRKXYZ",
+            list(vec![
+                real(&bracket.x1)?,
+                real(&bracket.y1)?,
+                zero(),
+                real(&bracket.x2)?,
+                real(&bracket.y2)?,
+                zero(),
+                zero(),
+                zero(),
+                zero(),
+            ]),
+        )
+    }
+
+    if let Some(style) = &sgroup.bracket_style {
+        tokens.keyword(
+            b"BRKTYP",
+            printables(match style {
+                BracketStyle::Bracket => b"BRACKET",
+                BracketStyle::Parenthesis => b"PAREN",
+            }),
+        )
+    }
+
+    if sgroup.expanded {
+        tokens.keyword(b"ESTATE", printables(b"E"))
+    }
+
+    for vector in &sgroup.crossing_vectors {
+        tokens.keyword(
+            b"CSTATE",
+            list(vec![
+                vector.bond.printables(),
+                real(&vector.x)?,
+                real(&vector.y)?,
+                printables(b"0"),
+            ]),
+        )
+    }
+
+    for attachment in &sgroup.attachments {
+        tokens.keyword(
+            b"SAP",
+            list(vec![
+                attachment.atom.printables(),
+                match &attachment.leaving_atom {
+                    Some(atom) => atom.printables(),
+                    None => printables(b"0"),
+                },
+                text(&trim_end(attachment.id.printables())),
+            ]),
+        )
+    }
+
+    texts(&mut tokens, b"CLASS", &sgroup.class);
+    texts(&mut tokens, b"FIELDNAME", &sgroup.field_name);
+    texts(&mut tokens, b"FIELDINFO", &sgroup.field_info);
+    texts(&mut tokens, b"FIELDDISP", &sgroup.field_display);
+    texts(&mut tokens, b"QUERYTYPE", &sgroup.query_type);
+    texts(&mut tokens, b"QUERYOP", &sgroup.query_operator);
+
+    for data in &sgroup.field_data {
+        tokens.keyword(b"FIELDDATA", text(data.printables()))
+    }
+
+    line(writer, tokens)
+}
+
+fn references(tokens: &mut Tokens, key: &[u8], references: &[Natural]) {
+    if !references.is_empty() {
+        tokens.keyword(
+            key,
+            list(references.iter().map(Natural::printables).collect()),
+        )
+    }
+}
+
+fn optional(tokens: &mut Tokens, key: &[u8], value: &Option<Natural>) {
+    if let Some(value) = value {
+        tokens.keyword(key, value.printables())
+    }
+}
+
+fn texts(tokens: &mut Tokens, key: &[u8], value: &Option<Text>) {
+    if let Some(value) = value {
+        tokens.keyword(key, text(value.printables()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        molfile::{CrossingVector, SgroupAttachment},
+        primitive::{FixedInteger, FixedReal, Sequence},
+        text::Digit,
+    };
+    use pretty_assertions::assert_eq;
+
+    fn write(sgroups: &[Sgroup]) -> Result<String, Error> {
+        let mut bytes = Vec::new();
+
+        sgroup_block(&mut Writer::new(&mut bytes), sgroups)?;
+
+        Ok(String::from_utf8(bytes).unwrap())
+    }
+
+    fn natural(value: u32) -> Natural {
+        Natural::from_int(value).unwrap()
+    }
+
+    #[test]
+    fn superatom() {
+        let mut sgroup = Sgroup::new(natural(1), SgroupType::Superatom);
+
+        sgroup.external_index = Some(natural(1));
+        sgroup.atoms = vec![natural(2), natural(3)];
+        sgroup.crossing_bonds = vec![natural(1)];
+        sgroup.label = Text::from_str("C O2");
+        sgroup.expanded = true;
+        sgroup.crossing_vectors = vec![CrossingVector {
+            bond: natural(1),
+            x: FixedReal::Integer(
+                FixedInteger::from_int(-1).unwrap(),
+                vec![Digit::d5(), Digit::d0(), Digit::d0(), Digit::d0()],
+            ),
+            y: FixedReal::Integer(FixedInteger::Zero, vec![Digit::d0(); 4]),
+        }];
+        sgroup.attachments = vec![SgroupAttachment {
+            atom: natural(2),
+            leaving_atom: Some(natural(1)),
+            id: Sequence::from_str("1 ").unwrap(),
+        }];
+        sgroup.class = Text::from_str("AA");
+
+        assert_eq!(
+            write(&[sgroup]),
+            Ok([
+                "M  V30 1 SUP 1 ATOMS=(2 2 3) XBONDS=(1 1) LABEL=\"C O2\" ESTATE=E CSTATE=(4 1 -1.-",
+                "M  V30 5 0 0) SAP=(3 2 1 1) CLASS=AA",
+                "",
+            ]
+            .join("\n"))
+        )
+    }
+
+    #[test]
+    fn data() {
+        let mut sgroup = Sgroup::new(natural(2), SgroupType::Data);
+
+        sgroup.parent = Some(natural(1));
+        sgroup.bracket_style = Some(BracketStyle::Parenthesis);
+        sgroup.field_name = Text::from_str("pKa");
+        sgroup.field_data = vec![Text::from_str("4.7 \"est\"").unwrap()];
+
+        assert_eq!(
+            write(&[sgroup]),
+            Ok("M  V30 2 DAT 0 PARENT=1 BRKTYP=PAREN FIELDNAME=pKa FIELDDATA=\"4.7 \"\"est\"\"\"\n"
+                .to_string())
+        )
+    }
+
+    #[test]
+    fn field_type() {
+        let mut sgroup = Sgroup::new(natural(1), SgroupType::Data);
+
+        sgroup.field_type = Text::from_str("N");
+
+        assert_eq!(write(&[sgroup]), Err(Error::Unsupported(0, 0)))
+    }
+}
@@ -0,0 +1,152 @@
+use crate::{
+    primitive::FixedReal,
+    text::Printable,
+    write::{Emit, Error, Writer},
+};
+
+pub fn printables(bytes: &[u8]) -> Vec<Printable> {
+    bytes
+        .iter()
+        .filter_map(|byte| Printable::from_byte(*byte))
+        .collect()
+}
+
+pub fn number(number: usize) -> Vec<Printable> {
+    printables(number.to_string().as_bytes())
+}
+
+pub fn trimmed<E: Emit + ?Sized>(item: &E) -> Result<Vec<Printable>, Error> {
+    let mut bytes = Vec::new();
+
+    Writer::new(&mut bytes).write(item)?;
+
+    let start = bytes
+        .iter()
+        .position(|byte| byte != &b' ')
+        .unwrap_or(bytes.len());
+
+    Ok(printables(&bytes[start..]))
+}
+
+pub fn trim_end(printables: &[Printable]) -> Vec<Printable> {
+    let end = printables
+        .iter()
+        .rposition(|printable| printable != &Printable::Space)
+        .map_or(0, |index| index + 1);
+
+    printables[..end].to_vec()
+}
+
+pub fn real<const I: usize, const F: usize>(
+    real: &FixedReal<I, F>,
+) -> Result<Vec<Printable>, Error> {
+    let mut result = trimmed(real)?;
+
+    while result.last() == Some(&Printable::D0) {
+        result.pop();
+    }
+
+    if result.last() == Some(&Printable::Dot) {
+        result.pop();
+    }
+
+    Ok(result)
+}
+
+pub fn text(printables: &[Printable]) -> Vec<Printable> {
+    let plain = !printables.is_empty()
+        && printables.iter().all(|printable| {
+            !matches!(
+                printable,
+                Printable::Space
+                    | Printable::DoubleQuote
+                    | Printable::OpenParen
+                    | Printable::CloseParen
+            )
+        });
+
+    if plain {
+        return printables.to_vec();
+    }
+
+    let mut result = vec![Printable::DoubleQuote];
+
+    for printable in printables {
+        if printable == &Printable::DoubleQuote {
+            result.push(Printable::DoubleQuote);
+        }
+
+        result.push(*printable);
+    }
+
+    result.push(Printable::DoubleQuote);
+
+    result
+}
+
+pub fn list(items: Vec<Vec<Printable>>) -> Vec<Printable> {
+    let mut result = vec![Printable::OpenParen];
+
+    result.extend(number(items.len()));
+
+    for item in items {
+        result.push(Printable::Space);
+        result.extend(item);
+    }
+
+    result.push(Printable::CloseParen);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{primitive::FixedInteger, text::Digit};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn real_fraction() {
+        let real = FixedReal::<5, 4>::NegativeZero(vec![
+            Digit::d8(),
+            Digit::d6(),
+            Digit::d6(),
+            Digit::d0(),
+        ]);
+
+        assert_eq!(self::real(&real), Ok(printables(b"-0.866")))
+    }
+
+    #[test]
+    fn real_integer() {
+        let real = FixedReal::<5, 4>::Integer(
+            FixedInteger::from_int(-10).unwrap(),
+            vec![Digit::d0(); 4],
+        );
+
+        assert_eq!(self::real(&real), Ok(printables(b"-10")))
+    }
+
+    #[test]
+    fn text_plain() {
+        assert_eq!(text(&printables(b"pKa")), printables(b"pKa"))
+    }
+
+    #[test]
+    fn text_quoted() {
+        assert_eq!(
+            text(&printables(b"4.7 \"est\"")),
+            printables(b"\"4.7 \"\"est\"\"\"")
+        )
+    }
+
+    #[test]
+    fn text_empty() {
+        assert_eq!(text(&[]), printables(b"\"\""))
+    }
+
+    #[test]
+    fn list_items() {
+        assert_eq!(list(vec![number(2), number(3)]), printables(b"(2 2 3)"))
+    }
+}
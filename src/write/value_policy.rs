@@ -0,0 +1,9 @@
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ValuePolicy {
+    #[default]
+    Reject,
+    // blank lines become a single space, $$$$ gains a leading space
+    Escape,
+    // the whole data item is left out
+    Drop,
+}
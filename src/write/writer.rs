@@ -0,0 +1,137 @@
+use std::io;
+
+use crate::{molfile::Unsupported, text::Printable};
+
+use super::{Emit, Error};
+
+pub struct Writer<'a> {
+    inner: &'a mut dyn io::Write,
+    pub row: usize,
+    pub column: usize,
+    // features dropped because the target encoding has no form for them
+    pub warnings: Vec<Unsupported>,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(inner: &'a mut dyn io::Write) -> Self {
+        Self {
+            inner,
+            row: 0,
+            column: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn write<E: Emit + ?Sized>(&mut self, item: &E) -> Result<(), Error> {
+        item.emit(self)
+    }
+
+    pub fn write_line<E: Emit + ?Sized>(
+        &mut self,
+        item: &E,
+    ) -> Result<(), Error> {
+        item.emit(self)?;
+        self.end_line()
+    }
+
+    pub fn push(&mut self, printable: Printable) -> Result<(), Error> {
+        self.push_all(&[printable])
+    }
+
+    pub fn push_all(&mut self, printables: &[Printable]) -> Result<(), Error> {
+        let bytes = printables
+            .iter()
+            .map(Printable::to_byte)
+            .collect::<Vec<_>>();
+
+        self.inner.write_all(&bytes)?;
+        self.column += printables.len();
+
+        Ok(())
+    }
+
+    pub fn literal(&mut self, bytes: &'static [u8]) -> Result<(), Error> {
+        self.inner.write_all(bytes)?;
+        self.column += bytes.len();
+
+        Ok(())
+    }
+
+    pub fn pad(&mut self, count: usize) -> Result<(), Error> {
+        self.push_all(&vec![Printable::Space; count])
+    }
+
+    pub fn right(
+        &mut self,
+        printables: &[Printable],
+        width: usize,
+    ) -> Result<(), Error> {
+        if printables.len() > width {
+            return Err(Error::Overflow(self.row, self.column));
+        }
+
+        self.pad(width - printables.len())?;
+        self.push_all(printables)
+    }
+
+    pub fn end_line(&mut self) -> Result<(), Error> {
+        self.inner.write_all(b"\n")?;
+        self.row += 1;
+        self.column = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub fn emit<E: Emit + ?Sized>(item: &E) -> Result<String, Error> {
+    let mut bytes = Vec::new();
+    let mut writer = Writer::new(&mut bytes);
+
+    writer.write(item)?;
+
+    Ok(String::from_utf8(bytes).expect("printable bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn position() {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        writer.push_all(&[Printable::A, Printable::B]).unwrap();
+        writer.end_line().unwrap();
+        writer.pad(2).unwrap();
+        writer.push(Printable::C).unwrap();
+
+        assert_eq!((writer.row, writer.column), (1, 3));
+        assert_eq!(bytes, b"AB\n  C".to_vec())
+    }
+
+    #[test]
+    fn right_overflow() {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        writer.pad(1).unwrap();
+
+        assert_eq!(
+            writer.right(&[Printable::D1, Printable::D0], 1),
+            Err(Error::Overflow(0, 1))
+        )
+    }
+
+    #[test]
+    fn right() {
+        let mut bytes = Vec::new();
+        let mut writer = Writer::new(&mut bytes);
+
+        writer.right(&[Printable::D1, Printable::D0], 3).unwrap();
+
+        assert_eq!(bytes, b" 10".to_vec())
+    }
+}
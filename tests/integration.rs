@@ -1 +1,410 @@
+use std::{fs::File, io::BufReader};
 
+use ctcore::{
+    molfile::{Entry, Property},
+    primitive::{FixedInteger, Natural},
+    rdf::{DatumValue, Identifier, Structure},
+    read::{
+        header, molfile, rgfile, rxnfile, Error, ErrorKind, RdfReader, Reader,
+        SdfReader, Segment, Strictness, WarningKind,
+    },
+    rgroup::Occurrence,
+    write::{
+        molfile_v2000, molfile_v3000, rxnfile_v2000, rxnfile_v3000, SdfWriter,
+        Writer,
+    },
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn v2000_molfile() {
+    let mut bytes = include_bytes!("data/v2k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let molfile = molfile(&mut reader).unwrap();
+
+    assert_eq!(molfile.atoms.len(), 4);
+    assert_eq!(molfile.bonds.len(), 3);
+    assert_eq!(
+        molfile.properties,
+        vec![
+            Property::Charge(vec![Entry {
+                atom: Natural::from_int(3).unwrap(),
+                value: FixedInteger::from_int(-1).unwrap()
+            }]),
+            Property::End
+        ]
+    )
+}
+
+#[test]
+fn v3000_molfile() {
+    let mut bytes = include_bytes!("data/v3k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let molfile = molfile(&mut reader).unwrap();
+
+    assert_eq!(molfile.atoms.len(), 4);
+    assert_eq!(molfile.bonds.len(), 3);
+    assert_eq!(
+        molfile.properties,
+        vec![
+            Property::Charge(vec![Entry {
+                atom: Natural::from_int(3).unwrap(),
+                value: FixedInteger::from_int(-1).unwrap()
+            }]),
+            Property::End
+        ]
+    )
+}
+
+#[test]
+fn lenient_molfile() {
+    let mut bytes = include_bytes!("data/lenient.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+
+    reader.options.strictness = Strictness::Lenient;
+
+    let lenient = molfile(&mut reader).unwrap();
+    let mut bytes = include_bytes!("data/v2k.mol").iter().cloned();
+
+    assert_eq!(lenient, molfile(&mut Reader::new(&mut bytes)).unwrap());
+    assert_eq!(
+        reader
+            .warnings
+            .iter()
+            .map(|warning| (warning.row, warning.kind.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (3, WarningKind::BlankChiral),
+            (3, WarningKind::BlankVersion),
+            (4, WarningKind::LowercaseSymbol),
+            (4, WarningKind::ShortAtomLine),
+            (7, WarningKind::Tab),
+            (12, WarningKind::TrailingWhitespace),
+        ]
+    )
+}
+
+#[test]
+fn lenient_molfile_strict() {
+    let mut bytes = include_bytes!("data/lenient.mol").iter().cloned();
+
+    assert!(molfile(&mut Reader::new(&mut bytes)).is_err())
+}
+
+#[test]
+fn v2000_molfile_error_path() {
+    let source = String::from_utf8(include_bytes!("data/v2k.mol").to_vec())
+        .unwrap()
+        .replacen(
+            " 0  0  0  0  0  0  0  0  0  0  0  0",
+            "x0  0  0  0  0  0  0  0  0  0  0  0",
+            1,
+        );
+    let mut bytes = source.bytes();
+    let error = molfile(&mut Reader::new(&mut bytes)).unwrap_err();
+
+    assert_eq!(
+        error.path.segments(),
+        &[
+            Segment::Field("ctab"),
+            Segment::Field("atoms"),
+            Segment::Index(0),
+            Segment::Field("mass_difference")
+        ]
+    );
+    assert_eq!(error.path.to_string(), "ctab.atoms[0].mass_difference")
+}
+
+#[test]
+fn header_round_trip() {
+    let source = include_bytes!("data/v2k.mol");
+    let mut bytes = source.iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let header = header(&mut reader).unwrap();
+    let mut output = Vec::new();
+    let mut writer = Writer::new(&mut output);
+
+    writer.write(&header).unwrap();
+
+    assert_eq!(writer.row, 4);
+    assert_eq!(output, source[..output.len()].to_vec())
+}
+
+#[test]
+fn v2000_round_trip() {
+    let source = include_bytes!("data/v2k.mol");
+    let mut bytes = source.iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let molfile = molfile(&mut reader).unwrap();
+    let mut output = Vec::new();
+
+    molfile_v2000(&mut Writer::new(&mut output), &molfile).unwrap();
+
+    assert_eq!(output, source.to_vec())
+}
+
+#[test]
+fn v3000_to_v2000() {
+    let mut bytes = include_bytes!("data/v3k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let v3000 = molfile(&mut reader).unwrap();
+    let mut output = Vec::new();
+
+    molfile_v2000(&mut Writer::new(&mut output), &v3000).unwrap();
+
+    let mut bytes = output.into_iter();
+    let mut reader = Reader::new(&mut bytes);
+    let v2000 = molfile(&mut reader).unwrap();
+
+    assert_eq!(v2000.atoms, v3000.atoms);
+    assert_eq!(v2000.bonds, v3000.bonds);
+    assert_eq!(v2000.properties, v3000.properties)
+}
+
+#[test]
+fn v3000_round_trip() {
+    let source = include_bytes!("data/v3k.mol");
+    let mut bytes = source.iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let molfile = molfile(&mut reader).unwrap();
+    let mut output = Vec::new();
+
+    molfile_v3000(&mut Writer::new(&mut output), &molfile).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        String::from_utf8(source.to_vec()).unwrap()
+    )
+}
+
+#[test]
+fn v2000_to_v3000() {
+    let mut bytes = include_bytes!("data/v2k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let v2000 = molfile(&mut reader).unwrap();
+    let mut output = Vec::new();
+
+    molfile_v3000(&mut Writer::new(&mut output), &v2000).unwrap();
+
+    let mut bytes = output.into_iter();
+    let mut reader = Reader::new(&mut bytes);
+    let v3000 = molfile(&mut reader).unwrap();
+
+    assert_eq!(v3000.atoms, v2000.atoms);
+    assert_eq!(v3000.bonds, v2000.bonds);
+    assert_eq!(v3000.properties, v2000.properties)
+}
+
+#[test]
+fn v2000_to_v3000_conversion() {
+    let mut bytes = include_bytes!("data/v2k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let v2000 = molfile(&mut reader).unwrap();
+    let v3000 = v2000.to_v3000().unwrap();
+    let mut output = Vec::new();
+
+    molfile_v3000(&mut Writer::new(&mut output), &v3000).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        String::from_utf8(include_bytes!("data/v3k.mol").to_vec()).unwrap()
+    );
+    assert_eq!(v3000.to_v2000(), Ok(v2000))
+}
+
+#[test]
+fn v3000_to_v2000_conversion() {
+    let mut bytes = include_bytes!("data/v3k.mol").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let v3000 = molfile(&mut reader).unwrap();
+    let v2000 = v3000.to_v2000().unwrap();
+    let mut output = Vec::new();
+
+    molfile_v2000(&mut Writer::new(&mut output), &v2000).unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        String::from_utf8(include_bytes!("data/v2k.mol").to_vec()).unwrap()
+    );
+    assert_eq!(v2000.to_v3000(), Ok(v3000))
+}
+
+#[test]
+fn sdfile() {
+    let mut bytes = include_bytes!("data/records.sdf").iter().cloned();
+    let records = SdfReader::new(&mut bytes)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert_eq!(
+        records[0].molfile.to_v3000(),
+        Ok(records[1].molfile.clone())
+    );
+    assert_eq!(records[0].data.len(), 2);
+    assert_eq!(records[1].data[0], records[0].data[0])
+}
+
+#[test]
+fn sdfile_from_read() {
+    let file = File::open("tests/data/records.sdf").unwrap();
+    let records = SdfReader::from_read(BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    let mut bytes = include_bytes!("data/records.sdf").iter().cloned();
+
+    assert_eq!(
+        records,
+        SdfReader::new(&mut bytes)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    )
+}
+
+#[test]
+fn sdfile_recovery() {
+    let records =
+        String::from_utf8(include_bytes!("data/records.sdf").to_vec()).unwrap();
+    let bad = records.replacen("V2000", "V2001", 1);
+    let source = [records.as_str(), bad.as_str(), records.as_str()].concat();
+    let mut buffer = source.as_bytes();
+    let mut quarantine = Vec::new();
+    let mut reader = SdfReader::from_read(&mut buffer);
+
+    reader.quarantine = Some(&mut quarantine);
+
+    let results = reader.collect::<Vec<_>>();
+    let start = records.len();
+    let end = records.len() + bad.find("$$$$").unwrap() + 5;
+
+    assert_eq!(
+        results.iter().map(Result::is_ok).collect::<Vec<_>>(),
+        vec![true, true, false, true, true, true]
+    );
+    assert!(matches!(
+        &results[2],
+        Err(Error {
+            kind: ErrorKind::Record(2, range, _),
+            ..
+        }) if range == &(start..end)
+    ));
+    assert_eq!(quarantine, source.as_bytes()[start..end].to_vec())
+}
+
+#[test]
+fn sdfile_round_trip() {
+    let source = include_bytes!("data/records.sdf");
+    let mut bytes = source.iter().cloned();
+    let mut output = Vec::new();
+    let mut writer = SdfWriter::new(&mut output);
+
+    for record in SdfReader::new(&mut bytes) {
+        writer.write(&record.unwrap()).unwrap();
+    }
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        String::from_utf8(source.to_vec()).unwrap()
+    )
+}
+
+#[test]
+fn v2000_rxnfile() {
+    let mut bytes = include_bytes!("data/v2k.rxn").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let reaction = rxnfile(&mut reader).unwrap();
+    let mappings = |molfile: &ctcore::molfile::Molfile| {
+        molfile
+            .atoms
+            .iter()
+            .map(|atom| atom.mapping.to_int())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(reader.is_eof());
+    assert_eq!(reaction.reactants.len(), 1);
+    assert_eq!(reaction.products.len(), 2);
+    assert_eq!(reaction.agents.len(), 0);
+    assert_eq!(mappings(&reaction.reactants[0]), vec![1, 2]);
+    assert_eq!(mappings(&reaction.products[0]), vec![1, 0]);
+    assert_eq!(mappings(&reaction.products[1]), vec![2])
+}
+
+#[test]
+fn rxnfile_version_round_trip() {
+    let mut bytes = include_bytes!("data/v2k.rxn").iter().cloned();
+    let v2000 = rxnfile(&mut Reader::new(&mut bytes)).unwrap();
+    let mut output = Vec::new();
+
+    rxnfile_v3000(&mut Writer::new(&mut output), &v2000).unwrap();
+
+    let mut bytes = output.into_iter();
+    let v3000 = rxnfile(&mut Reader::new(&mut bytes)).unwrap();
+    let mut output = Vec::new();
+
+    rxnfile_v2000(&mut Writer::new(&mut output), &v3000).unwrap();
+
+    let mut bytes = output.into_iter();
+    let round_trip = rxnfile(&mut Reader::new(&mut bytes)).unwrap();
+
+    assert_eq!(round_trip.name, v2000.name);
+    assert_eq!(round_trip.parameters, v2000.parameters);
+
+    for (left, right) in round_trip
+        .reactants
+        .iter()
+        .chain(&round_trip.products)
+        .zip(v2000.reactants.iter().chain(&v2000.products))
+    {
+        assert_eq!(left.atoms, right.atoms);
+        assert_eq!(left.bonds, right.bonds);
+        assert_eq!(left.properties, right.properties)
+    }
+}
+
+#[test]
+fn rdfile() {
+    let mut bytes = include_bytes!("data/records.rdf").iter().cloned();
+    let reader = RdfReader::new(&mut bytes).unwrap();
+    let records = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    let mut bytes = include_bytes!("data/v2k.mol").iter().cloned();
+    let acetate = molfile(&mut Reader::new(&mut bytes)).unwrap();
+
+    assert_eq!(records.len(), 2);
+    assert!(matches!(
+        &records[0].structure,
+        Structure::Reaction(Some(reaction)) if reaction.products.len() == 2
+    ));
+    assert_eq!(
+        records[0].identifier,
+        Some(Identifier::Internal(Natural::from_int(1).unwrap()))
+    );
+    assert_eq!(records[0].data[0].data_type.fields().len(), 4);
+    assert_eq!(
+        records[0].data[1].value,
+        DatumValue::Molfile(Box::new(acetate.clone()))
+    );
+    assert_eq!(
+        records[1].structure,
+        Structure::Molecule(Some(Box::new(acetate)))
+    );
+    assert_eq!(records[1].data.len(), 1)
+}
+
+#[test]
+fn rgfile_query() {
+    let mut bytes = include_bytes!("data/query.rgf").iter().cloned();
+    let mut reader = Reader::new(&mut bytes);
+    let query = rgfile(&mut reader).unwrap();
+
+    assert!(reader.is_eof());
+    assert_eq!(query.root.atoms.len(), 3);
+    assert_eq!(query.rgroups.len(), 1);
+    assert_eq!(query.rgroups[0].members.len(), 2);
+    assert_eq!(
+        query.rgroups[0].occurrence,
+        vec![Occurrence::GreaterThan(0)]
+    );
+    assert!(!query.rgroups[0].rest_h);
+    assert_eq!(query.rgroups[0].if_then, None)
+}